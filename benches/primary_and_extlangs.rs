@@ -0,0 +1,50 @@
+//! Compares `LangTag::primary_and_extlangs_str` with the typed
+//! `LangTag::language` accessors.
+//!
+//! Run with `cargo bench --bench primary_and_extlangs`.
+use std::hint::black_box;
+use std::time::Instant;
+
+use langtag::LangTag;
+
+const TAGS: [&str; 8] = [
+	"en",
+	"fr-CA",
+	"zh-yue-Hant-HK",
+	"sgn-ase-US",
+	"de-Latn-DE-1901-u-co-phonebk",
+	"x-private",
+	"i-klingon",
+	"zh-min-nan",
+];
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench(name: &str, tags: &[&LangTag], f: impl Fn(&LangTag) -> usize) {
+	let start = Instant::now();
+	let mut total = 0;
+	for _ in 0..ITERATIONS {
+		for tag in tags {
+			total += f(black_box(tag));
+		}
+	}
+	black_box(total);
+
+	let elapsed = start.elapsed();
+	let per_tag = elapsed / (ITERATIONS * tags.len() as u32);
+	println!("{name:<24} {elapsed:>12.2?} ({per_tag:.2?} per tag)");
+}
+
+fn main() {
+	let tags: Vec<_> = TAGS.iter().map(|t| LangTag::new(*t).unwrap()).collect();
+
+	bench("primary_and_extlangs_str", &tags, |tag| {
+		let (primary, extlangs) = tag.primary_and_extlangs_str();
+		primary.map_or(0, str::len) + extlangs.len()
+	});
+
+	bench("language", &tags, |tag| match tag.language() {
+		Some(language) => language.primary().len() + language.extension().map_or(0, |e| e.len()),
+		None => 0,
+	});
+}
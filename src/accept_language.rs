@@ -0,0 +1,187 @@
+//! `Accept-Language` HTTP header parsing.
+//!
+//! See [RFC 9110 section 12.5.4](https://www.rfc-editor.org/rfc/rfc9110#section-12.5.4).
+use crate::{matching::lookup_in, LangTagBuf};
+
+/// Language range of an `Accept-Language` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LanguageRange {
+	/// Language tag.
+	Tag(LangTagBuf),
+
+	/// `*` wildcard, matching any language.
+	Wildcard,
+}
+
+/// `Accept-Language` entry: a language range with its quality weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+	/// Language range.
+	pub range: LanguageRange,
+
+	/// Quality weight, between `0.0` and `1.0`.
+	pub quality: f32,
+}
+
+/// Policy applied to malformed quality weights (e.g. `q=abc` or `q=1.5`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MalformedQuality {
+	/// Drop the entry.
+	#[default]
+	Drop,
+
+	/// Keep the entry, with the default quality of `1.0`.
+	Default,
+}
+
+/// `Accept-Language` parsing options.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Options {
+	/// Policy applied to malformed quality weights.
+	pub malformed_quality: MalformedQuality,
+}
+
+/// Parses the given `Accept-Language` header value, sorting entries by
+/// descending quality.
+///
+/// Entries with the same quality keep their header order. Malformed entries
+/// are skipped, as with [`parse_with`] using the default [`Options`].
+///
+/// ```
+/// use langtag::accept_language::{parse, LanguageRange};
+///
+/// let entries: Vec<_> = parse("*;q=0.5, fr;q=0.9, fr-CH, en_US").collect();
+/// assert_eq!(entries.len(), 3);
+/// assert_eq!(entries[0].range, LanguageRange::Tag("fr-CH".parse().unwrap()));
+/// assert_eq!(entries[1].quality, 0.9);
+/// assert_eq!(entries[2].range, LanguageRange::Wildcard);
+/// ```
+pub fn parse(header: &str) -> impl Iterator<Item = Entry> {
+	let mut entries: Vec<_> = parse_with(header, Options::default()).collect();
+	entries.sort_by(|a, b| b.quality.total_cmp(&a.quality));
+	entries.into_iter()
+}
+
+/// Selects the available tag best matching the given `Accept-Language`
+/// header value.
+///
+/// The header ranges are considered by descending quality (see [`parse`]),
+/// and the first one for which [`lookup`](crate::lookup) finds an available
+/// tag wins. The `*` wildcard matches the first available tag.
+///
+/// Ranges with a quality of `0` are not acceptable: available tags they
+/// match (see [`LangTag::matches_basic`](crate::LangTag::matches_basic)) are
+/// never selected. A `*;q=0` entry is ignored.
+///
+/// ```
+/// use langtag::{accept_language::negotiate, LangTagBuf};
+///
+/// let available: Vec<LangTagBuf> = ["en", "fr-CA", "de"]
+///     .into_iter()
+///     .map(|t| t.parse().unwrap())
+///     .collect();
+///
+/// assert_eq!(negotiate("fr-CA-x-foo;q=0.5, de-CH", &available).unwrap(), "de");
+/// assert_eq!(negotiate("es, *;q=0.1", &available).unwrap(), "en");
+/// assert_eq!(negotiate("en;q=0, *", &available).unwrap(), "fr-CA");
+/// assert_eq!(negotiate("es, fr", &available), None);
+/// ```
+pub fn negotiate<'a>(header: &str, available: &'a [LangTagBuf]) -> Option<&'a LangTagBuf> {
+	let (accepted, rejected): (Vec<_>, Vec<_>) =
+		parse(header).partition(|entry| entry.quality > 0.0);
+
+	let rejected: Vec<_> = rejected
+		.into_iter()
+		.filter_map(|entry| match entry.range {
+			LanguageRange::Tag(tag) => Some(tag),
+			LanguageRange::Wildcard => None,
+		})
+		.collect();
+
+	let acceptable = available
+		.iter()
+		.filter(|tag| !rejected.iter().any(|r| tag.matches_basic(r.as_str())));
+
+	accepted.into_iter().find_map(|entry| match entry.range {
+		LanguageRange::Tag(tag) => lookup_in(tag.as_str(), acceptable.clone()),
+		LanguageRange::Wildcard => acceptable.clone().next(),
+	})
+}
+
+/// Parses the given `Accept-Language` header value, with the given options.
+///
+/// Entries are returned in the header order. Quality weights are handled as
+/// follows:
+/// - a missing or empty weight (`en`, `en;q=`) defaults to `1.0`;
+/// - a weight that is not of the form `0[.ddd]` or `1[.000]`, as defined by
+///   [RFC 9110 section 12.4.2](https://www.rfc-editor.org/rfc/rfc9110#section-12.4.2)
+///   (e.g. `en;q=abc`, `en;q=1.5` or `en;q=0.1234`), is handled according to
+///   [`Options::malformed_quality`].
+///
+/// Entries whose language range is not a well-formed language tag (or the
+/// `*` wildcard) are skipped.
+///
+/// ```
+/// use langtag::accept_language::{parse_with, LanguageRange, Options};
+///
+/// let entries: Vec<_> = parse_with("fr-CH, fr;q=0.9, en;q=abc, *;q=0.5", Options::default()).collect();
+/// assert_eq!(entries.len(), 3);
+/// assert_eq!(entries[1].quality, 0.9);
+/// assert_eq!(entries[2].range, LanguageRange::Wildcard);
+/// ```
+pub fn parse_with(header: &str, options: Options) -> impl Iterator<Item = Entry> + '_ {
+	header.split(',').filter_map(move |item| {
+		let mut parts = item.split(';');
+		let range = parts.next().unwrap().trim();
+
+		let mut quality = 1.0;
+		for param in parts {
+			if let Some((name, value)) = param.split_once('=') {
+				if name.trim().eq_ignore_ascii_case("q") {
+					quality = match parse_quality(value.trim()) {
+						Some(q) => q,
+						None => match options.malformed_quality {
+							MalformedQuality::Drop => return None,
+							MalformedQuality::Default => 1.0,
+						},
+					}
+				}
+			}
+		}
+
+		let range = if range == "*" {
+			LanguageRange::Wildcard
+		} else {
+			LanguageRange::Tag(LangTagBuf::new(range.to_owned()).ok()?)
+		};
+
+		Some(Entry { range, quality })
+	})
+}
+
+/// Parses a quality weight, returning `None` if it is malformed.
+///
+/// ```abnf
+/// qvalue = ( "0" [ "." 0*3DIGIT ] ) / ( "1" [ "." 0*3("0") ] )
+/// ```
+fn parse_quality(value: &str) -> Option<f32> {
+	if value.is_empty() {
+		return Some(1.0);
+	}
+
+	let (int, frac) = match value.split_once('.') {
+		Some((int, frac)) => (int, frac),
+		None => (value, ""),
+	};
+
+	if frac.len() > 3 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	match int {
+		"0" => value.parse().ok(),
+		"1" if frac.bytes().all(|b| b == b'0') => Some(1.0),
+		_ => None,
+	}
+}
@@ -0,0 +1,156 @@
+//! Generation of arbitrary language tags and components.
+use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{
+	ExtensionBuf, LangTagBuf, LanguageBuf, NormalLangTagBuf, PrivateUseBuf, RegionBuf, ScriptBuf,
+	VariantBuf, GRANDFATHERED,
+};
+
+const ALPHA: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGIT: &[u8] = b"0123456789";
+const ALPHANUM: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Pushes between `min` and `max` characters taken from `chars`.
+fn push_chars(
+	u: &mut Unstructured,
+	s: &mut String,
+	chars: &[u8],
+	min: usize,
+	max: usize,
+) -> Result<()> {
+	let len = u.int_in_range(min..=max)?;
+	for _ in 0..len {
+		s.push(*u.choose(chars)? as char)
+	}
+
+	Ok(())
+}
+
+fn push_language(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	match u.int_in_range(0u8..=2)? {
+		0 => {
+			push_chars(u, s, ALPHA, 2, 3)?;
+			for _ in 0..u.int_in_range(0..=3)? {
+				s.push('-');
+				push_chars(u, s, ALPHA, 3, 3)?
+			}
+
+			Ok(())
+		}
+		1 => push_chars(u, s, ALPHA, 4, 4),
+		_ => push_chars(u, s, ALPHA, 5, 8),
+	}
+}
+
+fn push_script(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	push_chars(u, s, ALPHA, 4, 4)
+}
+
+fn push_region(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	if u.arbitrary()? {
+		push_chars(u, s, ALPHA, 2, 2)
+	} else {
+		push_chars(u, s, DIGIT, 3, 3)
+	}
+}
+
+fn push_variant(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	if u.arbitrary()? {
+		push_chars(u, s, ALPHANUM, 5, 8)
+	} else {
+		push_chars(u, s, DIGIT, 1, 1)?;
+		push_chars(u, s, ALPHANUM, 3, 3)
+	}
+}
+
+fn push_extension(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	let singleton = *u.choose(ALPHANUM)?;
+	s.push(match singleton {
+		b'x' | b'X' => 'y',
+		c => c as char,
+	});
+
+	for _ in 0..u.int_in_range(1..=3)? {
+		s.push('-');
+		push_chars(u, s, ALPHANUM, 2, 8)?
+	}
+
+	Ok(())
+}
+
+fn push_private_use(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	s.push('x');
+	for _ in 0..u.int_in_range(1..=3)? {
+		s.push('-');
+		push_chars(u, s, ALPHANUM, 1, 8)?
+	}
+
+	Ok(())
+}
+
+fn push_normal(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	push_language(u, s)?;
+
+	if u.arbitrary()? {
+		s.push('-');
+		push_script(u, s)?
+	}
+
+	if u.arbitrary()? {
+		s.push('-');
+		push_region(u, s)?
+	}
+
+	for _ in 0..u.int_in_range(0..=2)? {
+		s.push('-');
+		push_variant(u, s)?
+	}
+
+	for _ in 0..u.int_in_range(0..=2)? {
+		s.push('-');
+		push_extension(u, s)?
+	}
+
+	if u.arbitrary()? {
+		s.push('-');
+		push_private_use(u, s)?
+	}
+
+	Ok(())
+}
+
+fn push_lang_tag(u: &mut Unstructured, s: &mut String) -> Result<()> {
+	match u.int_in_range(0u8..=9)? {
+		0 => push_private_use(u, s),
+		1 => {
+			s.push_str(u.choose(&GRANDFATHERED)?.as_str());
+			Ok(())
+		}
+		_ => push_normal(u, s),
+	}
+}
+
+macro_rules! arbitrary {
+	($($ty:ident: $f:ident),*) => {
+		$(
+			impl<'a> Arbitrary<'a> for $ty {
+				fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+					let mut s = String::new();
+					$f(u, &mut s)?;
+					Self::new(s).map_err(|_| Error::IncorrectFormat)
+				}
+			}
+		)*
+	};
+}
+
+arbitrary! {
+	LangTagBuf: push_lang_tag,
+	NormalLangTagBuf: push_normal,
+	LanguageBuf: push_language,
+	ScriptBuf: push_script,
+	RegionBuf: push_region,
+	VariantBuf: push_variant,
+	ExtensionBuf: push_extension,
+	PrivateUseBuf: push_private_use
+}
@@ -0,0 +1,184 @@
+//! Language tag builder.
+use crate::{
+	ExtendedLangTag, ExtensionSubtag, InvalidLangTag, LangTag, LangTagBuf, PrimaryLanguage,
+	PrivateUseSubtag, Region, Script, Singleton, Variant,
+};
+
+/// Language tag build error.
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+	/// No primary language subtag was given, although the tag is not a
+	/// private use tag.
+	#[error("missing primary language")]
+	MissingPrimaryLanguage,
+
+	/// Extended language subtags were given with a primary language of 4
+	/// characters or more.
+	#[error("extended language subtags require a 2 or 3 letters primary language")]
+	UnexpectedExtendedLanguage,
+
+	/// More than 3 extended language subtags were given.
+	#[error("too many extended language subtags")]
+	TooManyExtendedLanguages,
+
+	/// The assembled tag is not well-formed.
+	#[error(transparent)]
+	Invalid(#[from] InvalidLangTag<String>),
+}
+
+/// Language tag builder.
+///
+/// Assembles a language tag from typed components, formatting them in the
+/// order mandated by RFC 5646, and validating the result once.
+///
+/// ```
+/// use langtag::{ExtensionSubtag, LangTagBuilder, PrimaryLanguage, Region, Singleton};
+///
+/// let tag = LangTagBuilder::new()
+///   .primary_language(PrimaryLanguage::new("en").unwrap())
+///   .region(Some(Region::new("US").unwrap()))
+///   .push_extension(Singleton::new(b'u').unwrap(), ExtensionSubtag::new("ca").unwrap())
+///   .push_extension(Singleton::new(b'u').unwrap(), ExtensionSubtag::new("gregory").unwrap())
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(tag, "en-US-u-ca-gregory");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct LangTagBuilder<'a> {
+	primary_language: Option<&'a PrimaryLanguage>,
+	extended_languages: Vec<&'a ExtendedLangTag>,
+	script: Option<&'a Script>,
+	region: Option<&'a Region>,
+	variants: Vec<&'a Variant>,
+	extensions: Vec<(Singleton, Vec<&'a ExtensionSubtag>)>,
+	private_use: Vec<&'a PrivateUseSubtag>,
+}
+
+impl<'a> LangTagBuilder<'a> {
+	/// Creates a new empty builder.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the primary language subtag.
+	pub fn primary_language(&mut self, language: &'a PrimaryLanguage) -> &mut Self {
+		self.primary_language = Some(language);
+		self
+	}
+
+	/// Appends an extended language subtag.
+	pub fn extended_language(&mut self, extlang: &'a ExtendedLangTag) -> &mut Self {
+		self.extended_languages.push(extlang);
+		self
+	}
+
+	/// Sets or removes the script subtag.
+	pub fn script(&mut self, script: Option<&'a Script>) -> &mut Self {
+		self.script = script;
+		self
+	}
+
+	/// Sets or removes the region subtag.
+	pub fn region(&mut self, region: Option<&'a Region>) -> &mut Self {
+		self.region = region;
+		self
+	}
+
+	/// Appends a variant subtag.
+	pub fn push_variant(&mut self, variant: &'a Variant) -> &mut Self {
+		self.variants.push(variant);
+		self
+	}
+
+	/// Appends a subtag to the extension introduced by the given singleton
+	/// (ignoring case), creating the extension if needed.
+	///
+	/// Extensions are formatted in the order they are created.
+	pub fn push_extension(
+		&mut self,
+		singleton: Singleton,
+		subtag: &'a ExtensionSubtag,
+	) -> &mut Self {
+		let key = singleton.unwrap().to_ascii_lowercase();
+		match self
+			.extensions
+			.iter_mut()
+			.find(|(s, _)| s.unwrap().to_ascii_lowercase() == key)
+		{
+			Some((_, subtags)) => subtags.push(subtag),
+			None => self.extensions.push((singleton, vec![subtag])),
+		}
+
+		self
+	}
+
+	/// Appends a private use subtag.
+	///
+	/// Private use subtags are unconstrained, and may be repeated.
+	pub fn push_private_use(&mut self, subtag: &'a PrivateUseSubtag) -> &mut Self {
+		self.private_use.push(subtag);
+		self
+	}
+
+	/// Builds the language tag.
+	///
+	/// If no primary language is given, only private use subtags are
+	/// allowed, and a private use tag (`x-…`) is built.
+	pub fn build(&self) -> Result<LangTagBuf, BuildError> {
+		let mut result = String::new();
+
+		match self.primary_language {
+			Some(primary) => {
+				if !self.extended_languages.is_empty() && primary.len() > 3 {
+					return Err(BuildError::UnexpectedExtendedLanguage);
+				}
+
+				if self.extended_languages.len() > 3 {
+					return Err(BuildError::TooManyExtendedLanguages);
+				}
+
+				result.push_str(primary);
+			}
+			None => {
+				if !self.extended_languages.is_empty()
+					|| self.script.is_some()
+					|| self.region.is_some()
+					|| !self.variants.is_empty()
+					|| !self.extensions.is_empty()
+					|| self.private_use.is_empty()
+				{
+					return Err(BuildError::MissingPrimaryLanguage);
+				}
+			}
+		}
+
+		let mut push = |subtag: &str| {
+			if !result.is_empty() {
+				result.push('-')
+			}
+
+			result.push_str(subtag)
+		};
+
+		self.extended_languages.iter().for_each(|s| push(s));
+		self.script.into_iter().for_each(|s| push(s));
+		self.region.into_iter().for_each(|s| push(s));
+		self.variants.iter().for_each(|s| push(s));
+
+		for (singleton, subtags) in &self.extensions {
+			push(&singleton.to_string());
+			subtags.iter().for_each(|s| push(s))
+		}
+
+		if !self.private_use.is_empty() {
+			push("x");
+			self.private_use.iter().for_each(|s| push(s))
+		}
+
+		match LangTag::new(&result) {
+			Ok(_) => Ok(unsafe { LangTagBuf::new_unchecked(result) }),
+			Err(_) => Err(InvalidLangTag(result).into()),
+		}
+	}
+}
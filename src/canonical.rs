@@ -0,0 +1,973 @@
+//! Canonicalization of language tags.
+use std::{
+	cmp::Ordering,
+	collections::HashSet,
+	fmt,
+	hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "registry")]
+use crate::{registry, Language, GRANDFATHERED};
+use crate::{utils, Extension, LangTag, LangTagBuf, NormalLangTag, PrivateUseSubtag, TypedLangTag};
+
+/// Case of a canonical chunk.
+#[derive(Clone, Copy)]
+enum Case {
+	/// All lowercase.
+	Lower,
+
+	/// All uppercase.
+	Upper,
+
+	/// First letter uppercase, the rest lowercase.
+	Title,
+
+	/// Registered case, left as is.
+	Preserve,
+}
+
+impl Case {
+	fn apply(self, i: usize, b: u8) -> u8 {
+		match self {
+			Self::Lower => b.to_ascii_lowercase(),
+			Self::Upper => b.to_ascii_uppercase(),
+			Self::Title if i == 0 => b.to_ascii_uppercase(),
+			Self::Title => b.to_ascii_lowercase(),
+			Self::Preserve => b,
+		}
+	}
+}
+
+/// Visits the canonical form of the given tag, chunk by chunk.
+///
+/// Subtags are cased following RFC 5646 section 2.1.1 (lowercase, titlecase
+/// scripts, uppercase regions), extensions are sorted by singleton and
+/// grandfathered tags use their registered case.
+fn visit_canonical(tag: &LangTag, mut f: impl FnMut(&str, Case)) {
+	match tag.as_typed() {
+		TypedLangTag::Normal(t) => visit_canonical_normal(t, t.language().as_str(), true, f),
+		TypedLangTag::PrivateUse(t) => f(t.as_str(), Case::Lower),
+		TypedLangTag::Grandfathered(t) => f(t.as_str(), Case::Preserve),
+	}
+}
+
+/// Visits the canonical form of the given normal tag, using `language` as
+/// language subtags.
+///
+/// Extensions are kept in their original order unless `sort_extensions` is
+/// set.
+fn visit_canonical_normal(
+	tag: &NormalLangTag,
+	language: &str,
+	sort_extensions: bool,
+	mut f: impl FnMut(&str, Case),
+) {
+	f(language, Case::Lower);
+
+	if let Some(script) = tag.script() {
+		f("-", Case::Preserve);
+		f(script.as_str(), Case::Title)
+	}
+
+	if let Some(region) = tag.region() {
+		f("-", Case::Preserve);
+		f(region.as_str(), Case::Upper)
+	}
+
+	for variant in tag.variants().iter() {
+		f("-", Case::Preserve);
+		f(variant.as_str(), Case::Lower)
+	}
+
+	if sort_extensions {
+		for extension in tag.extensions().iter_sorted() {
+			f("-", Case::Preserve);
+			f(extension.as_str(), Case::Lower)
+		}
+	} else {
+		for extension in tag.extensions().iter() {
+			f("-", Case::Preserve);
+			f(extension.as_str(), Case::Lower)
+		}
+	}
+
+	if let Some(private_use) = tag.private_use() {
+		f("-", Case::Preserve);
+		f(private_use.as_str(), Case::Lower)
+	}
+}
+
+/// Returns the lowercase bytes of the given tag, with its extensions sorted
+/// by singleton.
+fn canonical_bytes(tag: &LangTag) -> impl Iterator<Item = u8> + '_ {
+	let (head, extensions, tail) = match tag.as_normal().map(NormalLangTag::extensions) {
+		Some(extensions) if !extensions.is_empty() => {
+			let start = extensions.as_ptr() as usize - tag.as_ptr() as usize;
+			let end = start + extensions.len();
+			(&tag.0[..(start - 1)], Some(extensions), &tag.0[end..])
+		}
+		_ => (tag.as_str(), None, ""),
+	};
+
+	let extensions = extensions
+		.into_iter()
+		.flat_map(|e| e.iter_sorted())
+		.flat_map(|e| std::iter::once(b'-').chain(e.as_str().bytes()));
+
+	head.bytes()
+		.chain(extensions)
+		.chain(tail.bytes())
+		.map(utils::into_smallcase)
+}
+
+/// Returns the language subtags without the primary language subtag if
+/// the first extended language subtag is registered with the primary
+/// language as prefix.
+#[cfg(feature = "registry")]
+pub(crate) fn collapsed_language(language: &Language) -> &str {
+	if let Some(extlang) = language.extension_subtags().next() {
+		let primary = language.primary();
+		if registry::extlang_prefix(extlang).is_some_and(|prefix| primary == prefix) {
+			return &language[(primary.len() + 1)..];
+		}
+	}
+
+	language
+}
+
+/// Collapses the primary and extended language subtags of the given tag, see
+/// [`LangTag::collapse_extlang`].
+#[cfg(feature = "registry")]
+fn collapse_extlang(tag: &LangTag) -> LangTagBuf {
+	if let Some(normal) = tag.as_normal() {
+		let language = normal.language();
+		let collapsed = collapsed_language(language);
+		if collapsed.len() != language.len() {
+			let result = tag.0[(language.len() - collapsed.len())..].to_owned();
+			return unsafe { LangTagBuf::new_unchecked(result) };
+		}
+	}
+
+	tag.to_owned()
+}
+
+/// Replaces the primary language subtag of the given tag by its
+/// `Preferred-Value`, see [`LangTag::with_canonical_language`].
+#[cfg(feature = "registry")]
+fn canonical_language(tag: &LangTag) -> LangTagBuf {
+	if let Some(normal) = tag.as_normal() {
+		let primary = normal.language().primary();
+		if let Some(preferred) = primary
+			.registry_fields()
+			.and_then(|(preferred, _)| preferred)
+		{
+			let mut result = String::with_capacity(tag.0.len());
+			result.push_str(preferred);
+			result.push_str(&tag.0[primary.len()..]);
+			if let Ok(result) = LangTagBuf::new(result) {
+				return result;
+			}
+		}
+	}
+
+	tag.to_owned()
+}
+
+/// Replaces the region subtag of the given tag by its `Preferred-Value`, see
+/// [`LangTag::with_canonical_region`].
+#[cfg(feature = "registry")]
+fn canonical_region(tag: &LangTag) -> LangTagBuf {
+	if let Some(normal) = tag.as_normal() {
+		if let Some(region) = normal.region() {
+			if let Some(preferred) = registry::region_preferred_value(region) {
+				let start = region.as_ptr() as usize - tag.0.as_ptr() as usize;
+				let mut result = String::with_capacity(tag.0.len());
+				result.push_str(&tag.0[..start]);
+				result.push_str(preferred);
+				result.push_str(&tag.0[(start + region.len())..]);
+				return unsafe { LangTagBuf::new_unchecked(result) };
+			}
+		}
+	}
+
+	tag.to_owned()
+}
+
+/// Checks if the default canonicalization of the given tag replaces some of
+/// its subtags using the registry.
+#[cfg(feature = "registry")]
+fn has_registry_replacement(tag: &LangTag) -> bool {
+	if tag
+		.as_grandfathered()
+		.is_some_and(|g| g.preferred_value_str().is_some())
+	{
+		return true;
+	}
+
+	match tag.as_normal() {
+		Some(t) => {
+			let language = t.language();
+			collapsed_language(language).len() != language.len()
+				|| language
+					.primary()
+					.registry_fields()
+					.is_some_and(|(preferred, _)| preferred.is_some())
+				|| t.region()
+					.is_some_and(|r| registry::region_preferred_value(r).is_some())
+		}
+		None => false,
+	}
+}
+
+/// Sorts the private use subtags of the given tag, see
+/// [`LangTag::with_sorted_private_use`].
+fn sort_private_use(tag: &LangTag) -> LangTagBuf {
+	let private_use = match tag.as_typed() {
+		TypedLangTag::Normal(t) => t.private_use(),
+		TypedLangTag::PrivateUse(t) => Some(t),
+		TypedLangTag::Grandfathered(_) => None,
+	};
+
+	match private_use {
+		Some(private_use) => {
+			let mut subtags: Vec<_> = private_use.iter().map(PrivateUseSubtag::as_str).collect();
+			subtags.sort_by_cached_key(|s| s.to_ascii_lowercase());
+
+			let start = private_use.as_ptr() as usize - tag.0.as_ptr() as usize;
+			let mut result = String::with_capacity(tag.0.len());
+			result.push_str(&tag.0[..start]);
+			result.push('x');
+			for subtag in subtags {
+				result.push('-');
+				result.push_str(subtag)
+			}
+
+			unsafe { LangTagBuf::new_unchecked(result) }
+		}
+		None => tag.to_owned(),
+	}
+}
+
+/// Canonicalization options, see [`LangTag::canonicalize_with`].
+///
+/// The default options produce the canonical form described by RFC 5646
+/// section 4.5: subtags are cased, extensions sorted, extended language
+/// subtags collapsed, and deprecated grandfathered tags, primary languages
+/// and regions replaced by their `Preferred-Value`. Redundant tags are only
+/// replaced when they are made of extended language subtags (`zh-cmn-Hans`
+/// becomes `cmn-Hans`), since other redundant tags are not embedded.
+/// Removing the `Suppress-Script` and sorting private use subtags go beyond
+/// the RFC, and are disabled by default.
+///
+/// Options relying on the registry (extended language subtags, grandfathered
+/// tags, languages, regions and `Suppress-Script`) have no effect without
+/// the `registry` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CanonicalizeOptions {
+	/// Normalize the case of subtags (`en-Latn-US`).
+	pub case: bool,
+
+	/// Sort extensions by singleton.
+	pub sort_extensions: bool,
+
+	/// Remove the script subtag if it is the `Suppress-Script` of the
+	/// language (`en-Latn` becomes `en`).
+	pub remove_suppressed_script: bool,
+
+	/// Collapse the primary and extended language subtags (`zh-cmn` becomes
+	/// `cmn`).
+	pub collapse_extlang: bool,
+
+	/// Replace grandfathered tags by their `Preferred-Value`.
+	pub replace_grandfathered: bool,
+
+	/// Replace deprecated primary languages by their `Preferred-Value`
+	/// (`iw` becomes `he`).
+	pub canonical_language: bool,
+
+	/// Replace deprecated regions by their `Preferred-Value`.
+	pub canonical_region: bool,
+
+	/// Sort private use subtags.
+	pub sort_private_use: bool,
+}
+
+impl CanonicalizeOptions {
+	/// Options disabling every transformation.
+	///
+	/// This is useful to enable only some of them:
+	///
+	/// ```
+	/// use langtag::CanonicalizeOptions;
+	///
+	/// let options = CanonicalizeOptions {
+	///     case: true,
+	///     ..CanonicalizeOptions::NONE
+	/// };
+	/// ```
+	pub const NONE: Self = Self {
+		case: false,
+		sort_extensions: false,
+		remove_suppressed_script: false,
+		collapse_extlang: false,
+		replace_grandfathered: false,
+		canonical_language: false,
+		canonical_region: false,
+		sort_private_use: false,
+	};
+}
+
+impl Default for CanonicalizeOptions {
+	fn default() -> Self {
+		Self {
+			case: true,
+			sort_extensions: true,
+			remove_suppressed_script: false,
+			collapse_extlang: true,
+			replace_grandfathered: true,
+			canonical_language: true,
+			canonical_region: true,
+			sort_private_use: false,
+		}
+	}
+}
+
+impl LangTag {
+	/// Returns the canonical form of this tag according to the given options.
+	///
+	/// ```
+	/// use langtag::{CanonicalizeOptions, LangTag};
+	///
+	/// let tag = LangTag::new("EN-b-ccc-a-bbb-x-foo-bar").unwrap();
+	/// assert_eq!(tag.canonicalize_with(CanonicalizeOptions::default()), "en-a-bbb-b-ccc-x-foo-bar");
+	///
+	/// let options = CanonicalizeOptions {
+	///   case: false,
+	///   sort_private_use: true,
+	///   ..Default::default()
+	/// };
+	/// assert_eq!(tag.canonicalize_with(options), "EN-a-bbb-b-ccc-x-bar-foo");
+	/// ```
+	pub fn canonicalize_with(&self, options: CanonicalizeOptions) -> LangTagBuf {
+		let mut tag = self.to_owned();
+
+		#[cfg(feature = "registry")]
+		{
+			if options.replace_grandfathered {
+				if let Some(preferred) = self
+					.as_grandfathered()
+					.and_then(|g| g.preferred_value_str())
+				{
+					tag = unsafe { LangTagBuf::new_unchecked(preferred.to_owned()) }
+				}
+			}
+
+			if options.collapse_extlang {
+				tag = collapse_extlang(&tag)
+			}
+
+			if options.canonical_language {
+				tag = canonical_language(&tag)
+			}
+
+			if options.canonical_region {
+				tag = canonical_region(&tag)
+			}
+
+			if options.remove_suppressed_script {
+				if let Some(t) = tag.remove_suppressed_script() {
+					tag = t
+				}
+			}
+		}
+
+		if options.sort_private_use {
+			tag = sort_private_use(&tag)
+		}
+
+		let mut result = String::with_capacity(tag.len());
+		let write = |chunk: &str, case: Case| {
+			let case = if options.case { case } else { Case::Preserve };
+			result.extend(
+				chunk
+					.bytes()
+					.enumerate()
+					.map(|(i, b)| case.apply(i, b) as char),
+			)
+		};
+
+		if let Some(t) = tag.as_normal() {
+			visit_canonical_normal(t, t.language().as_str(), options.sort_extensions, write)
+		} else if options.case {
+			visit_canonical(&tag, write)
+		} else {
+			return tag;
+		}
+
+		unsafe { LangTagBuf::new_unchecked(result) }
+	}
+
+	/// Returns a copy of this tag with the canonical case of RFC 5646
+	/// section 2.1.1, leaving the subtags order intact.
+	///
+	/// Language, variant, extension and private use subtags are lowercased,
+	/// script subtags titlecased and region subtags uppercased. Grandfathered
+	/// tags use their registered case (`en-GB-oed`). The result is equal to
+	/// this tag.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("DE-aRaB-LY-b-CCC-a-BBB").unwrap();
+	/// assert_eq!(tag.to_canonical().as_str(), "de-Arab-LY-b-ccc-a-bbb");
+	/// ```
+	pub fn to_canonical(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			case: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Returns the recommended form of this tag to persist, e.g. in a
+	/// database.
+	///
+	/// The stored form is the tag, all lowercase, with its extensions sorted
+	/// by singleton. It does not depend on registry data, so that two
+	/// canonically equal tags (see [`Self::canonical_eq`]) are always stored
+	/// the same way, regardless of the enabled features. This format is
+	/// stable across versions, so that stored tags remain comparable.
+	///
+	/// This is case-lossy by design: the stored form is not the canonical
+	/// form of RFC 5646 (see [`Self::canonicalize_with`] for that).
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("EN-Latn-US-b-CCC-a-bbb").unwrap();
+	/// assert_eq!(tag.store().as_str(), "en-latn-us-a-bbb-b-ccc");
+	/// ```
+	pub fn store(&self) -> LangTagBuf {
+		let mut result = self
+			.canonicalize_with(CanonicalizeOptions {
+				sort_extensions: true,
+				..CanonicalizeOptions::NONE
+			})
+			.into_string();
+		result.make_ascii_lowercase();
+		unsafe { LangTagBuf::new_unchecked(result) }
+	}
+
+	/// Returns a deterministic key identifying this tag, suitable for maps and
+	/// file names.
+	///
+	/// This is the [stored form](Self::store) of the tag, and is stable across
+	/// versions.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("EN-Latn-US-b-CCC-a-bbb").unwrap();
+	/// assert_eq!(tag.cache_key(), "en-latn-us-a-bbb-b-ccc");
+	/// ```
+	pub fn cache_key(&self) -> String {
+		self.store().into_string()
+	}
+
+	/// Checks that this tag and `other` have the same canonical form.
+	///
+	/// Contrarily to `==`, which only ignores case, this also ignores the
+	/// order of extensions. For instance `en-b-ccc-a-bbb` and `EN-a-bbb-b-ccc`
+	/// are canonically equal.
+	pub fn canonical_eq(&self, other: &Self) -> bool {
+		match (self.as_typed(), other.as_typed()) {
+			(TypedLangTag::Normal(a), TypedLangTag::Normal(b)) => {
+				a.language() == b.language()
+					&& a.script() == b.script()
+					&& a.region() == b.region()
+					&& a.variants() == b.variants()
+					&& a.extensions()
+						.iter_sorted()
+						.eq(b.extensions().iter_sorted())
+					&& a.private_use() == b.private_use()
+			}
+			(TypedLangTag::PrivateUse(a), TypedLangTag::PrivateUse(b)) => a == b,
+			(TypedLangTag::Grandfathered(a), TypedLangTag::Grandfathered(b)) => a == b,
+			_ => false,
+		}
+	}
+
+	/// Checks that this tag is already in canonical form, without allocating.
+	///
+	/// This is the case when language, variant, extension and private use
+	/// subtags are lowercase, script subtags titlecase, region subtags
+	/// uppercase, extensions sorted by singleton, and grandfathered tags in
+	/// their registered case. Registry based replacements (see
+	/// [`Self::write_canonical`]) are not checked.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// assert!(LangTag::new("en-Latn-US-a-bbb-b-ccc").unwrap().is_canonical());
+	/// assert!(!LangTag::new("en-latn-US").unwrap().is_canonical());
+	/// assert!(!LangTag::new("en-b-ccc-a-bbb").unwrap().is_canonical());
+	/// ```
+	pub fn is_canonical(&self) -> bool {
+		let mut bytes = self.as_bytes().iter();
+		let mut canonical = true;
+		visit_canonical(self, |chunk, case| {
+			if canonical {
+				canonical = chunk
+					.bytes()
+					.enumerate()
+					.all(|(i, b)| bytes.next() == Some(&case.apply(i, b)))
+			}
+		});
+
+		canonical
+	}
+
+	/// Compares this tag and `other` by their canonical form.
+	///
+	/// Contrarily to [`Ord`], which compares the tags as strings (ignoring
+	/// case), this also ignores the order of extensions. It is consistent
+	/// with [`Self::canonical_eq`]: `en-b-ccc-a-bbb` and `EN-a-bbb-b-ccc`
+	/// compare equal, and both come before `en-a-ccc`.
+	///
+	/// This does not allocate.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let mut tags = ["en-b-ccc-a-zzz", "en-a-bbb", "de"].map(|t| LangTag::new(t).unwrap());
+	/// tags.sort_by(|a, b| a.canonical_cmp(b));
+	/// assert_eq!(tags, ["de", "en-a-bbb", "en-b-ccc-a-zzz"]);
+	/// ```
+	pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+		canonical_bytes(self).cmp(canonical_bytes(other))
+	}
+
+	/// Feeds the canonical form of this tag into the given hasher.
+	///
+	/// The same bytes are produced regardless of the input case or extension
+	/// ordering, so that two canonically equal tags (see
+	/// [`Self::canonical_eq`]) produce the same hash. This is useful to build
+	/// composite keys combining a tag with other data in a single hasher.
+	/// See [`Canonical`] to hash a tag field of a `#[derive(Hash)]` type.
+	///
+	/// Like [`str`], the bytes are followed by a `0xff` terminator, so that
+	/// the tag cannot run into the data hashed after it.
+	pub fn hash_canonical_into<H: Hasher>(&self, state: &mut H) {
+		visit_canonical(self, |chunk, case| {
+			for (i, b) in chunk.bytes().enumerate() {
+				case.apply(i, b).hash(state)
+			}
+		});
+		state.write_u8(0xff)
+	}
+
+	/// Writes the canonical form of this tag into `w`.
+	///
+	/// This is the same as [`Self::canonicalize_with`] with the default
+	/// [`CanonicalizeOptions`]:
+	/// - language, variant, extension and private use subtags are lowercased;
+	/// - script subtags are titlecased (`Latn`);
+	/// - region subtags are uppercased (`US`);
+	/// - extensions are sorted by singleton;
+	/// - grandfathered tags use their registered case (`i-default`).
+	///
+	/// With the `registry` feature, extended language subtags are collapsed
+	/// and deprecated grandfathered tags, primary languages and regions are
+	/// replaced by their `Preferred-Value` (`iw-IL` becomes `he-IL`). This
+	/// only allocates when such a replacement happens.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let mut s = String::new();
+	/// LangTag::new("EN-latn-us-b-CCC-a-bbb").unwrap().write_canonical(&mut s).unwrap();
+	/// assert_eq!(s, "en-Latn-US-a-bbb-b-ccc");
+	/// ```
+	pub fn write_canonical<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+		#[cfg(feature = "registry")]
+		if has_registry_replacement(self) {
+			return w.write_str(&self.canonicalize_with(CanonicalizeOptions::default()));
+		}
+
+		let mut result = Ok(());
+		let write = |chunk: &str, case: Case| {
+			for (i, b) in chunk.bytes().enumerate() {
+				if result.is_ok() {
+					result = w.write_char(case.apply(i, b) as char)
+				}
+			}
+		};
+
+		match self.as_normal() {
+			Some(t) => visit_canonical_normal(t, t.language().as_str(), true, write),
+			None => visit_canonical(self, write),
+		}
+
+		result
+	}
+
+	/// Writes the canonical form of this tag (see [`Self::write_canonical`])
+	/// into `out`, replacing its content but reusing its allocation.
+	///
+	/// This avoids allocation churn when canonicalizing many tags.
+	///
+	/// ```
+	/// use langtag::{LangTag, LangTagBuf};
+	///
+	/// let mut out = LangTagBuf::new("und".to_owned()).unwrap();
+	/// let mut results = Vec::new();
+	/// for tag in ["EN-us", "fr-b-bbb-a-aaa"] {
+	///   LangTag::new(tag).unwrap().canonicalize_into(&mut out);
+	///   results.push(out.to_string());
+	/// }
+	///
+	/// assert_eq!(results, ["en-US", "fr-a-aaa-b-bbb"]);
+	/// ```
+	pub fn canonicalize_into(&self, out: &mut LangTagBuf) {
+		// The canonical form of a valid tag is always valid.
+		out.0.clear();
+		self.write_canonical(&mut out.0).unwrap()
+	}
+}
+
+impl LangTagBuf {
+	/// Returns a copy of this tag with the canonical case, see
+	/// [`LangTag::to_canonical`].
+	pub fn canonical(&self) -> LangTagBuf {
+		self.to_canonical()
+	}
+
+	/// Reorders the extensions of this tag by singleton (ignoring case), in
+	/// place.
+	///
+	/// The subtags of each extension and the private use subtags are left
+	/// untouched.
+	///
+	/// ```
+	/// use langtag::LangTagBuf;
+	///
+	/// let mut tag: LangTagBuf = "fr-b-ext3-a-ext1-x-foo".parse().unwrap();
+	/// tag.sort_extensions();
+	/// assert_eq!(tag, "fr-a-ext1-b-ext3-x-foo");
+	/// ```
+	pub fn sort_extensions(&mut self) {
+		let extensions = self.extensions();
+		if extensions.is_canonically_ordered() {
+			return;
+		}
+
+		let start = extensions.as_ptr() as usize - self.0.as_ptr() as usize;
+		let end = start + extensions.len();
+		let sorted = extensions
+			.iter_sorted()
+			.map(Extension::as_str)
+			.collect::<Vec<_>>()
+			.join("-");
+
+		self.0.replace_range(start..end, &sorted)
+	}
+}
+
+#[cfg(feature = "registry")]
+impl LangTag {
+	/// Collapses the primary and extended language subtags into the single
+	/// extended language subtag, e.g. `zh-cmn-Hant` into `cmn-Hant`.
+	///
+	/// This is the canonical form of extended language subtags according to
+	/// RFC 5646. Tags whose first extended language subtag is not registered
+	/// with the primary language as prefix are returned unchanged.
+	pub fn collapse_extlang(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			collapse_extlang: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Expands a primary language subtag that is a registered extended
+	/// language subtag into its prefixed form, e.g. `cmn-Hant` into
+	/// `zh-cmn-Hant`.
+	///
+	/// This is the inverse of [`Self::collapse_extlang`], and goes in the
+	/// *non-canonical* direction: RFC 5646 prefers the collapsed form. It is
+	/// only useful to interoperate with legacy systems expecting the
+	/// macrolanguage prefix. Tags that already have extended language subtags,
+	/// or whose primary language is not a registered extended language subtag,
+	/// are returned unchanged.
+	pub fn expand_extlang(&self) -> LangTagBuf {
+		if let Some(normal) = self.as_normal() {
+			let language = normal.language();
+			if language.extension().is_none() {
+				if let Some(prefix) = registry::extlang_prefix(language.primary()) {
+					let mut result = String::with_capacity(prefix.len() + 1 + self.0.len());
+					result.push_str(prefix);
+					result.push('-');
+					result.push_str(&self.0);
+					return unsafe { LangTagBuf::new_unchecked(result) };
+				}
+			}
+		}
+
+		self.to_owned()
+	}
+
+	/// Returns the most minimal equivalent form of this tag, by removing its
+	/// redundant subtags.
+	///
+	/// The following transformations are applied, in order:
+	/// 1. the primary and extended language subtags are collapsed into the
+	///    extended language subtag (see [`Self::collapse_extlang`]), e.g.
+	///    `zh-cmn-Hans` becomes `cmn-Hans`;
+	/// 2. the script subtag is removed if it is the `Suppress-Script` of the
+	///    language, e.g. `en-Latn-US` becomes `en-US`.
+	///
+	/// Contrarily to likely subtags minimization, the result is always
+	/// equivalent according to the registry. The tag is returned unchanged
+	/// when nothing is redundant.
+	pub fn remove_redundant(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			collapse_extlang: true,
+			remove_suppressed_script: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Replaces a deprecated primary language subtag by its registered
+	/// `Preferred-Value`, e.g. `iw-IL` becomes `he-IL`.
+	///
+	/// Tags whose primary language is not deprecated are returned unchanged.
+	pub fn with_canonical_language(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			canonical_language: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Replaces a deprecated region subtag by its registered
+	/// `Preferred-Value`, e.g. `my-BU` becomes `my-MM`.
+	///
+	/// Tags without region, or whose region is not deprecated, are returned
+	/// unchanged. Deprecated regions without preferred value (such as `YU`)
+	/// are kept as is.
+	pub fn with_canonical_region(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			canonical_region: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Returns the known forms of this tag that mean the same thing,
+	/// including the tag itself.
+	///
+	/// This includes the grandfathered tags and their preferred value,
+	/// the collapsed and expanded extended language forms and the forms with
+	/// and without the `Suppress-Script` of the language, in any combination.
+	/// Each equivalent appears once (ignoring case). This is useful to match a
+	/// tag against a database that may store any equivalent form.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let equivalents = LangTag::new("no-bok").unwrap().equivalents();
+	/// assert_eq!(equivalents, ["no-bok", "nb", "nb-Latn"]);
+	/// ```
+	pub fn equivalents(&self) -> Vec<LangTagBuf> {
+		let mut result = vec![self.to_owned()];
+		let mut i = 0;
+
+		while i < result.len() {
+			let tag = result[i].as_lang_tag();
+			let mut candidates = Vec::new();
+
+			match tag.as_grandfathered() {
+				Some(g) => candidates.extend(
+					g.preferred_value_str()
+						.map(|p| LangTagBuf::new(p.to_owned()).unwrap()),
+				),
+				None => {
+					candidates.extend(GRANDFATHERED.iter().filter_map(|g| {
+						g.preferred_value_str()
+							.filter(|p| *tag == **p)
+							.map(|_| LangTagBuf::new(g.as_str().to_owned()).unwrap())
+					}));
+					candidates.push(tag.collapse_extlang());
+					candidates.push(tag.expand_extlang());
+					candidates.extend(tag.toggle_suppress_script());
+				}
+			}
+
+			for candidate in candidates {
+				if !result.contains(&candidate) {
+					result.push(candidate)
+				}
+			}
+
+			i += 1
+		}
+
+		result
+	}
+
+	/// Removes the script subtag if it is the `Suppress-Script` of the
+	/// language.
+	fn remove_suppressed_script(&self) -> Option<LangTagBuf> {
+		let normal = self.as_normal()?;
+		if normal
+			.script()?
+			.is_suppress_script_for(normal.language().primary())
+		{
+			Some(normal.without_suppressed_script())
+		} else {
+			None
+		}
+	}
+
+	/// Removes the script subtag if it is the `Suppress-Script` of the
+	/// language, or adds it if missing.
+	fn toggle_suppress_script(&self) -> Option<LangTagBuf> {
+		let normal = self.as_normal()?;
+		let language = normal.language();
+		let suppress_script = registry::suppress_script(language.primary())?;
+		let language_end = language.len();
+
+		if normal.script().is_some() {
+			return self.remove_suppressed_script();
+		}
+
+		let mut result = self.0[..language_end].to_owned();
+		result.push('-');
+		result.push_str(suppress_script);
+		result.push_str(&self.0[language_end..]);
+		Some(unsafe { LangTagBuf::new_unchecked(result) })
+	}
+}
+
+#[cfg(feature = "registry")]
+impl NormalLangTag {
+	/// Returns a copy of this tag without its script subtag if it is the
+	/// `Suppress-Script` of the language.
+	///
+	/// ```
+	/// use langtag::NormalLangTag;
+	///
+	/// let tag = NormalLangTag::new("en-Latn-US").unwrap();
+	/// assert_eq!(tag.without_suppressed_script(), "en-US");
+	///
+	/// let tag = NormalLangTag::new("sr-Latn-RS").unwrap();
+	/// assert_eq!(tag.without_suppressed_script(), "sr-Latn-RS");
+	/// ```
+	pub fn without_suppressed_script(&self) -> LangTagBuf {
+		let result = match self.script() {
+			Some(script) if script.is_suppress_script_for(self.language().primary()) => {
+				let language_end = self.language().len();
+				let mut result = self.as_str()[..language_end].to_owned();
+				result.push_str(&self.as_str()[(language_end + 1 + script.len())..]);
+				result
+			}
+			_ => self.as_str().to_owned(),
+		};
+
+		unsafe { LangTagBuf::new_unchecked(result) }
+	}
+}
+
+/// Canonicalizes every given tag with the given options, see
+/// [`LangTag::canonicalize_with`].
+///
+/// The output preserves the input order.
+pub fn canonicalize_all<'a>(
+	tags: impl IntoIterator<Item = &'a LangTag>,
+	options: CanonicalizeOptions,
+) -> Vec<LangTagBuf> {
+	tags.into_iter()
+		.map(|tag| tag.canonicalize_with(options))
+		.collect()
+}
+
+/// Canonicalizes every given tag with the given options, removing
+/// canonically equal duplicates.
+///
+/// The output preserves the input order, keeping the first occurrence of
+/// each tag.
+///
+/// ```
+/// use langtag::{canonicalize_and_dedup, CanonicalizeOptions, LangTag};
+///
+/// let tags = ["en-US", "fr", "EN-us", "en"].map(|t| LangTag::new(t).unwrap());
+/// let result = canonicalize_and_dedup(tags, CanonicalizeOptions::default());
+/// assert_eq!(result, ["en-US", "fr", "en"]);
+/// ```
+pub fn canonicalize_and_dedup<'a>(
+	tags: impl IntoIterator<Item = &'a LangTag>,
+	options: CanonicalizeOptions,
+) -> Vec<LangTagBuf> {
+	let tags = canonicalize_all(tags, options);
+	let mut seen = HashSet::with_capacity(tags.len());
+	let keep: Vec<bool> = tags
+		.iter()
+		.map(|tag| seen.insert(Canonical(tag.as_lang_tag())))
+		.collect();
+
+	tags.into_iter()
+		.zip(keep)
+		.filter_map(|(tag, keep)| keep.then_some(tag))
+		.collect()
+}
+
+/// Language tag compared and hashed by canonical form.
+///
+/// Two wrapped tags are equal if they are canonically equal (see
+/// [`LangTag::canonical_eq`]), and they are hashed using
+/// [`LangTag::hash_canonical_into`]. This can be used as a field type in
+/// structures deriving `PartialEq`, `Eq` and `Hash`.
+///
+/// ```
+/// use langtag::{Canonical, LangTag};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Canonical(LangTag::new("en-b-ccc-a-bbb").unwrap()));
+/// assert!(set.contains(&Canonical(LangTag::new("EN-a-bbb-b-ccc").unwrap())));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Canonical<T>(pub T);
+
+impl<T: AsRef<LangTag>> PartialEq for Canonical<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref().canonical_eq(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<LangTag>> Eq for Canonical<T> {}
+
+impl<T: AsRef<LangTag>> Hash for Canonical<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.as_ref().hash_canonical_into(state)
+	}
+}
+
+/// Serializes the canonical form of the tag (see [`LangTag::write_canonical`]).
+#[cfg(feature = "serde")]
+impl<T: AsRef<LangTag>> serde::Serialize for Canonical<T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut canonical = String::new();
+		self.0.as_ref().write_canonical(&mut canonical).unwrap();
+		serializer.serialize_str(&canonical)
+	}
+}
+
+/// Deserializes a language tag and canonicalizes it (see
+/// [`LangTag::write_canonical`]), so that `en-us` and `EN-US` both give
+/// `en-US`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Canonical<LangTagBuf> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let tag = LangTagBuf::deserialize(deserializer)?;
+		let mut canonical = String::new();
+		tag.write_canonical(&mut canonical).unwrap();
+		Ok(Self(unsafe { LangTagBuf::new_unchecked(canonical) }))
+	}
+}
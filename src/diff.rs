@@ -0,0 +1,158 @@
+//! Component-wise differences between language tags.
+use std::fmt;
+
+use crate::{Extensions, LangTag, Language, PrivateUse, Region, Script, Variants};
+
+/// Differing values of a language tag component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentDiff<T> {
+	/// Value in the first tag.
+	pub left: T,
+
+	/// Value in the second tag.
+	pub right: T,
+}
+
+impl<T: PartialEq> ComponentDiff<T> {
+	fn new(left: T, right: T) -> Option<Self> {
+		if left == right {
+			None
+		} else {
+			Some(Self { left, right })
+		}
+	}
+}
+
+/// Component-wise difference between two language tags.
+///
+/// Each field is `None` if the component is equal (ignoring case) in both
+/// tags, or gives the two differing values. See [`LangTag::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagDiff<'a> {
+	/// Language subtags.
+	pub language: Option<ComponentDiff<Option<&'a Language>>>,
+
+	/// Script subtag.
+	pub script: Option<ComponentDiff<Option<&'a Script>>>,
+
+	/// Region subtag.
+	pub region: Option<ComponentDiff<Option<&'a Region>>>,
+
+	/// Variant subtags.
+	pub variants: Option<ComponentDiff<&'a Variants>>,
+
+	/// Extension subtags.
+	pub extensions: Option<ComponentDiff<&'a Extensions>>,
+
+	/// Private use subtags.
+	pub private_use: Option<ComponentDiff<Option<&'a PrivateUse>>>,
+}
+
+impl TagDiff<'_> {
+	/// Checks that all the components are equal.
+	pub fn is_empty(&self) -> bool {
+		self.language.is_none()
+			&& self.script.is_none()
+			&& self.region.is_none()
+			&& self.variants.is_none()
+			&& self.extensions.is_none()
+			&& self.private_use.is_none()
+	}
+}
+
+impl LangTag {
+	/// Compares the components of this tag with the components of `other`,
+	/// ignoring case.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let a = LangTag::new("en-US").unwrap();
+	/// let b = LangTag::new("en-GB").unwrap();
+	/// let diff = a.diff(b);
+	/// assert!(diff.language.is_none());
+	/// assert_eq!(diff.to_string(), "region: US vs GB");
+	/// ```
+	pub fn diff<'a>(&'a self, other: &'a Self) -> TagDiff<'a> {
+		TagDiff {
+			language: ComponentDiff::new(self.language(), other.language()),
+			script: ComponentDiff::new(self.script(), other.script()),
+			region: ComponentDiff::new(self.region(), other.region()),
+			variants: ComponentDiff::new(self.variants(), other.variants()),
+			extensions: ComponentDiff::new(self.extensions(), other.extensions()),
+			private_use: ComponentDiff::new(self.private_use(), other.private_use()),
+		}
+	}
+}
+
+/// Displays a possibly missing or empty component.
+fn display_component(value: Option<&str>) -> &str {
+	match value {
+		Some(value) if !value.is_empty() => value,
+		_ => "none",
+	}
+}
+
+impl fmt::Display for TagDiff<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let components = [
+			(
+				"language",
+				self.language
+					.map(|d| (d.left.map(Language::as_str), d.right.map(Language::as_str))),
+			),
+			(
+				"script",
+				self.script
+					.map(|d| (d.left.map(Script::as_str), d.right.map(Script::as_str))),
+			),
+			(
+				"region",
+				self.region
+					.map(|d| (d.left.map(Region::as_str), d.right.map(Region::as_str))),
+			),
+			(
+				"variants",
+				self.variants
+					.map(|d| (Some(d.left.as_str()), Some(d.right.as_str()))),
+			),
+			(
+				"extensions",
+				self.extensions
+					.map(|d| (Some(d.left.as_str()), Some(d.right.as_str()))),
+			),
+			(
+				"private use",
+				self.private_use.map(|d| {
+					(
+						d.left.map(PrivateUse::as_str),
+						d.right.map(PrivateUse::as_str),
+					)
+				}),
+			),
+		];
+
+		let mut first = true;
+		for (name, diff) in components {
+			if let Some((left, right)) = diff {
+				if !first {
+					f.write_str(", ")?
+				}
+
+				write!(
+					f,
+					"{name}: {} vs {}",
+					display_component(left),
+					display_component(right)
+				)?;
+				first = false
+			}
+		}
+
+		if first {
+			f.write_str("no difference")
+		} else {
+			Ok(())
+		}
+	}
+}
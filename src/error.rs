@@ -0,0 +1,190 @@
+//! Diagnostics for invalid language tags.
+use std::fmt;
+
+use crate::{InvalidLangTag, SubtagKind};
+
+/// Reason why a language tag is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidLangTagKind {
+	/// The input is empty.
+	Empty,
+
+	/// A subtag is not made of 1 to 8 ASCII letters and digits, for instance
+	/// an empty subtag (`en--US`), a subtag that is too long or containing
+	/// an invalid character (`en-U$`).
+	MalformedSubtag,
+
+	/// A well-formed subtag is not allowed at its position, for instance
+	/// trailing subtags after an otherwise valid tag (`en-US-foo`).
+	UnexpectedSubtag,
+
+	/// The input ends prematurely, after an extension or private use
+	/// singleton without subtags (`en-a`, `en-x`).
+	Incomplete,
+}
+
+impl fmt::Display for InvalidLangTagKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Empty => write!(f, "empty language tag"),
+			Self::MalformedSubtag => write!(f, "malformed subtag"),
+			Self::UnexpectedSubtag => write!(f, "unexpected subtag"),
+			Self::Incomplete => write!(f, "incomplete language tag"),
+		}
+	}
+}
+
+impl<T: AsRef<[u8]>> InvalidLangTag<T> {
+	/// Returns the byte offset of the first subtag rejected by the grammar.
+	///
+	/// If the input ends prematurely (e.g. `en-a`), this is the length of
+	/// the input.
+	///
+	/// ```
+	/// use langtag::{LangTag, SubtagKind};
+	///
+	/// let error = LangTag::new("en-US-foo!").unwrap_err();
+	/// assert_eq!(error.position(), 6);
+	/// assert_eq!(error.expected(), SubtagKind::Variant);
+	/// ```
+	pub fn position(&self) -> usize {
+		diagnose(self.0.as_ref()).position
+	}
+
+	/// Returns the first component that could have been found at
+	/// [`Self::position`].
+	pub fn expected(&self) -> SubtagKind {
+		diagnose(self.0.as_ref()).expected
+	}
+
+	/// Returns the reason why the input is not a valid language tag.
+	///
+	/// ```
+	/// use langtag::{InvalidLangTagKind, LangTag};
+	///
+	/// assert_eq!(LangTag::new("").unwrap_err().kind(), InvalidLangTagKind::Empty);
+	/// assert_eq!(LangTag::new("en-U$").unwrap_err().kind(), InvalidLangTagKind::MalformedSubtag);
+	/// assert_eq!(LangTag::new("en-US-foo").unwrap_err().kind(), InvalidLangTagKind::UnexpectedSubtag);
+	/// assert_eq!(LangTag::new("en-x").unwrap_err().kind(), InvalidLangTagKind::Incomplete);
+	/// ```
+	pub fn kind(&self) -> InvalidLangTagKind {
+		diagnose(self.0.as_ref()).kind
+	}
+}
+
+/// Diagnostic of an invalid language tag.
+struct Diagnostic {
+	position: usize,
+	expected: SubtagKind,
+	kind: InvalidLangTagKind,
+}
+
+/// Parsing state, after the last subtag read.
+#[derive(Clone, Copy)]
+enum State {
+	Start,
+	Language { extlangs: Option<u8> },
+	Script,
+	Region,
+	Variant,
+	Singleton,
+	Extension,
+	PrivateUseSingleton,
+	PrivateUse,
+}
+
+impl State {
+	/// Returns the first component that can follow this state.
+	fn expected(self) -> SubtagKind {
+		match self {
+			Self::Start => SubtagKind::PrimaryLanguage,
+			Self::Language { extlangs: Some(n) } if n < 3 => SubtagKind::ExtendedLanguage,
+			Self::Language { .. } => SubtagKind::Script,
+			Self::Script => SubtagKind::Region,
+			Self::Region | Self::Variant => SubtagKind::Variant,
+			Self::Singleton | Self::Extension => SubtagKind::Extension,
+			Self::PrivateUseSingleton | Self::PrivateUse => SubtagKind::PrivateUse,
+		}
+	}
+
+	/// Returns the state following the given subtag, if it is allowed here.
+	fn next(self, subtag: &[u8]) -> Option<Self> {
+		let len = subtag.len();
+		let alpha = subtag.iter().all(u8::is_ascii_alphabetic);
+		let digit = subtag.iter().all(u8::is_ascii_digit);
+		let private_use_singleton = subtag.eq_ignore_ascii_case(b"x");
+
+		match self {
+			Self::Start if private_use_singleton => Some(Self::PrivateUseSingleton),
+			Self::Start if alpha && (2..=3).contains(&len) => {
+				Some(Self::Language { extlangs: Some(0) })
+			}
+			Self::Start if alpha && len >= 4 => Some(Self::Language { extlangs: None }),
+			Self::Start => None,
+			Self::Language { extlangs: Some(n) } if n < 3 && alpha && len == 3 => {
+				Some(Self::Language {
+					extlangs: Some(n + 1),
+				})
+			}
+			Self::Language { .. } if alpha && len == 4 => Some(Self::Script),
+			Self::Language { .. } | Self::Script if (alpha && len == 2) || (digit && len == 3) => {
+				Some(Self::Region)
+			}
+			Self::Language { .. } | Self::Script | Self::Region | Self::Variant
+				if len >= 5 || (len == 4 && subtag[0].is_ascii_digit()) =>
+			{
+				Some(Self::Variant)
+			}
+			Self::Singleton | Self::Extension if len >= 2 => Some(Self::Extension),
+			Self::Singleton => None,
+			Self::PrivateUseSingleton | Self::PrivateUse => Some(Self::PrivateUse),
+			_ if private_use_singleton => Some(Self::PrivateUseSingleton),
+			_ if len == 1 => Some(Self::Singleton),
+			_ => None,
+		}
+	}
+}
+
+/// Finds the first subtag of the given invalid tag rejected by the grammar,
+/// the component expected there and the reason of the rejection.
+fn diagnose(tag: &[u8]) -> Diagnostic {
+	let mut state = State::Start;
+	let mut position = 0;
+
+	let kind = if tag.is_empty() {
+		InvalidLangTagKind::Empty
+	} else {
+		loop {
+			let end = tag[position..]
+				.iter()
+				.position(|b| *b == b'-')
+				.map(|i| position + i);
+			let subtag = &tag[position..end.unwrap_or(tag.len())];
+
+			let well_formed =
+				(1..=8).contains(&subtag.len()) && subtag.iter().all(u8::is_ascii_alphanumeric);
+			if !well_formed {
+				break InvalidLangTagKind::MalformedSubtag;
+			}
+
+			match state.next(subtag) {
+				Some(next) => state = next,
+				None => break InvalidLangTagKind::UnexpectedSubtag,
+			}
+
+			match end {
+				Some(end) => position = end + 1,
+				None => {
+					position = tag.len();
+					break InvalidLangTagKind::Incomplete;
+				}
+			}
+		}
+	};
+
+	Diagnostic {
+		position,
+		expected: state.expected(),
+		kind,
+	}
+}
@@ -59,6 +59,9 @@ pub static GRANDFATHERED: [GrandfatheredLangTag; 26] = [
 	ZhMin, ZhMinNan, ZhXiang,
 ];
 
+/// Length of the longest grandfathered tag.
+pub(crate) const GRANDFATHERED_MAX_LEN: usize = 11;
+
 impl GrandfatheredLangTag {
 	/// Try to parse a grandfathered tag.
 	#[inline]
@@ -88,6 +91,27 @@ impl GrandfatheredLangTag {
 		}
 	}
 
+	/// Returns the extended language form of the grandfathered tag, if its
+	/// registered replacement is an extended language subtag.
+	///
+	/// For instance `zh-min-nan` gives `zh-nan`, whose
+	/// [`Language::extended_primary`] is `nan`.
+	#[inline]
+	pub fn extlang_form(&self) -> Option<&'static Language> {
+		unsafe {
+			match self {
+				SgnBeFr => Some(Language::new_unchecked("sgn-sfb")),
+				SgnBeNl => Some(Language::new_unchecked("sgn-vgt")),
+				SgnChDe => Some(Language::new_unchecked("sgn-sgg")),
+				ZhGuoyu => Some(Language::new_unchecked("zh-cmn")),
+				ZhHakka => Some(Language::new_unchecked("zh-hak")),
+				ZhMinNan => Some(Language::new_unchecked("zh-nan")),
+				ZhXiang => Some(Language::new_unchecked("zh-hsn")),
+				_ => None,
+			}
+		}
+	}
+
 	/// Returns the bytes representation of the tag.
 	#[inline]
 	pub fn as_bytes(&self) -> &[u8] {
@@ -130,6 +154,75 @@ impl GrandfatheredLangTag {
 	}
 }
 
+#[cfg(feature = "registry")]
+impl GrandfatheredLangTag {
+	/// Returns the registered `Preferred-Value` of this tag, if any.
+	pub(crate) fn preferred_value_str(&self) -> Option<&'static str> {
+		match self {
+			EnGbOed => Some("en-GB-oxendict"),
+			IAmi => Some("ami"),
+			IBnn => Some("bnn"),
+			IHak => Some("hak"),
+			IKlingon => Some("tlh"),
+			ILux => Some("lb"),
+			INavajo => Some("nv"),
+			IPwn => Some("pwn"),
+			ITao => Some("tao"),
+			ITay => Some("tay"),
+			ITsu => Some("tsu"),
+			SgnBeFr => Some("sfb"),
+			SgnBeNl => Some("vgt"),
+			SgnChDe => Some("sgg"),
+			ArtLojban => Some("jbo"),
+			NoBok => Some("nb"),
+			NoNyn => Some("nn"),
+			ZhGuoyu => Some("cmn"),
+			ZhHakka => Some("hak"),
+			ZhMinNan => Some("nan"),
+			ZhXiang => Some("hsn"),
+			IDefault | IEnochian | IMingo | CelGaulish | ZhMin => None,
+		}
+	}
+
+	/// Returns the modern replacement of this tag, given by its registered
+	/// `Preferred-Value`, if any.
+	///
+	/// For instance `i-klingon` gives `tlh` and `zh-min-nan` gives `nan`,
+	/// while `i-default` has no replacement.
+	pub fn preferred_value(&self) -> Option<crate::LangTagBuf> {
+		self.preferred_value_str()
+			.map(|v| unsafe { crate::LangTagBuf::new_unchecked(v.to_owned()) })
+	}
+
+	/// Checks if this tag is marked as deprecated by the registry.
+	///
+	/// Only `i-default` and `i-mingo` are not deprecated.
+	pub fn is_deprecated(&self) -> bool {
+		self.deprecated_on().is_some()
+	}
+
+	/// Returns the date (formatted as `YYYY-MM-DD`) at which this tag was
+	/// deprecated by the registry, if it is deprecated.
+	pub fn deprecated_on(&self) -> Option<&'static str> {
+		match self {
+			EnGbOed => Some("2015-04-17"),
+			IAmi | IBnn | IPwn | ITao | ITay | ITsu => Some("2009-07-29"),
+			IEnochian => Some("2015-03-29"),
+			IHak => Some("2000-01-10"),
+			IKlingon => Some("2004-02-24"),
+			ILux => Some("1998-09-22"),
+			INavajo => Some("2000-02-18"),
+			SgnBeFr | SgnBeNl | SgnChDe => Some("2009-07-29"),
+			ArtLojban => Some("2003-09-02"),
+			CelGaulish => Some("2011-08-16"),
+			NoBok | NoNyn => Some("2000-02-18"),
+			ZhGuoyu => Some("2005-07-15"),
+			ZhHakka | ZhMin | ZhMinNan | ZhXiang => Some("2009-07-29"),
+			IDefault | IMingo => None,
+		}
+	}
+}
+
 impl<'a> TryFrom<&'a [u8]> for GrandfatheredLangTag {
 	type Error = InvalidGrandfatheredTag<&'a [u8]>;
 
@@ -150,3 +243,32 @@ impl fmt::Display for GrandfatheredLangTag {
 		self.as_str().fmt(f)
 	}
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GrandfatheredLangTag {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GrandfatheredLangTag {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor;
+
+		impl serde::de::Visitor<'_> for Visitor {
+			type Value = GrandfatheredLangTag;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "grandfathered language tag")
+			}
+
+			fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+				GrandfatheredLangTag::new(v)
+					.map_err(|v| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+			}
+		}
+
+		deserializer.deserialize_str(Visitor)
+	}
+}
@@ -25,21 +25,69 @@
 //!
 //! [`LangTag::new`]: crate::LangTag::new
 //! [`LangTagBuf`]: crate::LangTagBuf
+// Parsing is used by long-running tools and servers, it must never write to
+// the standard output or error.
+#![warn(clippy::print_stdout, clippy::print_stderr, clippy::dbg_macro)]
+#[cfg(feature = "registry")]
+use std::borrow::Cow;
 use std::hash::Hash;
 
 use static_regular_grammar::RegularGrammar;
 
+pub mod accept_language;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod builder;
+mod canonical;
+mod diff;
+mod error;
 mod grandfathered;
+mod matching;
 mod normal;
 mod private_use;
+#[cfg(feature = "registry")]
+mod registry;
+mod span;
+mod token;
+#[cfg(feature = "registry")]
+mod unicode;
 mod utils;
+#[cfg(feature = "registry")]
+mod validation;
 
+pub use builder::*;
+pub use canonical::*;
+pub use diff::*;
+pub use error::*;
 pub use grandfathered::*;
+pub use matching::*;
 pub use normal::*;
 pub use private_use::*;
+pub use span::*;
+pub use token::*;
 use utils::str_eq;
+#[cfg(feature = "registry")]
+pub use validation::*;
 
 /// Any language tag (normal, private use or grandfathered).
+///
+/// # Equality and hashing
+///
+/// Comparison, ordering and hashing are lexical and case-insensitive, as
+/// mandated by RFC 5646: `en-US` and `EN-us` are equal and have the same
+/// hash, but `en-a-foo-b-bar` and `en-b-bar-a-foo` are different tags even
+/// though they have the same canonical form. The same holds for
+/// [`LangTagBuf`] and every component type (e.g. [`Language`], [`Script`]),
+/// so that `a == b` always implies `hash(a) == hash(b)`, and owned and
+/// borrowed values can be used interchangeably as map keys.
+///
+/// Use [`Canonical`] to compare and hash tags by canonical form, or
+/// [`Exact`] to compare and hash them byte-for-byte.
+///
+/// # Size
+///
+/// `LangTag` is an unsized wrapper around `str`: a `&LangTag` reference has
+/// the same size as a `&str` (two pointer-sized words).
 #[derive(RegularGrammar)]
 #[grammar(file = "src/grammar.abnf", cache = "automata/langtag.aut.cbor")]
 #[grammar(sized(
@@ -50,6 +98,80 @@ use utils::str_eq;
 pub struct LangTag(str);
 
 impl LangTag {
+	/// Parses a list of language tags separated by `sep`.
+	///
+	/// Each item is trimmed of surrounding whitespaces before being
+	/// validated, and empty items are skipped. The returned tags borrow
+	/// from `input`.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tags: Result<Vec<_>, _> = LangTag::parse_list("en-US; fr;; de", ';').collect();
+	/// assert_eq!(tags.unwrap(), ["en-US", "fr", "de"]);
+	///
+	/// // `en-US, fr` is not a valid tag.
+	/// assert!(LangTag::parse_list("en-US, fr; de", ';').next().unwrap().is_err());
+	/// ```
+	pub fn parse_list(
+		input: &str,
+		sep: char,
+	) -> impl Iterator<Item = Result<&LangTag, InvalidLangTag<&str>>> {
+		input
+			.split(sep)
+			.map(str::trim)
+			.filter(|item| !item.is_empty())
+			.map(LangTag::new)
+	}
+
+	/// Parses each of the given strings as a language tag.
+	///
+	/// This is a convenience for validating many tags at once. The grammar
+	/// automaton is compiled into the validation code at build time, so no
+	/// automaton is built nor shared at run time and each item costs a
+	/// single linear scan.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let mut results = LangTag::parse_all(["en-US", "fr", "en_US"]);
+	/// assert_eq!(results.next().unwrap().unwrap(), "en-US");
+	/// assert_eq!(results.next().unwrap().unwrap(), "fr");
+	/// assert!(results.next().unwrap().is_err());
+	/// ```
+	pub fn parse_all<'a>(
+		inputs: impl IntoIterator<Item = &'a str>,
+	) -> impl Iterator<Item = Result<&'a LangTag, InvalidLangTag<&'a str>>> {
+		inputs.into_iter().map(LangTag::new)
+	}
+
+	/// Parses the value of an HTML `lang` attribute.
+	///
+	/// This is more tolerant than [`Self::new`]:
+	/// - surrounding ASCII whitespaces are trimmed;
+	/// - an empty value, meaning that the language is unknown, returns `None`.
+	///
+	/// Case is preserved, since tags are case-insensitive, and the explicit
+	/// `und` (undetermined) tag is returned as is. Invalid values return
+	/// `None` rather than an error, for best-effort parsing.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// assert_eq!(LangTag::from_html_lang(" EN-us\n").unwrap(), "en-US");
+	/// assert_eq!(LangTag::from_html_lang("und").unwrap(), "und");
+	/// assert!(LangTag::from_html_lang("  ").is_none());
+	/// assert!(LangTag::from_html_lang("en_US").is_none());
+	/// ```
+	pub fn from_html_lang(value: &str) -> Option<LangTagBuf> {
+		let value = value.trim_matches(|c: char| c.is_ascii_whitespace());
+		if value.is_empty() {
+			None
+		} else {
+			LangTag::new(value).ok().map(ToOwned::to_owned)
+		}
+	}
+
 	/// Returns the language subtags, if any.
 	///
 	/// Only normal language tags and regular grandfathered tags have language
@@ -64,6 +186,104 @@ impl LangTag {
 		}
 	}
 
+	/// Returns a tag made of the language subtags of this tag only, for
+	/// instance `en` for `en-Latn-US-x-foo`.
+	///
+	/// Regular grandfathered tags give their language (`zh` for
+	/// `zh-min-nan`). Private use and irregular grandfathered tags have no
+	/// language subtags, and return `None`.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// assert_eq!(LangTag::new("en-Latn-US-x-foo").unwrap().language_range().unwrap(), "en");
+	/// assert_eq!(LangTag::new("art-lojban").unwrap().language_range().unwrap(), "art");
+	/// assert_eq!(LangTag::new("i-klingon").unwrap().language_range(), None);
+	/// ```
+	pub fn language_range(&self) -> Option<LangTagBuf> {
+		self.as_typed()
+			.language()
+			.map(|language| unsafe { LangTagBuf::new_unchecked(language.as_str().to_owned()) })
+	}
+
+	/// Checks if the primary language of this tag is one of the given
+	/// languages (ignoring case).
+	///
+	/// Regular grandfathered tags are checked using the language they map to.
+	/// Private use and irregular grandfathered tags have no language, and are
+	/// never matched.
+	pub fn primary_is_one_of(&self, languages: &[&PrimaryLanguage]) -> bool {
+		self.language()
+			.is_some_and(|l| languages.contains(&l.primary()))
+	}
+
+	/// Checks if the primary language of this tag is one of the given
+	/// strings (ignoring case).
+	///
+	/// See [`Self::primary_is_one_of`].
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// assert!(LangTag::new("FR-ca").unwrap().primary_is_one_of_str(&["en", "fr"]));
+	/// assert!(LangTag::new("no-bok").unwrap().primary_is_one_of_str(&["no"]));
+	/// assert!(!LangTag::new("x-fr").unwrap().primary_is_one_of_str(&["fr"]));
+	/// ```
+	pub fn primary_is_one_of_str(&self, languages: &[&str]) -> bool {
+		self.language().is_some_and(|l| {
+			languages
+				.iter()
+				.any(|s| utils::case_insensitive_eq(l.primary().as_bytes(), s.as_bytes()))
+		})
+	}
+
+	/// Returns the primary language subtag and the extended language subtags
+	/// as raw string slices.
+	///
+	/// This is a lightweight alternative to [`Self::language`] for code that
+	/// only needs string comparisons: the second slice holds the `-`-separated
+	/// extended language subtags, and is empty if there are none.
+	///
+	/// Private use tags and irregular grandfathered tags have no language,
+	/// and return `(None, "")`. Regular grandfathered tags return the primary
+	/// language they map to, without extended language subtags.
+	pub fn primary_and_extlangs_str(&self) -> (Option<&str>, &str) {
+		let (primary, rest) = match self.0.split_once('-') {
+			Some((primary, rest)) => (primary, rest),
+			None => (&self.0, ""),
+		};
+
+		if primary.len() == 1 {
+			// private use tag or irregular `i-*` grandfathered tag.
+			return (None, "");
+		}
+
+		if self.0.len() <= GRANDFATHERED_MAX_LEN {
+			if let Ok(g) = GrandfatheredLangTag::new(&self.0) {
+				return match g.language() {
+					Some(language) => (Some(language.primary().as_str()), ""),
+					None => (None, ""),
+				};
+			}
+		}
+
+		let mut end = 0;
+		if primary.len() <= 3 {
+			for (i, segment) in rest.split('-').take(3).enumerate() {
+				if segment.len() == 3 && segment.bytes().all(|b| b.is_ascii_alphabetic()) {
+					end += segment.len();
+					if i > 0 {
+						end += 1
+					}
+				} else {
+					break;
+				}
+			}
+		}
+
+		(Some(primary), &rest[..end])
+	}
+
 	/// Returns the script subtag, if any.
 	pub fn script(&self) -> Option<&Script> {
 		self.as_normal().and_then(NormalLangTag::script)
@@ -88,21 +308,341 @@ impl LangTag {
 			.unwrap_or(Extensions::EMPTY)
 	}
 
+	/// Returns an iterator over the extensions of this tag, in canonical
+	/// (singleton) order, regardless of their order in the tag.
+	///
+	/// Private use subtags are not extensions, and are not included.
+	pub fn extensions_sorted(&self) -> SortedExtensionsIter<'_> {
+		self.extensions().iter_sorted()
+	}
+
+	/// Returns the raw subtags of the first extension introduced by the
+	/// given singleton (ignoring case).
+	///
+	/// This is a generic accessor for extensions that do not follow the
+	/// keyword structure of the `u` and `t` extensions. The iterator is empty
+	/// if no extension uses this singleton.
+	///
+	/// ```
+	/// use langtag::{LangTag, Singleton};
+	///
+	/// let tag = LangTag::new("en-A-foo-bar-b-baz").unwrap();
+	/// let a = Singleton::new(b'a').unwrap();
+	/// assert!(tag.extension_subtag_values(a).eq(["foo", "bar"]));
+	/// ```
+	pub fn extension_subtag_values(&self, singleton: Singleton) -> impl Iterator<Item = &str> {
+		let singleton = utils::into_smallcase(singleton.unwrap());
+		self.extensions()
+			.iter()
+			.find(|e| utils::into_smallcase(e.singleton().unwrap()) == singleton)
+			.map(Extension::iter)
+			.unwrap_or_default()
+			.map(ExtensionSubtag::as_str)
+	}
+
+	/// Returns a copy of this tag without the first extension introduced by
+	/// the given singleton (ignoring case), keeping all the other extensions
+	/// and private use subtags.
+	///
+	/// The tag is returned unchanged if no extension uses this singleton.
+	///
+	/// ```
+	/// use langtag::{LangTag, Singleton};
+	///
+	/// let tag = LangTag::new("en-t-fr-u-ca-gregory-x-foo").unwrap();
+	/// let t = Singleton::new(b't').unwrap();
+	/// assert_eq!(tag.without_singleton(t), "en-u-ca-gregory-x-foo");
+	/// ```
+	pub fn without_singleton(&self, singleton: Singleton) -> LangTagBuf {
+		let singleton = utils::into_smallcase(singleton.unwrap());
+		let extension = self
+			.extensions()
+			.iter()
+			.find(|e| utils::into_smallcase(e.singleton().unwrap()) == singleton);
+
+		match extension {
+			Some(extension) => {
+				let start = extension.as_ptr() as usize - self.0.as_ptr() as usize;
+				let end = start + extension.len();
+				let mut result = String::with_capacity(self.0.len() - extension.len() - 1);
+				result.push_str(&self.0[..(start - 1)]);
+				result.push_str(&self.0[end..]);
+				debug_assert!(LangTag::new(&result).is_ok());
+				unsafe { LangTagBuf::new_unchecked(result) }
+			}
+			None => self.to_owned(),
+		}
+	}
+
+	/// Returns a copy of this tag with the given language subtags (primary
+	/// language and extended language subtags), preserving every other
+	/// component.
+	///
+	/// Private use tags get the language prepended (`x-foo` becomes
+	/// `fr-x-foo`), while grandfathered tags, whose subtags cannot be
+	/// separated, are replaced by the language alone.
+	///
+	/// ```
+	/// use langtag::{LangTag, Language};
+	///
+	/// let tag = LangTag::new("zh-yue-Hant-HK").unwrap();
+	/// let language = Language::new("cmn").unwrap();
+	/// assert_eq!(tag.with_language(language), "cmn-Hant-HK");
+	/// ```
+	pub fn with_language(&self, language: &Language) -> LangTagBuf {
+		let mut result = language.as_str().to_owned();
+		match self.as_typed() {
+			TypedLangTag::Normal(t) => result.push_str(&self.0[t.language().len()..]),
+			TypedLangTag::PrivateUse(_) => {
+				result.push('-');
+				result.push_str(&self.0)
+			}
+			TypedLangTag::Grandfathered(_) => (),
+		}
+
+		debug_assert!(LangTag::new(&result).is_ok());
+		unsafe { LangTagBuf::new_unchecked(result) }
+	}
+
 	/// Returns the private use subtag, if any.
 	pub fn private_use(&self) -> Option<&PrivateUse> {
 		self.as_normal().and_then(NormalLangTag::private_use)
 	}
 
+	/// Returns a copy of this tag with its private use subtags sorted
+	/// (ignoring case), keeping the `x` prefix.
+	///
+	/// The meaning of private use subtags is application-defined, and their
+	/// order may be significant: sorting them may change the semantics of
+	/// the tag. This is never part of the default canonicalization, and
+	/// should only be used when the order is known to be insignificant, for
+	/// instance to deduplicate tags.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("en-US-x-foo-Bar-baz").unwrap();
+	/// assert_eq!(tag.with_sorted_private_use(), "en-US-x-Bar-baz-foo");
+	/// ```
+	pub fn with_sorted_private_use(&self) -> LangTagBuf {
+		self.canonicalize_with(CanonicalizeOptions {
+			sort_private_use: true,
+			..CanonicalizeOptions::NONE
+		})
+	}
+
+	/// Splits this tag into the tag without its private use component and
+	/// the private use subtags.
+	///
+	/// Since a private use tag (e.g. `x-foo`) has nothing left once its
+	/// private use subtags are removed, the returned tag is `und`
+	/// (undetermined language) in this case. Grandfathered tags are returned
+	/// as is, with no private use subtags.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("en-US-x-foo-bar").unwrap();
+	/// let (public, private) = tag.take_private_use();
+	/// assert_eq!(public, "en-US");
+	/// assert_eq!(private, ["foo", "bar"]);
+	///
+	/// let (public, private) = LangTag::new("x-foo").unwrap().take_private_use();
+	/// assert_eq!(public, "und");
+	/// assert_eq!(private, ["foo"]);
+	/// ```
+	pub fn take_private_use(&self) -> (LangTagBuf, Vec<PrivateUseSubtagBuf>) {
+		let private_use = match self.as_typed() {
+			TypedLangTag::Normal(t) => t.private_use(),
+			TypedLangTag::PrivateUse(t) => Some(t),
+			TypedLangTag::Grandfathered(_) => None,
+		};
+
+		match private_use {
+			Some(private_use) => {
+				let subtags = private_use.iter().map(ToOwned::to_owned).collect();
+
+				let start = private_use.as_ptr() as usize - self.0.as_ptr() as usize;
+				let tag = if start == 0 {
+					"und".to_owned()
+				} else {
+					self.0[..start - 1].to_owned()
+				};
+
+				(unsafe { LangTagBuf::new_unchecked(tag) }, subtags)
+			}
+			None => (self.to_owned(), Vec::new()),
+		}
+	}
+
 	/// Returns an iterator over the private use subtag subtags.
-	pub fn private_use_subtags(&self) -> PrivateUseIter {
+	pub fn private_use_subtags(&self) -> PrivateUseIter<'_> {
 		self.private_use()
 			.map(PrivateUse::iter)
 			.unwrap_or(PrivateUseIter::empty())
 	}
 
+	/// Checks if this tag is made of a single primary language subtag, with
+	/// nothing else (e.g. `en`, `de` or `zh`).
+	///
+	/// This is the most general form of a tag, useful to know when a
+	/// fallback chain reached the bare language. Private use and
+	/// grandfathered tags are never single subtags.
+	pub fn is_single_subtag(&self) -> bool {
+		!self.0.contains('-')
+	}
+
+	/// Returns the total number of subtags in this tag, including singletons
+	/// and the private use `x`.
+	///
+	/// For instance `en-Latn-US-x-a-b` has 6 subtags.
+	pub fn total_subtag_count(&self) -> usize {
+		self.0.bytes().filter(|b| *b == b'-').count() + 1
+	}
+
+	/// Returns the number of leading subtags shared by this tag and `other`,
+	/// ignoring case.
+	///
+	/// For instance `en-US` and `en-GB` share 1 subtag, while `en-Latn-US`
+	/// and `en-Latn-GB` share 2. This can be used to score how related two
+	/// tags are.
+	pub fn shared_prefix_subtags(&self, other: &Self) -> usize {
+		self.0
+			.split('-')
+			.zip(other.0.split('-'))
+			.take_while(|(a, b)| utils::case_insensitive_eq(a.as_bytes(), b.as_bytes()))
+			.count()
+	}
+
+	/// Checks that this tag follows the structural rules of RFC 5646 that
+	/// are not enforced by the grammar, such as the uniqueness of extension
+	/// singletons.
+	///
+	/// Private use and grandfathered tags are always valid.
+	pub fn validate_structure(&self) -> Result<(), StructureError> {
+		match self.as_normal() {
+			Some(tag) => tag.validate_structure(),
+			None => Ok(()),
+		}
+	}
+
+	/// Returns how similar the language subtags (primary language and
+	/// extended language subtags) of this tag and `other` are, between `0.0`
+	/// and `1.0`.
+	///
+	/// The similarity is `0.0` if the primary languages differ (or if any of
+	/// the tags has no language subtags). Otherwise it is
+	/// `0.5 + 0.5 * shared / max`, where `shared` is the number of leading
+	/// extended language subtags the two tags have in common and `max` is
+	/// the maximum number of extended language subtags of the two tags
+	/// (the similarity is `1.0` if none have extended language subtags).
+	///
+	/// For instance `zh-yue` is identical to `zh-yue` (`1.0`), closer to
+	/// `zh` (`0.5`) than to `fr` (`0.0`). Other components are ignored.
+	pub fn language_similarity(&self, other: &Self) -> f32 {
+		match (self.language(), other.language()) {
+			(Some(a), Some(b)) if a.primary() == b.primary() => {
+				let max = a
+					.extension_subtags()
+					.count()
+					.max(b.extension_subtags().count());
+
+				if max == 0 {
+					1.0
+				} else {
+					let shared = a
+						.extension_subtags()
+						.zip(b.extension_subtags())
+						.take_while(|(a, b)| a == b)
+						.count();
+
+					0.5 + 0.5 * shared as f32 / max as f32
+				}
+			}
+			_ => 0.0,
+		}
+	}
+
+	/// Returns the bytes of this tag, which are guaranteed to be ASCII.
+	///
+	/// All well-formed language tags are made of ASCII letters, digits and
+	/// `-`, so the returned bytes can be fed to ASCII-only fast paths
+	/// without being checked again.
+	pub fn ascii_bytes(&self) -> &[u8] {
+		debug_assert!(self.0.is_ascii());
+		self.0.as_bytes()
+	}
+
+	/// Checks that this tag is byte-for-byte equal to `other`.
+	///
+	/// Contrarily to `==`, which ignores case as mandated by RFC 5646, this is
+	/// a case-sensitive comparison: `en-US` and `en-us` are equal, but not
+	/// exactly equal. Use this when the tag is an opaque identifier that must
+	/// round-trip exactly.
+	pub fn eq_exact(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+
+	/// Compares this tag to `other` byte-for-byte.
+	///
+	/// Contrarily to [`Ord::cmp`], which ignores case, this is a
+	/// case-sensitive comparison consistent with [`Self::eq_exact`]: `en-US`
+	/// is ordered before `en-us`.
+	pub fn cmp_exact(&self, other: &Self) -> std::cmp::Ordering {
+		self.0.cmp(&other.0)
+	}
+
+	/// Checks that this tag is equal to `other` (ignoring case) when variants
+	/// are ignored.
+	///
+	/// For instance `de-CH-1996` and `de-CH` are considered equal.
+	/// Private use and grandfathered tags (including regular ones such as
+	/// `zh-hakka`) have no variants, and are compared as usual.
+	pub fn eq_ignore_variants(&self, other: &Self) -> bool {
+		match (self.as_typed(), other.as_typed()) {
+			(TypedLangTag::Normal(a), TypedLangTag::Normal(b)) => a.eq_ignore_variants(b),
+			_ => self == other,
+		}
+	}
+
+	/// Returns a displayable view over the components of this tag, if it is
+	/// a normal language tag (see [`Self::is_normal`]).
+	///
+	/// See [`LangTagComponents`].
+	pub fn components_display(&self) -> Option<LangTagComponents<'_>> {
+		self.as_typed()
+			.as_normal()
+			.map(NormalLangTag::components_display)
+	}
+
+	/// Returns the byte offset where the extensions and private use subtags
+	/// begin, or the length of the tag if there are none.
+	///
+	/// The offset points at the first singleton (including `x`), so that
+	/// `&tag[..offset]` is the core of the tag followed by a `-` separator
+	/// (when there are extensions or private use subtags). Private use tags
+	/// start at offset `0`, and grandfathered tags have no extensions.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("de-CH-1996-a-ext-x-foo").unwrap();
+	/// assert_eq!(&tag[tag.extension_split_offset()..], "a-ext-x-foo");
+	/// ```
+	pub fn extension_split_offset(&self) -> usize {
+		match self.as_typed() {
+			TypedLangTag::Normal(t) => t.extension_split_offset(),
+			TypedLangTag::PrivateUse(_) => 0,
+			TypedLangTag::Grandfathered(_) => self.0.len(),
+		}
+	}
+
 	/// Returns wether or not this language tag is a normal language tag.
+	///
+	/// This agrees with [`Self::kind`]: grandfathered tags are never normal,
+	/// even when they are also well-formed normal tags, such as `zh-min-nan`.
 	pub fn is_normal(&self) -> bool {
-		self.as_normal().is_some()
+		self.kind().is_normal()
 	}
 
 	/// Returns wether or not this language tag is a private use tag.
@@ -115,7 +655,46 @@ impl LangTag {
 		self.as_grandfathered().is_some()
 	}
 
+	/// Checks if the given string is one of the grandfathered tags, ignoring
+	/// case, without parsing it first.
+	///
+	/// This is a cheap pre-check to route input before parsing it.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// assert!(LangTag::looks_grandfathered("I-Klingon"));
+	/// assert!(!LangTag::looks_grandfathered("en-US"));
+	/// ```
+	pub fn looks_grandfathered(s: &str) -> bool {
+		s.len() <= GRANDFATHERED_MAX_LEN
+			&& GRANDFATHERED
+				.iter()
+				.any(|tag| utils::case_insensitive_eq(tag.as_bytes(), s.as_bytes()))
+	}
+
+	/// Checks if this tag is the given grandfathered tag (ignoring case), or,
+	/// with the `registry` feature, its `Preferred-Value`.
+	///
+	/// For instance `tlh` is equivalent to `i-klingon`.
+	pub fn is_equivalent_to_grandfathered(&self, g: GrandfatheredLangTag) -> bool {
+		if utils::case_insensitive_eq(self.as_bytes(), g.as_bytes()) {
+			return true;
+		}
+
+		#[cfg(feature = "registry")]
+		if let Some(preferred) = g.preferred_value_str() {
+			return *self == *preferred;
+		}
+
+		false
+	}
+
 	/// Returns this language tag as a normal tag, if it is one.
+	///
+	/// Unlike [`Self::is_normal`], this only checks the syntax: regular
+	/// grandfathered tags such as `zh-min-nan` are also returned with their
+	/// normal interpretation.
 	pub fn as_normal(&self) -> Option<&NormalLangTag> {
 		NormalLangTag::new(&self.0).ok()
 	}
@@ -136,17 +715,96 @@ impl LangTag {
 	}
 
 	/// Find out what kind of language tag `self` is.
-	pub fn as_typed(&self) -> TypedLangTag {
-		match NormalLangTag::new(&self.0) {
-			Ok(t) => TypedLangTag::Normal(t),
-			Err(_) => match PrivateUseLangTag::new(&self.0) {
-				Ok(t) => TypedLangTag::PrivateUse(t),
-				Err(_) => TypedLangTag::Grandfathered(GrandfatheredLangTag::new(&self.0).unwrap()),
+	///
+	/// Grandfathered tags take precedence: some of them, such as
+	/// `zh-min-nan` or `art-lojban`, are also well-formed normal tags, but
+	/// are classified as [`TypedLangTag::Grandfathered`]. Use
+	/// [`Self::as_normal`] to get their normal interpretation.
+	pub fn as_typed(&self) -> TypedLangTag<'_> {
+		match GrandfatheredLangTag::new(&self.0) {
+			Ok(t) => TypedLangTag::Grandfathered(t),
+			Err(_) => match NormalLangTag::new(&self.0) {
+				Ok(t) => TypedLangTag::Normal(t),
+				Err(_) => TypedLangTag::PrivateUse(PrivateUseLangTag::new(&self.0).unwrap()),
 			},
 		}
 	}
 }
 
+#[cfg(feature = "registry")]
+impl LangTag {
+	/// Returns the preferred form of this tag as a string.
+	///
+	/// For grandfathered tags having a registered `Preferred-Value`, this is
+	/// the preferred value (e.g. `tlh` for `i-klingon`). Otherwise, this is
+	/// the tag itself. This function does not allocate.
+	pub fn preferred_str(&self) -> Cow<'_, str> {
+		match self
+			.as_grandfathered()
+			.and_then(|g| g.preferred_value_str())
+		{
+			Some(preferred) => Cow::Borrowed(preferred),
+			None => Cow::Borrowed(self.as_str()),
+		}
+	}
+
+	/// Checks if the language and region of this tag plausibly go together.
+	///
+	/// This is a heuristic quality signal, not a validity rule: the registry
+	/// does not forbid any combination. It relies on the CLDR likely subtags
+	/// and the UN M.49 regions to flag improbable pairs such as `ja-BR`,
+	/// which can help catching data-entry mistakes. A combination is
+	/// considered plausible if:
+	/// - the region is the likely region of the language, or the language
+	///   the likely language of the region;
+	/// - the (explicit or likely) script of the language is the likely
+	///   script of the region (`es-US`);
+	/// - the region and the likely region of the language are in the same
+	///   UN M.49 subregion (`bn-IN`, `ta-LK`);
+	/// - the language is an international language with official status on
+	///   several continents (English, French, Spanish, Portuguese, Arabic
+	///   and Chinese), as in `en-IN` or `ar-IL`.
+	///
+	/// Returns `true` when there is not enough data to judge, and for tags
+	/// without region.
+	pub fn is_plausible_combination(&self) -> bool {
+		const INTERNATIONAL: [&str; 6] = ["ar", "en", "es", "fr", "pt", "zh"];
+
+		let Some(tag) = self.as_normal() else {
+			return true;
+		};
+
+		let Some(region) = tag.region() else {
+			return true;
+		};
+
+		let language = tag.language().primary();
+		if INTERNATIONAL.iter().any(|l| *language == **l) {
+			return true;
+		}
+
+		let Some((_, likely_script, likely_region)) =
+			registry::likely_subtags(language, Some(region.as_str()))
+		else {
+			return true;
+		};
+
+		let Some((region_language, region_script, _)) =
+			registry::region_likely_subtags(region.as_str())
+		else {
+			return true;
+		};
+
+		let script = tag.script().map(Script::as_str).unwrap_or(likely_script);
+
+		*region == likely_region
+			|| utils::case_insensitive_eq(language.as_bytes(), region_language.as_bytes())
+			|| utils::case_insensitive_eq(script.as_bytes(), region_script.as_bytes())
+			|| registry::region_parent(region.as_str())
+				.is_some_and(|parent| registry::region_parent(likely_region) == Some(parent))
+	}
+}
+
 impl PartialEq for LangTag {
 	fn eq(&self, other: &Self) -> bool {
 		utils::case_insensitive_eq(self.as_bytes(), other.as_bytes())
@@ -176,6 +834,285 @@ impl Hash for LangTag {
 	}
 }
 
+impl<'a> From<&'a LangTag> for LangTagBuf {
+	fn from(value: &'a LangTag) -> Self {
+		value.to_owned()
+	}
+}
+
+impl LangTagBuf {
+	/// Returns the number of bytes allocated on the heap by this tag.
+	///
+	/// This is the capacity of the inner `String`, which may be larger than
+	/// the length of the tag.
+	pub fn heap_size(&self) -> usize {
+		self.0.capacity()
+	}
+
+	/// Builds a normal language tag from its components.
+	///
+	/// The components are concatenated and the result is parsed again,
+	/// failing if the components do not form a well-formed language tag.
+	/// See [`Self::from_components_unchecked`] to skip the final parse.
+	pub fn from_components(
+		language: &Language,
+		script: Option<&Script>,
+		region: Option<&Region>,
+		variants: &Variants,
+		extensions: &Extensions,
+		private_use: Option<&PrivateUse>,
+	) -> Result<Self, InvalidLangTag<String>> {
+		Self::new(
+			LangTagComponents {
+				language,
+				script,
+				region,
+				variants,
+				extensions,
+				private_use,
+			}
+			.to_string(),
+		)
+	}
+
+	/// Builds a normal language tag from its components without checking
+	/// the result.
+	///
+	/// In debug builds, the result is validated anyway.
+	///
+	/// # Safety
+	///
+	/// Each component must be individually valid (a valid [`Language`],
+	/// [`Script`], [`Region`], [`Variants`] list, [`Extensions`] list and
+	/// [`PrivateUse`] sequence) and they must be given in this order.
+	/// Variants and extensions may be empty.
+	pub unsafe fn from_components_unchecked(
+		language: &str,
+		script: Option<&str>,
+		region: Option<&str>,
+		variants: &str,
+		extensions: &str,
+		private_use: Option<&str>,
+	) -> Self {
+		let result = concat_components(language, script, region, variants, extensions, private_use);
+		debug_assert!(LangTag::new(&result).is_ok(), "invalid components");
+		Self::new_unchecked(result)
+	}
+
+	/// Builds a language tag by joining the given subtags with `-`.
+	///
+	/// The result is parsed as a whole, so the subtags must be given in a
+	/// valid order. On failure, the error identifies the first offending
+	/// subtag: either a subtag that is not 1 to 8 ASCII alphanumeric
+	/// characters, or the first subtag that cannot follow the previous ones.
+	///
+	/// ```
+	/// use langtag::LangTagBuf;
+	///
+	/// let tag = LangTagBuf::from_subtags(["sr", "Latn", "RS"]).unwrap();
+	/// assert_eq!(tag, "sr-Latn-RS");
+	///
+	/// let error = LangTagBuf::from_subtags(["sr", "RS", "Latn"]).unwrap_err();
+	/// assert_eq!(error.index, 2);
+	/// ```
+	pub fn from_subtags(
+		subtags: impl IntoIterator<Item = impl AsRef<str>>,
+	) -> Result<Self, InvalidSubtags> {
+		let mut tag = String::new();
+		let mut ends = Vec::new();
+		let mut malformed = None;
+
+		for (i, subtag) in subtags.into_iter().enumerate() {
+			let subtag = subtag.as_ref();
+			let well_formed = (1..=8).contains(&subtag.len())
+				&& subtag.bytes().all(|b| b.is_ascii_alphanumeric());
+			if malformed.is_none() && !well_formed {
+				malformed = Some(i)
+			}
+
+			if i > 0 {
+				tag.push('-')
+			}
+
+			tag.push_str(subtag);
+			ends.push(tag.len())
+		}
+
+		if let Some(index) = malformed {
+			return Err(InvalidSubtags { tag, index });
+		}
+
+		if LangTag::new(&tag).is_ok() {
+			return Ok(unsafe { Self::new_unchecked(tag) });
+		}
+
+		// The offending subtag is the one following the longest valid prefix.
+		let index = ends
+			.iter()
+			.rposition(|&end| LangTag::new(&tag[..end]).is_ok())
+			.map(|i| i + 1)
+			.unwrap_or(0);
+
+		Err(InvalidSubtags { tag, index })
+	}
+}
+
+/// Invalid subtag sequence error, see [`LangTagBuf::from_subtags`].
+#[derive(Debug, thiserror::Error)]
+#[error("invalid language tag `{tag}` (subtag {index})")]
+pub struct InvalidSubtags {
+	/// Subtags joined with `-`.
+	pub tag: String,
+
+	/// Index of the first offending subtag, or the number of subtags if
+	/// there are none.
+	pub index: usize,
+}
+
+fn concat_components(
+	language: &str,
+	script: Option<&str>,
+	region: Option<&str>,
+	variants: &str,
+	extensions: &str,
+	private_use: Option<&str>,
+) -> String {
+	let mut result = String::from(language);
+
+	for component in [
+		script,
+		region,
+		Some(variants),
+		Some(extensions),
+		private_use,
+	]
+	.into_iter()
+	.flatten()
+	{
+		if !component.is_empty() {
+			result.push('-');
+			result.push_str(component)
+		}
+	}
+
+	result
+}
+
+/// Language tag compared, ordered and hashed byte-for-byte.
+///
+/// This intentionally diverges from the default semantics of language tags,
+/// which ignore case as mandated by RFC 5646: `Exact(en-US)` and
+/// `Exact(en-us)` are different. This can be used to keep the textual forms
+/// supplied by users distinct, e.g. in a set. See [`LangTag::eq_exact`] and
+/// [`LangTag::cmp_exact`].
+///
+/// ```
+/// use langtag::{Exact, LangTag};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Exact(LangTag::new("en-US").unwrap()));
+/// set.insert(Exact(LangTag::new("en-us").unwrap()));
+/// assert_eq!(set.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Exact<T>(pub T);
+
+impl<T: AsRef<LangTag>> PartialEq for Exact<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref().eq_exact(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<LangTag>> Eq for Exact<T> {}
+
+impl<T: AsRef<LangTag>> PartialOrd for Exact<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: AsRef<LangTag>> Ord for Exact<T> {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		self.0.as_ref().cmp_exact(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<LangTag>> Hash for Exact<T> {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.0.as_ref().as_str().hash(state)
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Values that can be turned into an owned language tag.
+///
+/// This trait is sealed and implemented for `&LangTag` and `LangTagBuf`
+/// (infallible), and `&str` and `String` (fallible). It allows APIs to
+/// uniformly accept any of them:
+///
+/// ```
+/// use langtag::{IntoLangTagBuf, LangTagBuf};
+///
+/// fn store(tag: impl IntoLangTagBuf) -> Option<LangTagBuf> {
+///   tag.try_into_lang_tag_buf().ok()
+/// }
+///
+/// assert!(store("fr-FR").is_some());
+/// assert!(store("not a tag").is_none());
+/// ```
+pub trait IntoLangTagBuf: sealed::Sealed {
+	/// Conversion error, [`Infallible`](std::convert::Infallible) for values
+	/// that already are language tags.
+	type Error;
+
+	/// Tries to turn this value into an owned language tag.
+	fn try_into_lang_tag_buf(self) -> Result<LangTagBuf, Self::Error>;
+}
+
+impl sealed::Sealed for &LangTag {}
+
+impl IntoLangTagBuf for &LangTag {
+	type Error = std::convert::Infallible;
+
+	fn try_into_lang_tag_buf(self) -> Result<LangTagBuf, Self::Error> {
+		Ok(self.to_owned())
+	}
+}
+
+impl sealed::Sealed for LangTagBuf {}
+
+impl IntoLangTagBuf for LangTagBuf {
+	type Error = std::convert::Infallible;
+
+	fn try_into_lang_tag_buf(self) -> Result<LangTagBuf, Self::Error> {
+		Ok(self)
+	}
+}
+
+impl sealed::Sealed for &str {}
+
+impl<'a> IntoLangTagBuf for &'a str {
+	type Error = InvalidLangTag<&'a str>;
+
+	fn try_into_lang_tag_buf(self) -> Result<LangTagBuf, Self::Error> {
+		LangTag::new(self).map(LangTag::to_owned)
+	}
+}
+
+impl sealed::Sealed for String {}
+
+impl IntoLangTagBuf for String {
+	type Error = InvalidLangTag<String>;
+
+	fn try_into_lang_tag_buf(self) -> Result<LangTagBuf, Self::Error> {
+		LangTagBuf::new(self)
+	}
+}
+
 /// Language tag with type information (normal, private use or grandfathered).
 pub enum TypedLangTag<'a> {
 	Normal(&'a NormalLangTag),
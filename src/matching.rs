@@ -0,0 +1,194 @@
+//! Language range matching, as defined by [RFC 4647].
+//!
+//! [RFC 4647]: <https://www.rfc-editor.org/rfc/rfc4647>
+use crate::{LangTag, LangTagBuf};
+
+impl LangTag {
+	/// Returns an iterator over this tag and its successive fallbacks,
+	/// obtained by removing the last subtag.
+	///
+	/// A singleton left at the end of a truncated tag is removed with its
+	/// subtag, so that every fallback is a well-formed tag. Private use and
+	/// grandfathered tags have no fallbacks, the iterator then only yields
+	/// the tag itself.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("en-Latn-US-x-foo").unwrap();
+	/// let fallbacks: Vec<_> = tag.fallbacks().map(LangTag::as_str).collect();
+	/// assert_eq!(fallbacks, ["en-Latn-US-x-foo", "en-Latn-US", "en-Latn", "en"]);
+	/// ```
+	pub fn fallbacks(&self) -> Fallbacks<'_> {
+		Fallbacks {
+			next: Some(self),
+			truncate: self.is_normal(),
+		}
+	}
+
+	/// Checks if this tag matches the given basic language range, as defined
+	/// by [RFC 4647 section 3.3.1](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1)
+	/// (ignoring case).
+	///
+	/// The range matches if it is equal to this tag, or to a prefix of this
+	/// tag ending on a subtag boundary. The `*` range matches any tag, and a
+	/// `*` subtag matches the rest of the tag (`en-*` is the same as `en`).
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("de-CH-1996").unwrap();
+	/// assert!(tag.matches_basic("de-CH"));
+	/// assert!(!tag.matches_basic("de-C"));
+	/// ```
+	pub fn matches_basic(&self, range: &str) -> bool {
+		let mut subtags = self.0.split('-');
+		for r in range.split('-') {
+			if r == "*" {
+				return true;
+			}
+
+			match subtags.next() {
+				Some(subtag) if subtag.eq_ignore_ascii_case(r) => (),
+				_ => return false,
+			}
+		}
+
+		true
+	}
+
+	/// Checks if this tag matches the given extended language range, as
+	/// defined by [RFC 4647 section 3.3.2](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.2)
+	/// (ignoring case).
+	///
+	/// The first subtag of the range must match the first subtag of the tag
+	/// (or be `*`). Other `*` subtags match any number of subtags, and non
+	/// wildcard subtags of the range must appear in the tag in the same
+	/// order, without crossing an extension or private use singleton.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("de-Latn-DE").unwrap();
+	/// assert!(tag.matches_extended("de-*-DE"));
+	/// assert!(tag.matches_extended("de-DE"));
+	/// assert!(!tag.matches_extended("de-CH"));
+	/// ```
+	pub fn matches_extended(&self, range: &str) -> bool {
+		let mut range = range.split('-');
+		let mut subtags = self.0.split('-').peekable();
+
+		match (range.next(), subtags.next()) {
+			(Some("*"), Some(_)) => (),
+			(Some(r), Some(subtag)) if subtag.eq_ignore_ascii_case(r) => (),
+			_ => return false,
+		}
+
+		for r in range {
+			if r == "*" {
+				continue;
+			}
+
+			loop {
+				match subtags.next() {
+					Some(subtag) if subtag.eq_ignore_ascii_case(r) => break,
+					Some(subtag) if subtag.len() > 1 => (),
+					_ => return false,
+				}
+			}
+		}
+
+		true
+	}
+}
+
+/// Finds the available tag best matching the given language range, using the
+/// lookup scheme defined by [RFC 4647 section 3.4](https://www.rfc-editor.org/rfc/rfc4647#section-3.4).
+///
+/// The range is compared (ignoring case) to every available tag, and then
+/// progressively truncated from the end until a match is found. When the
+/// truncation leaves a trailing singleton (e.g. `x` or `u`), it is removed
+/// as well. Wildcard subtags (`*`) are ignored.
+///
+/// Returns `None` if no truncation of the range matches, in which case the
+/// caller should fall back to its default language.
+///
+/// ```
+/// use langtag::{lookup, LangTagBuf};
+///
+/// let available: Vec<LangTagBuf> = ["en", "zh-Hant", "fr-CA"]
+///     .into_iter()
+///     .map(|t| t.parse().unwrap())
+///     .collect();
+///
+/// assert_eq!(lookup("zh-Hant-CN-x-private1", &available).unwrap(), "zh-Hant");
+/// assert_eq!(lookup("fr-FR", &available), None);
+/// ```
+pub fn lookup<'a>(range: &str, available: &'a [LangTagBuf]) -> Option<&'a LangTagBuf> {
+	lookup_in(range, available.iter())
+}
+
+/// Finds the tag best matching the given language range among the
+/// `available` tags, see [`lookup`].
+pub(crate) fn lookup_in<'a>(
+	range: &str,
+	available: impl Iterator<Item = &'a LangTagBuf> + Clone,
+) -> Option<&'a LangTagBuf> {
+	let mut subtags: Vec<&str> = range.split('-').filter(|s| *s != "*").collect();
+
+	while !subtags.is_empty() {
+		let found = available.clone().find(|tag| {
+			let mut tag_subtags = tag.as_str().split('-');
+			subtags.iter().all(|r| {
+				tag_subtags
+					.next()
+					.is_some_and(|s| s.eq_ignore_ascii_case(r))
+			}) && tag_subtags.next().is_none()
+		});
+
+		if found.is_some() {
+			return found;
+		}
+
+		subtags.pop();
+		if subtags.last().is_some_and(|s| s.len() == 1) {
+			subtags.pop();
+		}
+	}
+
+	None
+}
+
+/// Iterator over the successive fallbacks of a language tag.
+///
+/// See [`LangTag::fallbacks`].
+pub struct Fallbacks<'a> {
+	next: Option<&'a LangTag>,
+	truncate: bool,
+}
+
+impl<'a> Iterator for Fallbacks<'a> {
+	type Item = &'a LangTag;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let tag = self.next?;
+		self.next = if self.truncate {
+			truncate(tag.as_str()).map(|t| unsafe { LangTag::new_unchecked(t) })
+		} else {
+			None
+		};
+
+		Some(tag)
+	}
+}
+
+/// Removes the last subtag of the given normal tag, along with the singleton
+/// that may precede it.
+fn truncate(tag: &str) -> Option<&str> {
+	let end = tag.rfind('-')?;
+	let truncated = &tag[..end];
+	match truncated.rfind('-') {
+		Some(i) if truncated.len() - i == 2 => Some(&truncated[..i]),
+		_ => Some(truncated),
+	}
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use crate::{InvalidLangTag, LangTagBuf};
+
+use super::{Extensions, Language, NormalLangTag, PrivateUse, Region, Script, Variants};
+
+/// Components of a normal language tag.
+///
+/// This is a view over the components of a tag that can be modified and
+/// displayed, for instance to compose a new tag with the `write!` macro.
+/// Use [`Self::build`] to get the resulting (validated) language tag.
+///
+/// ```
+/// use langtag::{LangTag, Region};
+/// use std::fmt::Write;
+///
+/// let tag = LangTag::new("fr-FR-x-foo").unwrap();
+/// let mut components = tag.components_display().unwrap();
+/// components.region = Some(Region::new("CA").unwrap());
+///
+/// let mut s = String::new();
+/// write!(s, "{components}").unwrap();
+/// assert_eq!(s, "fr-CA-x-foo");
+/// assert_eq!(components.build().unwrap(), "fr-CA-x-foo");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LangTagComponents<'a> {
+	/// Language subtags.
+	pub language: &'a Language,
+
+	/// Script subtag.
+	pub script: Option<&'a Script>,
+
+	/// Region subtag.
+	pub region: Option<&'a Region>,
+
+	/// Variant subtags.
+	pub variants: &'a Variants,
+
+	/// Extension subtags.
+	pub extensions: &'a Extensions,
+
+	/// Private use subtags.
+	pub private_use: Option<&'a PrivateUse>,
+}
+
+impl<'a> LangTagComponents<'a> {
+	/// Builds the language tag made of these components.
+	///
+	/// The result is validated before being returned: this fails if it
+	/// repeats a variant or an extension singleton (see
+	/// [`StructureError`](crate::StructureError)).
+	pub fn build(&self) -> Result<LangTagBuf, InvalidLangTag<String>> {
+		let tag = LangTagBuf::new(self.to_string())?;
+		match tag.validate_structure() {
+			Ok(()) => Ok(tag),
+			Err(_) => Err(InvalidLangTag(tag.into_string())),
+		}
+	}
+}
+
+impl fmt::Display for LangTagComponents<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.language.as_str())?;
+
+		let rest = [
+			self.script.map(Script::as_str),
+			self.region.map(Region::as_str),
+			Some(self.variants.as_str()),
+			Some(self.extensions.as_str()),
+			self.private_use.map(PrivateUse::as_str),
+		];
+
+		for component in rest.into_iter().flatten() {
+			if !component.is_empty() {
+				write!(f, "-{component}")?
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl NormalLangTag {
+	/// Returns a displayable view over the components of this tag.
+	pub fn components_display(&self) -> LangTagComponents<'_> {
+		LangTagComponents {
+			language: self.language(),
+			script: self.script(),
+			region: self.region(),
+			variants: self.variants(),
+			extensions: self.extensions(),
+			private_use: self.private_use(),
+		}
+	}
+}
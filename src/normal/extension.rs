@@ -39,6 +39,33 @@ impl Singleton {
 	pub fn unwrap(self) -> u8 {
 		self.0
 	}
+
+	/// Returns the singleton as a single byte slice.
+	#[inline]
+	pub fn as_bytes(&self) -> &[u8] {
+		std::slice::from_ref(&self.0)
+	}
+
+	/// Returns the singleton as a single character string.
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	/// Checks that this singleton is equal to `other`, ignoring case.
+	#[inline]
+	pub fn eq_ignore_case(self, other: Self) -> bool {
+		self.0.eq_ignore_ascii_case(&other.0)
+	}
+
+	/// Returns an iterator over all the lowercase singletons, in order
+	/// (`0` to `9`, then `a` to `z` except `x`).
+	pub fn all() -> impl Iterator<Item = Singleton> {
+		(b'0'..=b'9')
+			.chain(b'a'..=b'z')
+			.filter(|c| *c != b'x')
+			.map(Self)
+	}
 }
 
 impl FromStr for Singleton {
@@ -73,6 +100,27 @@ impl TryFrom<char> for Singleton {
 	}
 }
 
+impl<'a> TryFrom<&'a str> for Singleton {
+	type Error = InvalidSingleton<&'a str>;
+
+	#[inline]
+	fn try_from(str: &'a str) -> Result<Singleton, InvalidSingleton<&'a str>> {
+		Self::from_string(str)
+	}
+}
+
+impl TryFrom<String> for Singleton {
+	type Error = InvalidSingleton<String>;
+
+	#[inline]
+	fn try_from(string: String) -> Result<Singleton, InvalidSingleton<String>> {
+		match Self::from_string(&string) {
+			Ok(singleton) => Ok(singleton),
+			Err(_) => Err(InvalidSingleton(string)),
+		}
+	}
+}
+
 impl PartialEq<u8> for Singleton {
 	fn eq(&self, b: &u8) -> bool {
 		self.0 == *b
@@ -97,6 +145,35 @@ impl fmt::Debug for Singleton {
 	}
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Singleton {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Singleton {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor;
+
+		impl serde::de::Visitor<'_> for Visitor {
+			type Value = Singleton;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "extension singleton")
+			}
+
+			fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+				Singleton::from_string(v)
+					.map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+			}
+		}
+
+		deserializer.deserialize_str(Visitor)
+	}
+}
+
 /// Single extension and its subtags.
 ///
 /// Extensions provide a mechanism for extending language tags for use in
@@ -122,7 +199,7 @@ impl Extension {
 		Singleton(self.0.as_bytes()[0])
 	}
 
-	pub fn iter(&self) -> ExtensionIter {
+	pub fn iter(&self) -> ExtensionIter<'_> {
 		ExtensionIter::new(&self.0)
 	}
 }
@@ -156,10 +233,104 @@ impl Hash for Extension {
 	}
 }
 
+impl super::NormalLangTagBuf {
+	/// Returns a handle to edit the extensions of this tag in place.
+	pub fn extensions_mut(&mut self) -> ExtensionsMut<'_> {
+		ExtensionsMut(self)
+	}
+}
+
+/// Mutable extensions of an owned normal language tag.
+///
+/// See [`NormalLangTagBuf::extensions_mut`](super::NormalLangTagBuf::extensions_mut).
+/// Singletons and subtags are compared ignoring case.
+pub struct ExtensionsMut<'a>(&'a mut super::NormalLangTagBuf);
+
+impl ExtensionsMut<'_> {
+	/// Appends the given subtag to the first extension introduced by
+	/// `singleton`, or adds a new extension after the others if there is
+	/// none.
+	pub fn insert(&mut self, singleton: Singleton, subtag: &ExtensionSubtag) {
+		let (offset, new) = match self.0.extensions().get_ignore_case(singleton) {
+			Some(extension) => (
+				extension.as_ptr() as usize - self.0.as_ptr() as usize + extension.len(),
+				format!("-{subtag}"),
+			),
+			None => (
+				self.0.extensions_range().end,
+				format!("-{singleton}-{subtag}"),
+			),
+		};
+
+		self.0 .0.insert_str(offset, &new)
+	}
+
+	/// Removes every extension introduced by `singleton`.
+	///
+	/// Returns `true` if an extension was removed.
+	pub fn remove(&mut self, singleton: Singleton) -> bool {
+		self.retain(|s, _| !s.eq_ignore_case(singleton))
+	}
+
+	/// Removes the given subtag from every extension introduced by
+	/// `singleton`. Extensions left without subtags are removed.
+	///
+	/// Returns `true` if a subtag was removed.
+	pub fn remove_subtag(&mut self, singleton: Singleton, subtag: &str) -> bool {
+		self.retain(|s, e| {
+			!s.eq_ignore_case(singleton)
+				|| !utils::case_insensitive_eq(e.as_bytes(), subtag.as_bytes())
+		})
+	}
+
+	/// Only keeps the extension subtags for which `f` returns `true`,
+	/// removing extensions left without subtags.
+	///
+	/// Returns `true` if a subtag was removed.
+	fn retain(&mut self, mut f: impl FnMut(Singleton, &ExtensionSubtag) -> bool) -> bool {
+		let range = self.0.extensions_range();
+		let mut extensions = String::new();
+		let mut removed = false;
+
+		for extension in self.0.extensions().iter() {
+			let singleton = extension.singleton();
+			let mut kept = String::new();
+			for subtag in extension.iter() {
+				if f(singleton, subtag) {
+					kept.push('-');
+					kept.push_str(subtag)
+				} else {
+					removed = true
+				}
+			}
+
+			if !kept.is_empty() {
+				if !extensions.is_empty() {
+					extensions.push('-')
+				}
+
+				extensions.push(singleton.unwrap() as char);
+				extensions.push_str(&kept)
+			}
+		}
+
+		if removed {
+			if extensions.is_empty() {
+				self.0 .0.replace_range((range.start - 1)..range.end, "")
+			} else {
+				self.0 .0.replace_range(range, &extensions)
+			}
+		}
+
+		removed
+	}
+}
+
 #[derive(Default)]
 pub struct ExtensionIter<'a> {
 	data: &'a str,
 	offset: usize,
+	end: usize,
 }
 
 impl<'a> ExtensionIter<'a> {
@@ -167,6 +338,7 @@ impl<'a> ExtensionIter<'a> {
 		Self {
 			data,
 			offset: 2, // start after the singleton
+			end: data.len(),
 		}
 	}
 
@@ -179,7 +351,7 @@ impl<'a> Iterator for ExtensionIter<'a> {
 	type Item = &'a ExtensionSubtag;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.offset < self.data.len() {
+		if self.offset < self.end {
 			let end = super::find_segment_end(self.data, self.offset);
 			let item = unsafe { ExtensionSubtag::new_unchecked(&self.data[self.offset..end]) };
 			self.offset = end + 1;
@@ -190,6 +362,19 @@ impl<'a> Iterator for ExtensionIter<'a> {
 	}
 }
 
+impl<'a> DoubleEndedIterator for ExtensionIter<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.end > self.offset {
+			let start = super::find_segment_start(self.data, self.end);
+			let item = unsafe { ExtensionSubtag::new_unchecked(&self.data[start..self.end]) };
+			self.end = start - 1;
+			Some(item)
+		} else {
+			None
+		}
+	}
+}
+
 /// List of extensions.
 ///
 /// A list of language tag extension, separated by a `-` character.
@@ -246,13 +431,76 @@ impl Extensions {
 		None
 	}
 
-	pub fn iter(&self) -> ExtensionsIter {
+	pub fn iter(&self) -> ExtensionsIter<'_> {
 		ExtensionsIter::new(&self.0)
 	}
 
-	pub fn iter_extension(&self, singleton: Singleton) -> ExtensionIter {
+	/// Returns an iterator over the subtags of all the extensions, each
+	/// paired with the singleton of its extension.
+	///
+	/// For instance `a-ext1-ext2-b-ext3` yields `(a, ext1)`, `(a, ext2)` and
+	/// `(b, ext3)`.
+	pub fn iter_subtags(&self) -> impl Iterator<Item = (Singleton, &ExtensionSubtag)> {
+		self.iter()
+			.flat_map(|e| e.iter().map(move |subtag| (e.singleton(), subtag)))
+	}
+
+	/// Checks if an extension is introduced by the given singleton, ignoring
+	/// case.
+	pub fn contains_singleton(&self, singleton: Singleton) -> bool {
+		self.get_ignore_case(singleton).is_some()
+	}
+
+	/// Returns the number of extensions (singletons).
+	pub fn count(&self) -> usize {
+		self.0.split('-').filter(|s| s.len() == 1).count()
+	}
+
+	/// Returns the number of extension subtags, not counting the singletons.
+	pub fn subtag_count(&self) -> usize {
+		self.0.split('-').filter(|s| s.len() > 1).count()
+	}
+
+	/// Returns the first extension introduced by the given singleton,
+	/// ignoring case.
+	fn get_ignore_case(&self, singleton: Singleton) -> Option<&Extension> {
+		self.iter()
+			.find(|e| e.singleton().eq_ignore_case(singleton))
+	}
+
+	pub fn iter_extension(&self, singleton: Singleton) -> ExtensionIter<'_> {
 		self.get(singleton).map(Extension::iter).unwrap_or_default()
 	}
+
+	/// Returns an iterator over the extensions sorted by singleton
+	/// (ignoring case), regardless of their order in the list.
+	///
+	/// This does not allocate.
+	pub fn iter_sorted(&self) -> SortedExtensionsIter<'_> {
+		SortedExtensionsIter {
+			extensions: self,
+			last: None,
+		}
+	}
+
+	/// Checks that the extensions are sorted by singleton, as required by the
+	/// canonical form of a language tag.
+	///
+	/// Singletons are compared case-insensitively, digits first then letters.
+	/// Returns `false` if the same singleton appears more than once.
+	pub fn is_canonically_ordered(&self) -> bool {
+		let mut previous: Option<u8> = None;
+		for e in self.iter() {
+			let singleton = utils::into_smallcase(e.singleton().unwrap());
+			if previous.is_some_and(|p| p >= singleton) {
+				return false;
+			}
+
+			previous = Some(singleton)
+		}
+
+		true
+	}
 }
 
 impl PartialEq for Extensions {
@@ -287,19 +535,37 @@ impl Hash for Extensions {
 pub struct ExtensionsIter<'a> {
 	data: &'a str,
 	offset: usize,
+	end: usize,
 }
 
 impl<'a> ExtensionsIter<'a> {
 	fn new(data: &'a str) -> Self {
-		Self { data, offset: 0 }
+		Self {
+			data,
+			offset: 0,
+			end: data.len(),
+		}
 	}
 }
 
 impl<'a> Iterator for ExtensionsIter<'a> {
 	type Item = &'a Extension;
 
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = if self.offset < self.end {
+			self.data[self.offset..self.end]
+				.split('-')
+				.filter(|s| s.len() == 1)
+				.count()
+		} else {
+			0
+		};
+
+		(len, Some(len))
+	}
+
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.offset < self.data.len() {
+		if self.offset < self.end {
 			let offset = self.offset + 2; // skip singleton;
 			let end = super::find_list_end(self.data, offset, |_, segment| {
 				ExtensionSubtag::new(segment).is_ok()
@@ -315,6 +581,55 @@ impl<'a> Iterator for ExtensionsIter<'a> {
 	}
 }
 
+impl<'a> ExactSizeIterator for ExtensionsIter<'a> {}
+
+impl<'a> DoubleEndedIterator for ExtensionsIter<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.end > self.offset {
+			// find the last singleton.
+			let mut start = super::find_segment_start(self.data, self.end);
+			while super::find_segment_end(self.data, start) - start > 1 {
+				start = super::find_segment_start(self.data, start - 1)
+			}
+
+			let item = unsafe { Extension::new_unchecked(&self.data[start..self.end]) };
+			self.end = start.saturating_sub(1);
+			Some(item)
+		} else {
+			None
+		}
+	}
+}
+
+/// Iterator over the extensions of a list, sorted by singleton.
+///
+/// Extensions sharing the same singleton are yielded in their original
+/// order.
+pub struct SortedExtensionsIter<'a> {
+	extensions: &'a Extensions,
+	last: Option<(u8, usize)>,
+}
+
+impl<'a> Iterator for SortedExtensionsIter<'a> {
+	type Item = &'a Extension;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut next: Option<((u8, usize), &'a Extension)> = None;
+
+		for (i, e) in self.extensions.iter().enumerate() {
+			let key = (utils::into_smallcase(e.singleton().unwrap()), i);
+			if self.last.is_none_or(|last| key > last) && next.is_none_or(|(k, _)| key < k) {
+				next = Some((key, e))
+			}
+		}
+
+		next.map(|(key, e)| {
+			self.last = Some(key);
+			e
+		})
+	}
+}
+
 /// Single extension subtag.
 ///
 /// Extension subtag found in a language tag extension.
@@ -29,7 +29,7 @@ use crate::utils::{self, str_eq};
 pub struct LanguageExtension(str);
 
 impl LanguageExtension {
-	pub fn iter(&self) -> LanguageExtensionIter {
+	pub fn iter(&self) -> LanguageExtensionIter<'_> {
 		LanguageExtensionIter::new(&self.0)
 	}
 }
@@ -67,11 +67,16 @@ impl Hash for LanguageExtension {
 pub struct LanguageExtensionIter<'a> {
 	data: &'a str,
 	offset: usize,
+	end: usize,
 }
 
 impl<'a> LanguageExtensionIter<'a> {
 	fn new(data: &'a str) -> Self {
-		Self { data, offset: 0 }
+		Self {
+			data,
+			offset: 0,
+			end: data.len(),
+		}
 	}
 
 	pub fn empty() -> Self {
@@ -82,8 +87,18 @@ impl<'a> LanguageExtensionIter<'a> {
 impl<'a> Iterator for LanguageExtensionIter<'a> {
 	type Item = &'a ExtendedLangTag;
 
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = if self.offset < self.end {
+			super::super::count_subtags(&self.data[self.offset..self.end])
+		} else {
+			0
+		};
+
+		(len, Some(len))
+	}
+
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.offset < self.data.len() {
+		if self.offset < self.end {
 			let end = super::super::find_segment_end(self.data, self.offset);
 			let item = unsafe { ExtendedLangTag::new_unchecked(&self.data[self.offset..end]) };
 			self.offset = end + 1;
@@ -94,6 +109,21 @@ impl<'a> Iterator for LanguageExtensionIter<'a> {
 	}
 }
 
+impl<'a> ExactSizeIterator for LanguageExtensionIter<'a> {}
+
+impl<'a> DoubleEndedIterator for LanguageExtensionIter<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.end > self.offset {
+			let start = super::super::find_segment_start(self.data, self.end);
+			let item = unsafe { ExtendedLangTag::new_unchecked(&self.data[start..self.end]) };
+			self.end = start.saturating_sub(1);
+			Some(item)
+		} else {
+			None
+		}
+	}
+}
+
 /// Single extended language subtag.
 ///
 /// Extended language subtags are used to identify certain specially
@@ -1,6 +1,6 @@
 use crate::utils::{self, str_eq};
 use static_regular_grammar::RegularGrammar;
-use std::hash::Hash;
+use std::{hash::Hash, ops::Deref};
 
 mod extlang;
 pub use extlang::*;
@@ -57,11 +57,144 @@ impl Language {
 
 	/// Return an iterator to the extended language subtags.
 	#[inline]
-	pub fn extension_subtags(&self) -> LanguageExtensionIter {
+	pub fn extension_subtags(&self) -> LanguageExtensionIter<'_> {
 		self.extension()
 			.map(LanguageExtension::iter)
 			.unwrap_or_default()
 	}
+
+	/// Checks if the primary language subtag is followed by at least one
+	/// extended language subtag (e.g. `zh-cmn`).
+	#[inline]
+	pub fn is_extlang_form(&self) -> bool {
+		self.0.contains('-')
+	}
+
+	/// Returns the first extended language subtag, if any.
+	///
+	/// For instance `zh-cmn` gives `cmn`. This is always `None` when the
+	/// primary language subtag has 4 characters or more, since it cannot be
+	/// followed by extended language subtags.
+	#[inline]
+	pub fn extended_primary(&self) -> Option<&ExtendedLangTag> {
+		self.extension_subtags().next()
+	}
+}
+
+impl super::NormalLangTagBuf {
+	/// Returns a mutable handle on the language subtags.
+	pub fn language_mut(&mut self) -> LanguageMut<'_> {
+		LanguageMut(self)
+	}
+}
+
+/// Mutable language subtags of an owned normal language tag.
+///
+/// See [`NormalLangTagBuf::language_mut`](super::NormalLangTagBuf::language_mut).
+pub struct LanguageMut<'a>(&'a mut super::NormalLangTagBuf);
+
+impl LanguageMut<'_> {
+	/// Replaces the primary language subtag.
+	///
+	/// Extended language subtags are removed if the new primary language
+	/// subtag is longer than 3 characters, since they would not be allowed
+	/// anymore.
+	pub fn set_primary(&mut self, primary: &PrimaryLanguage) {
+		let end = if primary.len() > 3 {
+			self.0.language_end()
+		} else {
+			self.0.language().primary().len()
+		};
+
+		self.0 .0.replace_range(..end, primary)
+	}
+
+	/// Returns a mutable handle on the extended language subtags, if the
+	/// primary language subtag allows them (2 or 3 characters).
+	pub fn extension_mut(&mut self) -> Option<LanguageExtensionMut<'_>> {
+		if self.0.language().primary().len() <= 3 {
+			Some(LanguageExtensionMut(self.0))
+		} else {
+			None
+		}
+	}
+}
+
+impl Deref for LanguageMut<'_> {
+	type Target = Language;
+
+	fn deref(&self) -> &Language {
+		self.0.language()
+	}
+}
+
+/// Mutable extended language subtags of an owned normal language tag.
+///
+/// See [`LanguageMut::extension_mut`].
+pub struct LanguageExtensionMut<'a>(&'a mut super::NormalLangTagBuf);
+
+impl LanguageExtensionMut<'_> {
+	/// Maximum number of extended language subtags.
+	pub const MAX_LEN: usize = 3;
+
+	/// Returns an iterator over the extended language subtags.
+	pub fn iter(&self) -> LanguageExtensionIter<'_> {
+		self.0.language().extension_subtags()
+	}
+
+	/// Appends the given extended language subtag.
+	///
+	/// Returns `false` if it is already present (ignoring case) or if
+	/// there are already [`Self::MAX_LEN`] extended language subtags.
+	pub fn insert(&mut self, extlang: &ExtendedLangTag) -> bool {
+		let mut len = 0;
+		for e in self.iter() {
+			if e == extlang {
+				return false;
+			}
+
+			len += 1
+		}
+
+		if len < Self::MAX_LEN {
+			let offset = self.0.language_end();
+			self.0 .0.insert_str(offset, &format!("-{extlang}"));
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Removes the given extended language subtag (ignoring case).
+	///
+	/// Returns `true` if it was present.
+	pub fn remove(&mut self, extlang: &str) -> bool {
+		match self.iter().find(|e| *e == extlang) {
+			Some(e) => {
+				let start = e.as_ptr() as usize - self.0.as_ptr() as usize;
+				let end = start + e.len();
+				self.0 .0.replace_range((start - 1)..end, "");
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+#[cfg(feature = "registry")]
+impl Language {
+	/// Checks if the primary and extended language subtags are registered in
+	/// the IANA Language Subtag Registry.
+	///
+	/// Extended language subtags must also be registered with the primary
+	/// language subtag as prefix: `zh-yue` is registered, `fr-yue` is not.
+	pub fn is_registered(&self) -> bool {
+		let primary = self.primary();
+		primary.is_registered()
+			&& self.extension_subtags().all(|extlang| {
+				crate::registry::extlang_prefix(extlang).is_some_and(|prefix| *primary == *prefix)
+			})
+	}
 }
 
 impl PartialEq for Language {
@@ -11,14 +11,10 @@ use crate::utils::{self, str_eq};
 /// # Grammar
 ///
 /// ```abnf
-/// PrimaryLanguage = 2*3ALPHA
+/// PrimaryLanguage = 2*8ALPHA
 /// ```
 #[derive(RegularGrammar)]
-#[grammar(
-	file = "src/grammar.abnf",
-	entry_point = "extlang",
-	cache = "automata/extlang.aut.cbor"
-)]
+#[grammar(cache = "automata/primary.aut.cbor")]
 #[grammar(sized(
 	PrimaryLanguageBuf,
 	derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)
@@ -26,6 +22,55 @@ use crate::utils::{self, str_eq};
 #[cfg_attr(feature = "serde", grammar(serde))]
 pub struct PrimaryLanguage(str);
 
+/// Classification of a primary language subtag, by length.
+///
+/// See [RFC 5646 section 2.2.1](https://www.rfc-editor.org/rfc/rfc5646#section-2.2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrimaryLanguageKind {
+	/// Two-letter ISO 639-1 code (`en`).
+	TwoLetter,
+
+	/// Three-letter ISO 639-2, ISO 639-3 or ISO 639-5 code (`ast`),
+	/// including the `qaa..qtz` private use range.
+	ThreeLetter,
+
+	/// Four-letter subtag, reserved for future use.
+	Reserved,
+
+	/// Five to eight letters subtag, registered individually (`dinka`).
+	Registered,
+}
+
+impl PrimaryLanguageKind {
+	/// Checks if extended language subtags may follow a primary language
+	/// subtag of this kind.
+	///
+	/// Only two and three-letter subtags can be followed by extended
+	/// language subtags.
+	pub fn allows_extlang(&self) -> bool {
+		matches!(self, Self::TwoLetter | Self::ThreeLetter)
+	}
+}
+
+impl PrimaryLanguage {
+	/// Returns the kind of this primary language subtag, given by its length.
+	///
+	/// ```
+	/// use langtag::{PrimaryLanguage, PrimaryLanguageKind};
+	///
+	/// assert_eq!(PrimaryLanguage::new("en").unwrap().kind(), PrimaryLanguageKind::TwoLetter);
+	/// assert_eq!(PrimaryLanguage::new("dinka").unwrap().kind(), PrimaryLanguageKind::Registered);
+	/// ```
+	pub fn kind(&self) -> PrimaryLanguageKind {
+		match self.0.len() {
+			2 => PrimaryLanguageKind::TwoLetter,
+			3 => PrimaryLanguageKind::ThreeLetter,
+			4 => PrimaryLanguageKind::Reserved,
+			_ => PrimaryLanguageKind::Registered,
+		}
+	}
+}
+
 impl PartialEq for PrimaryLanguage {
 	fn eq(&self, other: &Self) -> bool {
 		utils::case_insensitive_eq(self.as_bytes(), other.as_bytes())
@@ -54,3 +99,30 @@ impl Hash for PrimaryLanguage {
 		utils::case_insensitive_hash(self.as_bytes(), state)
 	}
 }
+
+#[cfg(feature = "registry")]
+impl PrimaryLanguage {
+	/// Checks if this language subtag is registered in the IANA Language
+	/// Subtag Registry, or in the private use range `qaa..qtz`.
+	///
+	/// Well-formed but unregistered subtags, such as `ax`, are not.
+	pub fn is_registered(&self) -> bool {
+		self.registry_fields().is_some()
+	}
+
+	/// Returns the `Preferred-Value` and `Suppress-Script` fields of this
+	/// language subtag, or `None` if it is not registered.
+	///
+	/// Subtags of the private use range `qaa..qtz` have no fields.
+	pub(crate) fn registry_fields(&self) -> Option<crate::registry::LanguageFields> {
+		let bytes = self.0.as_bytes();
+		if bytes.len() == 3
+			&& utils::into_smallcase(bytes[0]) == b'q'
+			&& (b'a'..=b't').contains(&utils::into_smallcase(bytes[1]))
+		{
+			return Some((None, None));
+		}
+
+		crate::registry::language(&self.0)
+	}
+}
@@ -20,6 +20,17 @@ pub use extension::*;
 mod private_use;
 pub use private_use::*;
 
+mod components;
+pub use components::*;
+
+mod mutable;
+
+mod structure;
+pub use structure::*;
+
+mod parsed;
+pub use parsed::*;
+
 /// Normal language tag.
 #[derive(RegularGrammar)]
 #[grammar(
@@ -47,8 +58,10 @@ impl NormalLangTag {
 	}
 
 	fn script_range(&self) -> Result<Range<usize>, usize> {
-		let language_end = self.language_end();
-		eprintln!("language_end = {language_end}");
+		self.script_range_after(self.language_end())
+	}
+
+	fn script_range_after(&self, language_end: usize) -> Result<Range<usize>, usize> {
 		let offset = language_end + 1;
 		let end = find_list_end(&self.0, offset, |i, segment| {
 			i == offset && Script::new(segment).is_ok()
@@ -60,6 +73,13 @@ impl NormalLangTag {
 		}
 	}
 
+	/// Returns a tag made of the language subtags only.
+	///
+	/// For instance `zh-yue-Hant-HK-x-foo` gives `zh-yue`.
+	pub fn primary_tag(&self) -> crate::LangTagBuf {
+		unsafe { crate::LangTagBuf::new_unchecked(self.language().as_str().to_owned()) }
+	}
+
 	/// Returns the script subtag, if any.
 	pub fn script(&self) -> Option<&Script> {
 		self.script_range()
@@ -68,12 +88,10 @@ impl NormalLangTag {
 	}
 
 	fn region_range(&self) -> Result<Range<usize>, usize> {
-		let script_end = match self.script_range() {
-			Ok(range) => range.end,
-			Err(i) => i,
-		};
-		eprintln!("script_end = {script_end}");
+		self.region_range_after(optional_range_end(&self.script_range()))
+	}
 
+	fn region_range_after(&self, script_end: usize) -> Result<Range<usize>, usize> {
 		let offset = script_end + 1;
 		let end = find_list_end(&self.0, offset, |i, segment| {
 			i == offset && Region::new(segment).is_ok()
@@ -93,12 +111,10 @@ impl NormalLangTag {
 	}
 
 	fn variants_range(&self) -> Range<usize> {
-		let region_end = match self.region_range() {
-			Ok(range) => range.end,
-			Err(i) => i,
-		};
-		eprintln!("region_end = {region_end}");
+		self.variants_range_after(optional_range_end(&self.region_range()))
+	}
 
+	fn variants_range_after(&self, region_end: usize) -> Range<usize> {
 		let offset = region_end + 1;
 		let end = find_list_end(&self.0, offset, |_, segment| Variant::new(segment).is_ok());
 		if end == offset {
@@ -113,10 +129,22 @@ impl NormalLangTag {
 		unsafe { Variants::new_unchecked(&self.0[self.variants_range()]) }
 	}
 
-	fn extensions_range(&self) -> Range<usize> {
+	/// Returns the byte offset of the first extension singleton or private
+	/// use `x`, or the length of the tag if there are none.
+	pub fn extension_split_offset(&self) -> usize {
 		let variants_end = self.variants_range().end;
-		eprintln!("variants_end = {variants_end}");
+		if variants_end < self.0.len() {
+			variants_end + 1
+		} else {
+			self.0.len()
+		}
+	}
+
+	fn extensions_range(&self) -> Range<usize> {
+		self.extensions_range_after(self.variants_range().end)
+	}
 
+	fn extensions_range_after(&self, variants_end: usize) -> Range<usize> {
 		let offset = variants_end + 1;
 		let end = find_list_end(&self.0, offset, |_, segment| {
 			Singleton::from_string(segment).is_ok() || ExtensionSubtag::new(segment).is_ok()
@@ -131,15 +159,16 @@ impl NormalLangTag {
 
 	/// Returns the extension subtags.
 	pub fn extensions(&self) -> &Extensions {
-		eprintln!("looking for extensions in {}", &self.0);
 		unsafe { Extensions::new_unchecked(&self.0[self.extensions_range()]) }
 	}
 
 	fn private_use_offset(&self) -> Option<usize> {
-		let variants_end = self.variants_range().end;
+		self.private_use_offset_after(self.extensions_range().end)
+	}
 
-		if variants_end < self.0.len() {
-			Some(variants_end + 1)
+	fn private_use_offset_after(&self, extensions_end: usize) -> Option<usize> {
+		if extensions_end < self.0.len() {
+			Some(extensions_end + 1)
 		} else {
 			None
 		}
@@ -151,12 +180,82 @@ impl NormalLangTag {
 			.map(|i| unsafe { PrivateUse::new_unchecked(&self.0[i..]) })
 	}
 
-	pub fn private_use_subtags(&self) -> PrivateUseIter {
+	pub fn private_use_subtags(&self) -> PrivateUseIter<'_> {
 		match self.private_use() {
 			Some(p) => p.iter(),
 			None => PrivateUseIter::empty(),
 		}
 	}
+
+	/// Checks that this tag is equal to `other` (ignoring case) when variants
+	/// are ignored.
+	///
+	/// For instance `de-CH-1996` and `de-CH` are considered equal.
+	pub fn eq_ignore_variants(&self, other: &Self) -> bool {
+		let (a_before, a_after) = self.split_around_variants();
+		let (b_before, b_after) = other.split_around_variants();
+		utils::case_insensitive_eq(a_before.as_bytes(), b_before.as_bytes())
+			&& utils::case_insensitive_eq(a_after.as_bytes(), b_after.as_bytes())
+	}
+
+	/// Checks that this tag is equal to `other` (ignoring case) when
+	/// extensions and private use subtags are ignored.
+	///
+	/// Only the language, script, region and variant subtags are compared:
+	/// `en-US-a-foo` and `en-US-b-bar-x-baz` are considered equal.
+	pub fn eq_ignoring_extensions(&self, other: &Self) -> bool {
+		let a = &self.0[..self.variants_range().end];
+		let b = &other.0[..other.variants_range().end];
+		utils::case_insensitive_eq(a.as_bytes(), b.as_bytes())
+	}
+
+	/// Checks that this tag is equal to `other` (ignoring case) when private
+	/// use subtags are ignored.
+	///
+	/// For instance `en-US-x-foo` and `en-US` are considered equal.
+	pub fn eq_ignoring_private_use(&self, other: &Self) -> bool {
+		let a = &self.0[..self.extensions_range().end];
+		let b = &other.0[..other.extensions_range().end];
+		utils::case_insensitive_eq(a.as_bytes(), b.as_bytes())
+	}
+
+	/// Returns the parts of the tag before and after the variants, without
+	/// the separators delimiting the variants.
+	fn split_around_variants(&self) -> (&str, &str) {
+		let range = self.variants_range();
+		let before_end = if range.is_empty() {
+			range.start
+		} else {
+			range.start - 1
+		};
+
+		(&self.0[..before_end], &self.0[range.end..])
+	}
+}
+
+impl NormalLangTagBuf {
+	/// Sets, replaces or removes the script subtag.
+	pub fn set_script(&mut self, script: Option<&Script>) {
+		let range = self.script_range();
+		self.set_optional_subtag(range, script.map(Script::as_str))
+	}
+
+	/// Sets, replaces or removes the region subtag.
+	pub fn set_region(&mut self, region: Option<&Region>) {
+		let range = self.region_range();
+		self.set_optional_subtag(range, region.map(Region::as_str))
+	}
+
+	/// Sets, replaces or removes an optional subtag, given its range or the
+	/// offset at which it would be inserted.
+	fn set_optional_subtag(&mut self, range: Result<Range<usize>, usize>, value: Option<&str>) {
+		match (range, value) {
+			(Ok(range), Some(value)) => self.0.replace_range(range, value),
+			(Ok(range), None) => self.0.replace_range((range.start - 1)..range.end, ""),
+			(Err(offset), Some(value)) => self.0.insert_str(offset, &format!("-{value}")),
+			(Err(_), None) => (),
+		}
+	}
 }
 
 impl PartialEq for NormalLangTag {
@@ -188,9 +287,22 @@ impl Hash for NormalLangTag {
 	}
 }
 
+/// Returns the end of an optional subtag given its range, or the offset at
+/// which it would be inserted.
+fn optional_range_end(range: &Result<Range<usize>, usize>) -> usize {
+	match range {
+		Ok(range) => range.end,
+		Err(i) => *i,
+	}
+}
+
 /// Find the end of a subtag list using the `f` function to determine which
 /// subtag is part of the list.
 fn find_list_end(string: &str, mut offset: usize, mut f: impl FnMut(usize, &str) -> bool) -> usize {
+	if offset > string.len() {
+		return offset;
+	}
+
 	let bytes = string.as_bytes();
 	let mut i = offset;
 	let mut end = i;
@@ -216,6 +328,15 @@ fn find_list_end(string: &str, mut offset: usize, mut f: impl FnMut(usize, &str)
 	}
 }
 
+/// Counts the `-` separated subtags of the given string, without allocating.
+fn count_subtags(string: &str) -> usize {
+	if string.is_empty() {
+		0
+	} else {
+		string.bytes().filter(|b| *b == b'-').count() + 1
+	}
+}
+
 fn find_segment_end(string: &str, offset: usize) -> usize {
 	let bytes = string.as_bytes();
 	let mut i = offset;
@@ -0,0 +1,50 @@
+use crate::LangTagBuf;
+
+use super::NormalLangTagBuf;
+
+/// Owned language tag temporarily moved into a normal language tag.
+///
+/// The string is moved back to the target when dropped, including when
+/// unwinding from a panic.
+struct Restore<'a> {
+	target: &'a mut LangTagBuf,
+	tag: NormalLangTagBuf,
+}
+
+impl Drop for Restore<'_> {
+	fn drop(&mut self) {
+		// A normal language tag is always a valid language tag.
+		self.target.0 = std::mem::take(&mut self.tag.0)
+	}
+}
+
+impl LangTagBuf {
+	/// Calls `f` with this tag as an owned normal language tag that can be
+	/// edited in place, if it is one.
+	///
+	/// The changes are written back to this tag when `f` returns. Returns
+	/// `None`, without calling `f`, for private use and grandfathered tags
+	/// (see [`LangTag::is_normal`](crate::LangTag::is_normal)), including
+	/// regular ones such as `zh-hakka` that would silently stop being
+	/// grandfathered.
+	///
+	/// ```
+	/// use langtag::{LangTagBuf, NormalLangTagBuf};
+	///
+	/// let mut tag = LangTagBuf::new("fr-FR".to_owned()).unwrap();
+	/// tag.with_normal_mut(|t| *t = NormalLangTagBuf::new("fr-CA".to_owned()).unwrap());
+	/// assert_eq!(tag, "fr-CA");
+	///
+	/// let mut tag = LangTagBuf::new("i-klingon".to_owned()).unwrap();
+	/// assert!(tag.with_normal_mut(|_| ()).is_none());
+	/// ```
+	pub fn with_normal_mut<R>(&mut self, f: impl FnOnce(&mut NormalLangTagBuf) -> R) -> Option<R> {
+		if !self.is_normal() {
+			return None;
+		}
+
+		let tag = unsafe { NormalLangTagBuf::new_unchecked(std::mem::take(&mut self.0)) };
+		let mut restore = Restore { target: self, tag };
+		Some(f(&mut restore.tag))
+	}
+}
@@ -0,0 +1,168 @@
+use std::ops::{Deref, Range};
+
+use super::{
+	optional_range_end, Extensions, Language, NormalLangTag, NormalLangTagBuf, PrivateUse,
+	PrivateUseIter, Region, Script, Variants,
+};
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Normal language tag storage that can be wrapped in a [`ParsedLangTag`].
+///
+/// This trait is sealed and implemented for `&NormalLangTag` and
+/// `NormalLangTagBuf` only, which always dereference to the same tag, so
+/// that the offsets cached by [`ParsedLangTag`] stay valid.
+pub trait NormalLangTagStorage: sealed::Sealed + Deref<Target = NormalLangTag> {}
+
+impl sealed::Sealed for &NormalLangTag {}
+
+impl NormalLangTagStorage for &NormalLangTag {}
+
+impl sealed::Sealed for NormalLangTagBuf {}
+
+impl NormalLangTagStorage for NormalLangTagBuf {}
+
+/// Normal language tag with precomputed component offsets.
+///
+/// The accessors of [`NormalLangTag`] scan the tag each time they are
+/// called. This type scans it once, when it is created, and stores the
+/// boundaries of each component so that they can be accessed in constant
+/// time. It dereferences to the underlying [`NormalLangTag`].
+///
+/// ```
+/// use langtag::{NormalLangTag, NormalLangTagBuf};
+///
+/// let tag = NormalLangTag::new("fr-Latn-FR-1694acad-x-foo").unwrap().parsed();
+/// assert_eq!(tag.script().unwrap(), "Latn");
+/// assert_eq!(tag.region().unwrap(), "FR");
+///
+/// let tag = NormalLangTagBuf::new("en-US".to_owned()).unwrap().into_parsed();
+/// assert_eq!(tag.language(), "en");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParsedLangTag<T = NormalLangTagBuf> {
+	tag: T,
+	language_end: usize,
+	script: Result<Range<usize>, usize>,
+	region: Result<Range<usize>, usize>,
+	variants: Range<usize>,
+	extensions: Range<usize>,
+	private_use: Option<usize>,
+}
+
+impl<T: NormalLangTagStorage> ParsedLangTag<T> {
+	/// Parses the components of the given tag.
+	pub fn new(tag: T) -> Self {
+		let language_end = tag.language_end();
+		let script = tag.script_range_after(language_end);
+		let region = tag.region_range_after(optional_range_end(&script));
+		let variants = tag.variants_range_after(optional_range_end(&region));
+		let extensions = tag.extensions_range_after(variants.end);
+		let private_use = tag.private_use_offset_after(extensions.end);
+
+		Self {
+			tag,
+			language_end,
+			script,
+			region,
+			variants,
+			extensions,
+			private_use,
+		}
+	}
+
+	/// Returns the underlying tag.
+	pub fn as_normal_lang_tag(&self) -> &NormalLangTag {
+		&self.tag
+	}
+
+	/// Returns the underlying tag, dropping the cached offsets.
+	pub fn into_inner(self) -> T {
+		self.tag
+	}
+
+	/// Returns the language subtags.
+	pub fn language(&self) -> &Language {
+		unsafe { Language::new_unchecked(&self.tag.0[..self.language_end]) }
+	}
+
+	/// Returns the script subtag, if any.
+	pub fn script(&self) -> Option<&Script> {
+		self.script
+			.clone()
+			.ok()
+			.map(|range| unsafe { Script::new_unchecked(&self.tag.0[range]) })
+	}
+
+	/// Returns the region subtag, if any.
+	pub fn region(&self) -> Option<&Region> {
+		self.region
+			.clone()
+			.ok()
+			.map(|range| unsafe { Region::new_unchecked(&self.tag.0[range]) })
+	}
+
+	/// Returns the variant subtags.
+	pub fn variants(&self) -> &Variants {
+		unsafe { Variants::new_unchecked(&self.tag.0[self.variants.clone()]) }
+	}
+
+	/// Returns the extension subtags.
+	pub fn extensions(&self) -> &Extensions {
+		unsafe { Extensions::new_unchecked(&self.tag.0[self.extensions.clone()]) }
+	}
+
+	/// Returns the private use subtags.
+	pub fn private_use(&self) -> Option<&PrivateUse> {
+		self.private_use
+			.map(|i| unsafe { PrivateUse::new_unchecked(&self.tag.0[i..]) })
+	}
+
+	/// Returns an iterator over the private use subtags.
+	pub fn private_use_subtags(&self) -> PrivateUseIter<'_> {
+		match self.private_use() {
+			Some(p) => p.iter(),
+			None => PrivateUseIter::empty(),
+		}
+	}
+}
+
+impl<T: NormalLangTagStorage> Deref for ParsedLangTag<T> {
+	type Target = NormalLangTag;
+
+	fn deref(&self) -> &NormalLangTag {
+		&self.tag
+	}
+}
+
+impl<T: NormalLangTagStorage> AsRef<NormalLangTag> for ParsedLangTag<T> {
+	fn as_ref(&self) -> &NormalLangTag {
+		&self.tag
+	}
+}
+
+impl From<NormalLangTagBuf> for ParsedLangTag {
+	fn from(tag: NormalLangTagBuf) -> Self {
+		Self::new(tag)
+	}
+}
+
+impl NormalLangTag {
+	/// Parses the components of this tag once, for fast repeated access.
+	///
+	/// See [`ParsedLangTag`].
+	pub fn parsed(&self) -> ParsedLangTag<&Self> {
+		ParsedLangTag::new(self)
+	}
+}
+
+impl NormalLangTagBuf {
+	/// Parses the components of this tag once, for fast repeated access.
+	///
+	/// See [`ParsedLangTag`].
+	pub fn into_parsed(self) -> ParsedLangTag {
+		ParsedLangTag::new(self)
+	}
+}
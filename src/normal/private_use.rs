@@ -17,9 +17,47 @@ use std::hash::Hash;
 pub struct PrivateUse(str);
 
 impl PrivateUse {
-	pub fn iter(&self) -> PrivateUseIter {
+	pub fn iter(&self) -> PrivateUseIter<'_> {
 		PrivateUseIter::new(&self.0)
 	}
+
+	/// Returns the private use subtags, without the leading `x-`.
+	///
+	/// For instance `x-ext1-ext2` gives `ext1-ext2`.
+	pub fn subtags_str(&self) -> &str {
+		&self.0[2..]
+	}
+
+	/// Returns the first private use subtag.
+	pub fn first(&self) -> &PrivateUseSubtag {
+		let end = super::find_segment_end(&self.0, 2);
+		unsafe { PrivateUseSubtag::new_unchecked(&self.0[2..end]) }
+	}
+
+	/// Returns the last private use subtag.
+	pub fn last(&self) -> &PrivateUseSubtag {
+		let start = super::find_segment_start(&self.0, self.0.len());
+		unsafe { PrivateUseSubtag::new_unchecked(&self.0[start..]) }
+	}
+
+	/// Returns the number of private use subtags, not counting the `x`
+	/// singleton.
+	///
+	/// This shadows [`str::len`], use [`PrivateUse::as_str`] to get the length
+	/// in bytes.
+	pub fn len(&self) -> usize {
+		super::count_subtags(&self.0) - 1
+	}
+
+	/// Checks if the given private use subtag is present, ignoring case.
+	pub fn contains(&self, subtag: &PrivateUseSubtag) -> bool {
+		self.iter().any(|s| s == subtag)
+	}
+
+	/// Always returns `false`: there is at least one private use subtag.
+	pub fn is_empty(&self) -> bool {
+		false
+	}
 }
 
 impl PartialEq for PrivateUse {
@@ -55,6 +93,7 @@ impl Hash for PrivateUse {
 pub struct PrivateUseIter<'a> {
 	data: &'a str,
 	offset: usize,
+	end: usize,
 }
 
 impl<'a> PrivateUseIter<'a> {
@@ -62,6 +101,7 @@ impl<'a> PrivateUseIter<'a> {
 		Self {
 			data,
 			offset: 2, // start after the singleton
+			end: data.len(),
 		}
 	}
 
@@ -73,8 +113,18 @@ impl<'a> PrivateUseIter<'a> {
 impl<'a> Iterator for PrivateUseIter<'a> {
 	type Item = &'a PrivateUseSubtag;
 
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = if self.offset < self.end {
+			super::count_subtags(&self.data[self.offset..self.end])
+		} else {
+			0
+		};
+
+		(len, Some(len))
+	}
+
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.offset < self.data.len() {
+		if self.offset < self.end {
 			let end = super::find_segment_end(self.data, self.offset);
 			let item = unsafe { &PrivateUseSubtag::new_unchecked(&self.data[self.offset..end]) };
 			self.offset = end + 1;
@@ -85,6 +135,21 @@ impl<'a> Iterator for PrivateUseIter<'a> {
 	}
 }
 
+impl<'a> ExactSizeIterator for PrivateUseIter<'a> {}
+
+impl<'a> DoubleEndedIterator for PrivateUseIter<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.end > self.offset {
+			let start = super::find_segment_start(self.data, self.end);
+			let item = unsafe { PrivateUseSubtag::new_unchecked(&self.data[start..self.end]) };
+			self.end = start - 1;
+			Some(item)
+		} else {
+			None
+		}
+	}
+}
+
 /// Private use subtag.
 ///
 /// # Grammar
@@ -22,6 +22,75 @@ use crate::utils::{self, str_eq};
 #[cfg_attr(feature = "serde", grammar(serde))]
 pub struct Region(str);
 
+impl Region {
+	/// Checks if this region is a UN M.49 numeric code (e.g. `419`) rather
+	/// than an ISO 3166-1 alpha-2 code (e.g. `FR`).
+	pub fn is_numeric(&self) -> bool {
+		self.0.as_bytes()[0].is_ascii_digit()
+	}
+}
+
+#[cfg(feature = "registry")]
+impl Region {
+	/// Checks if this region is registered in the IANA Language Subtag
+	/// Registry, or in the private use ranges `QM..QZ` and `XA..XZ`.
+	pub fn is_registered(&self) -> bool {
+		crate::registry::is_registered_region(&self.0)
+	}
+
+	/// Returns the UN M.49 numeric code of this region.
+	///
+	/// For instance `US` maps to `840` and `FR` to `250`. Numeric regions are
+	/// returned as is. Returns `None` if this alpha-2 code has no numeric
+	/// counterpart.
+	pub fn to_numeric(&self) -> Option<RegionBuf> {
+		if self.is_numeric() {
+			Some(self.to_owned())
+		} else {
+			crate::registry::region_alpha2_to_numeric(&self.0)
+				.map(|code| unsafe { RegionBuf::new_unchecked(code.to_owned()) })
+		}
+	}
+
+	/// Returns the ISO 3166-1 alpha-2 code of this region.
+	///
+	/// For instance `840` maps to `US`. Alpha-2 regions are returned as is.
+	/// Returns `None` if this numeric code does not designate a country, as
+	/// is the case for macro-regions such as `419` (Latin America).
+	pub fn to_alpha2(&self) -> Option<RegionBuf> {
+		if self.is_numeric() {
+			crate::registry::region_numeric_to_alpha2(&self.0)
+				.map(|code| unsafe { RegionBuf::new_unchecked(code.to_owned()) })
+		} else {
+			Some(self.to_owned())
+		}
+	}
+
+	/// Returns the UN M.49 regions containing this region, from the closest
+	/// to the largest.
+	///
+	/// For instance `FR` is contained in `155` (Western Europe), `150`
+	/// (Europe) and `001` (World). Numeric country codes are handled like
+	/// their alpha-2 equivalent. Returns an empty list for `001` and unknown
+	/// regions.
+	pub fn containing_regions(&self) -> Vec<RegionBuf> {
+		let code = if self.is_numeric() {
+			crate::registry::region_numeric_to_alpha2(&self.0).unwrap_or(&self.0)
+		} else {
+			&self.0
+		};
+
+		let mut result = Vec::new();
+		let mut current = code;
+		while let Some(parent) = crate::registry::region_parent(current) {
+			result.push(unsafe { RegionBuf::new_unchecked(parent.to_owned()) });
+			current = parent
+		}
+
+		result
+	}
+}
+
 impl PartialEq for Region {
 	fn eq(&self, other: &Self) -> bool {
 		utils::case_insensitive_eq(self.as_bytes(), other.as_bytes())
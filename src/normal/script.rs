@@ -46,3 +46,20 @@ impl Hash for Script {
 		utils::case_insensitive_hash(self.as_bytes(), state)
 	}
 }
+
+#[cfg(feature = "registry")]
+impl Script {
+	/// Checks if this script is registered in the IANA Language Subtag
+	/// Registry, or in the private use range `Qaaa..Qabx`.
+	pub fn is_registered(&self) -> bool {
+		crate::registry::is_registered_script(&self.0)
+	}
+
+	/// Checks if this script is the `Suppress-Script` of the given language,
+	/// meaning that it should not be used with this language.
+	///
+	/// For instance `Latn` is the `Suppress-Script` of `en`.
+	pub fn is_suppress_script_for(&self, language: &super::PrimaryLanguage) -> bool {
+		crate::registry::suppress_script(language).is_some_and(|script| *self == *script)
+	}
+}
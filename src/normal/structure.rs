@@ -0,0 +1,46 @@
+use super::{NormalLangTag, Singleton, VariantBuf};
+use crate::utils;
+
+/// Structural rule violated by a well-formed language tag.
+///
+/// The grammar already bounds the number of extended language (at most 3),
+/// script (at most 1) and region (at most 1) subtags, but does not prevent
+/// repetitions forbidden by RFC 5646 sections 2.2.5 and 2.2.6.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StructureError {
+	/// The same variant subtag appears more than once.
+	#[error("duplicate variant `{0}`")]
+	DuplicateVariant(VariantBuf),
+
+	/// The same extension singleton appears more than once.
+	#[error("duplicate extension singleton `{0}`")]
+	DuplicateSingleton(Singleton),
+}
+
+impl NormalLangTag {
+	/// Checks that this tag follows the structural rules of RFC 5646 that
+	/// are not enforced by the grammar.
+	///
+	/// See [`StructureError`].
+	pub fn validate_structure(&self) -> Result<(), StructureError> {
+		let variants = self.variants();
+		for (i, variant) in variants.iter().enumerate() {
+			if variants.iter().take(i).any(|v| v == variant) {
+				return Err(StructureError::DuplicateVariant(variant.to_owned()));
+			}
+		}
+
+		let extensions = self.extensions();
+		for (i, extension) in extensions.iter().enumerate() {
+			let singleton = extension.singleton();
+			if extensions.iter().take(i).any(|e| {
+				utils::into_smallcase(e.singleton().unwrap())
+					== utils::into_smallcase(singleton.unwrap())
+			}) {
+				return Err(StructureError::DuplicateSingleton(singleton));
+			}
+		}
+
+		Ok(())
+	}
+}
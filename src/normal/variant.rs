@@ -1,4 +1,4 @@
-use std::hash::Hash;
+use std::{hash::Hash, ops::Deref};
 
 use static_regular_grammar::RegularGrammar;
 
@@ -18,6 +18,26 @@ use crate::utils::{self, str_eq};
 #[cfg_attr(feature = "serde", grammar(serde))]
 pub struct Variant(str);
 
+#[cfg(feature = "registry")]
+impl Variant {
+	/// Checks if this variant is registered in the IANA Language Subtag
+	/// Registry.
+	///
+	/// Well-formed but unregistered variants, such as `shadok`, are not.
+	pub fn is_registered(&self) -> bool {
+		crate::registry::is_registered_variant(&self.0)
+	}
+
+	/// Returns the registered `Prefix` fields of this variant.
+	///
+	/// A variant with prefixes should only be used after one of them: for
+	/// instance `nedis` has the `sl` prefix. Returns an empty slice if the
+	/// variant is not registered or has no prefix.
+	pub fn valid_prefixes(&self) -> &'static [&'static str] {
+		crate::registry::variant_prefixes(&self.0).unwrap_or_default()
+	}
+}
+
 impl PartialEq for Variant {
 	fn eq(&self, other: &Self) -> bool {
 		utils::case_insensitive_eq(self.as_bytes(), other.as_bytes())
@@ -69,7 +89,7 @@ impl Hash for Variant {
 pub struct Variants(str);
 
 impl Variants {
-	pub fn iter(&self) -> VariantsIter {
+	pub fn iter(&self) -> VariantsIter<'_> {
 		VariantsIter::new(&self.0)
 	}
 
@@ -80,6 +100,24 @@ impl Variants {
 	pub fn last(&self) -> Option<&Variant> {
 		self.iter().next_back()
 	}
+
+	/// Returns the number of variant subtags.
+	///
+	/// This shadows [`str::len`], use [`Variants::as_str`] to get the length
+	/// in bytes.
+	pub fn len(&self) -> usize {
+		super::count_subtags(&self.0)
+	}
+
+	/// Checks if there are no variant subtags.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Checks if the given variant is present, ignoring case.
+	pub fn contains(&self, variant: &Variant) -> bool {
+		self.iter().any(|v| v == variant)
+	}
 }
 
 impl PartialEq for Variants {
@@ -111,6 +149,45 @@ impl Hash for Variants {
 	}
 }
 
+impl super::NormalLangTagBuf {
+	/// Returns a handle to edit the variants of this tag in place.
+	pub fn variants_mut(&mut self) -> VariantsMut<'_> {
+		VariantsMut(self)
+	}
+}
+
+/// Mutable variants of an owned normal language tag.
+///
+/// See [`NormalLangTagBuf::variants_mut`](super::NormalLangTagBuf::variants_mut).
+pub struct VariantsMut<'a>(&'a mut super::NormalLangTagBuf);
+
+impl VariantsMut<'_> {
+	/// Appends the given variant after the last one, or after the language,
+	/// script and region subtags if there are no variants.
+	pub fn push(&mut self, variant: &Variant) {
+		let offset = self.0.variants_range().end;
+		self.0 .0.insert_str(offset, &format!("-{variant}"))
+	}
+
+	/// Removes the last variant and returns it, if any.
+	pub fn pop(&mut self) -> Option<VariantBuf> {
+		let last = self.0.variants().last()?;
+		let start = last.as_ptr() as usize - self.0.as_ptr() as usize;
+		let end = start + last.len();
+		let last = last.to_owned();
+		self.0 .0.replace_range((start - 1)..end, "");
+		Some(last)
+	}
+}
+
+impl Deref for VariantsMut<'_> {
+	type Target = Variants;
+
+	fn deref(&self) -> &Variants {
+		self.0.variants()
+	}
+}
+
 #[derive(Default)]
 pub struct VariantsIter<'a> {
 	data: &'a str,
@@ -135,6 +212,16 @@ impl<'a> VariantsIter<'a> {
 impl<'a> Iterator for VariantsIter<'a> {
 	type Item = &'a Variant;
 
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = if self.offset < self.end {
+			super::count_subtags(&self.data[self.offset..self.end])
+		} else {
+			0
+		};
+
+		(len, Some(len))
+	}
+
 	fn next(&mut self) -> Option<Self::Item> {
 		if self.offset < self.end {
 			let end = super::find_segment_end(self.data, self.offset);
@@ -147,6 +234,8 @@ impl<'a> Iterator for VariantsIter<'a> {
 	}
 }
 
+impl<'a> ExactSizeIterator for VariantsIter<'a> {}
+
 impl<'a> DoubleEndedIterator for VariantsIter<'a> {
 	fn next_back(&mut self) -> Option<Self::Item> {
 		if self.end > self.offset {
@@ -0,0 +1,8220 @@
+//! Registered primary language subtags.
+
+use super::LanguageFields;
+
+/// Registered primary language subtags with their `Preferred-Value` and
+/// `Suppress-Script` fields, sorted by subtag.
+pub(crate) static LANGUAGES: [(&str, LanguageFields); 8212] = [
+	("aa", (None, None)),
+	("aaa", (None, None)),
+	("aab", (None, None)),
+	("aac", (None, None)),
+	("aad", (None, None)),
+	("aae", (None, None)),
+	("aaf", (None, None)),
+	("aag", (None, None)),
+	("aah", (None, None)),
+	("aai", (None, None)),
+	("aak", (None, None)),
+	("aal", (None, None)),
+	("aam", (Some("aas"), None)),
+	("aan", (None, None)),
+	("aao", (None, None)),
+	("aap", (None, None)),
+	("aaq", (None, None)),
+	("aas", (None, None)),
+	("aat", (None, None)),
+	("aau", (None, None)),
+	("aav", (None, None)),
+	("aaw", (None, None)),
+	("aax", (None, None)),
+	("aaz", (None, None)),
+	("ab", (None, Some("Cyrl"))),
+	("aba", (None, None)),
+	("abb", (None, None)),
+	("abc", (None, None)),
+	("abd", (None, None)),
+	("abe", (None, None)),
+	("abf", (None, None)),
+	("abg", (None, None)),
+	("abh", (None, None)),
+	("abi", (None, None)),
+	("abj", (None, None)),
+	("abl", (None, None)),
+	("abm", (None, None)),
+	("abn", (None, None)),
+	("abo", (None, None)),
+	("abp", (None, None)),
+	("abq", (None, None)),
+	("abr", (None, None)),
+	("abs", (None, None)),
+	("abt", (None, None)),
+	("abu", (None, None)),
+	("abv", (None, None)),
+	("abw", (None, None)),
+	("abx", (None, None)),
+	("aby", (None, None)),
+	("abz", (None, None)),
+	("aca", (None, None)),
+	("acb", (None, None)),
+	("acd", (None, None)),
+	("ace", (None, None)),
+	("acf", (None, None)),
+	("ach", (None, None)),
+	("aci", (None, None)),
+	("ack", (None, None)),
+	("acl", (None, None)),
+	("acm", (None, None)),
+	("acn", (None, None)),
+	("acp", (None, None)),
+	("acq", (None, None)),
+	("acr", (None, None)),
+	("acs", (None, None)),
+	("act", (None, None)),
+	("acu", (None, None)),
+	("acv", (None, None)),
+	("acw", (None, None)),
+	("acx", (None, None)),
+	("acy", (None, None)),
+	("acz", (None, None)),
+	("ada", (None, None)),
+	("adb", (None, None)),
+	("add", (None, None)),
+	("ade", (None, None)),
+	("adf", (None, None)),
+	("adg", (None, None)),
+	("adh", (None, None)),
+	("adi", (None, None)),
+	("adj", (None, None)),
+	("adl", (None, None)),
+	("adn", (None, None)),
+	("ado", (None, None)),
+	("adp", (Some("dz"), None)),
+	("adq", (None, None)),
+	("adr", (None, None)),
+	("ads", (None, None)),
+	("adt", (None, None)),
+	("adu", (None, None)),
+	("adw", (None, None)),
+	("adx", (None, None)),
+	("ady", (None, None)),
+	("adz", (None, None)),
+	("ae", (None, None)),
+	("aea", (None, None)),
+	("aeb", (None, None)),
+	("aec", (None, None)),
+	("aed", (None, None)),
+	("aee", (None, None)),
+	("aek", (None, None)),
+	("ael", (None, None)),
+	("aem", (None, None)),
+	("aen", (None, None)),
+	("aeq", (None, None)),
+	("aer", (None, None)),
+	("aes", (None, None)),
+	("aeu", (None, None)),
+	("aew", (None, None)),
+	("aey", (None, None)),
+	("aez", (None, None)),
+	("af", (None, Some("Latn"))),
+	("afa", (None, None)),
+	("afb", (None, None)),
+	("afd", (None, None)),
+	("afe", (None, None)),
+	("afg", (None, None)),
+	("afh", (None, None)),
+	("afi", (None, None)),
+	("afk", (None, None)),
+	("afn", (None, None)),
+	("afo", (None, None)),
+	("afp", (None, None)),
+	("afs", (None, None)),
+	("aft", (None, None)),
+	("afu", (None, None)),
+	("afz", (None, None)),
+	("aga", (None, None)),
+	("agb", (None, None)),
+	("agc", (None, None)),
+	("agd", (None, None)),
+	("age", (None, None)),
+	("agf", (None, None)),
+	("agg", (None, None)),
+	("agh", (None, None)),
+	("agi", (None, None)),
+	("agj", (None, None)),
+	("agk", (None, None)),
+	("agl", (None, None)),
+	("agm", (None, None)),
+	("agn", (None, None)),
+	("ago", (None, None)),
+	("agp", (None, None)),
+	("agq", (None, None)),
+	("agr", (None, None)),
+	("ags", (None, None)),
+	("agt", (None, None)),
+	("agu", (None, None)),
+	("agv", (None, None)),
+	("agw", (None, None)),
+	("agx", (None, None)),
+	("agy", (None, None)),
+	("agz", (None, None)),
+	("aha", (None, None)),
+	("ahb", (None, None)),
+	("ahg", (None, None)),
+	("ahh", (None, None)),
+	("ahi", (None, None)),
+	("ahk", (None, None)),
+	("ahl", (None, None)),
+	("ahm", (None, None)),
+	("ahn", (None, None)),
+	("aho", (None, None)),
+	("ahp", (None, None)),
+	("ahr", (None, None)),
+	("ahs", (None, None)),
+	("aht", (None, None)),
+	("aia", (None, None)),
+	("aib", (None, None)),
+	("aic", (None, None)),
+	("aid", (None, None)),
+	("aie", (None, None)),
+	("aif", (None, None)),
+	("aig", (None, None)),
+	("aih", (None, None)),
+	("aii", (None, None)),
+	("aij", (None, None)),
+	("aik", (None, None)),
+	("ail", (None, None)),
+	("aim", (None, None)),
+	("ain", (None, None)),
+	("aio", (None, None)),
+	("aip", (None, None)),
+	("aiq", (None, None)),
+	("air", (None, None)),
+	("ais", (None, None)),
+	("ait", (None, None)),
+	("aiw", (None, None)),
+	("aix", (None, None)),
+	("aiy", (None, None)),
+	("aja", (None, None)),
+	("ajg", (None, None)),
+	("aji", (None, None)),
+	("ajn", (None, None)),
+	("ajp", (None, None)),
+	("ajt", (None, None)),
+	("aju", (None, None)),
+	("ajw", (None, None)),
+	("ajz", (None, None)),
+	("ak", (None, None)),
+	("akb", (None, None)),
+	("akc", (None, None)),
+	("akd", (None, None)),
+	("ake", (None, None)),
+	("akf", (None, None)),
+	("akg", (None, None)),
+	("akh", (None, None)),
+	("aki", (None, None)),
+	("akj", (None, None)),
+	("akk", (None, None)),
+	("akl", (None, None)),
+	("akm", (None, None)),
+	("ako", (None, None)),
+	("akp", (None, None)),
+	("akq", (None, None)),
+	("akr", (None, None)),
+	("aks", (None, None)),
+	("akt", (None, None)),
+	("aku", (None, None)),
+	("akv", (None, None)),
+	("akw", (None, None)),
+	("akx", (None, None)),
+	("aky", (None, None)),
+	("akz", (None, None)),
+	("ala", (None, None)),
+	("alc", (None, None)),
+	("ald", (None, None)),
+	("ale", (None, None)),
+	("alf", (None, None)),
+	("alg", (None, None)),
+	("alh", (None, None)),
+	("ali", (None, None)),
+	("alj", (None, None)),
+	("alk", (None, None)),
+	("all", (None, None)),
+	("alm", (None, None)),
+	("aln", (None, None)),
+	("alo", (None, None)),
+	("alp", (None, None)),
+	("alq", (None, None)),
+	("alr", (None, None)),
+	("als", (None, None)),
+	("alt", (None, None)),
+	("alu", (None, None)),
+	("alv", (None, None)),
+	("alw", (None, None)),
+	("alx", (None, None)),
+	("aly", (None, None)),
+	("alz", (None, None)),
+	("am", (None, Some("Ethi"))),
+	("ama", (None, None)),
+	("amb", (None, None)),
+	("amc", (None, None)),
+	("ame", (None, None)),
+	("amf", (None, None)),
+	("amg", (None, None)),
+	("ami", (None, None)),
+	("amj", (None, None)),
+	("amk", (None, None)),
+	("aml", (None, None)),
+	("amm", (None, None)),
+	("amn", (None, None)),
+	("amo", (None, None)),
+	("amp", (None, None)),
+	("amq", (None, None)),
+	("amr", (None, None)),
+	("ams", (None, None)),
+	("amt", (None, None)),
+	("amu", (None, None)),
+	("amv", (None, None)),
+	("amw", (None, None)),
+	("amx", (None, None)),
+	("amy", (None, None)),
+	("amz", (None, None)),
+	("an", (None, None)),
+	("ana", (None, None)),
+	("anb", (None, None)),
+	("anc", (None, None)),
+	("and", (None, None)),
+	("ane", (None, None)),
+	("anf", (None, None)),
+	("ang", (None, None)),
+	("anh", (None, None)),
+	("ani", (None, None)),
+	("anj", (None, None)),
+	("ank", (None, None)),
+	("anl", (None, None)),
+	("anm", (None, None)),
+	("ann", (None, None)),
+	("ano", (None, None)),
+	("anp", (None, None)),
+	("anq", (None, None)),
+	("anr", (None, None)),
+	("ans", (None, None)),
+	("ant", (None, None)),
+	("anu", (None, None)),
+	("anv", (None, None)),
+	("anw", (None, None)),
+	("anx", (None, None)),
+	("any", (None, None)),
+	("anz", (None, None)),
+	("aoa", (None, None)),
+	("aob", (None, None)),
+	("aoc", (None, None)),
+	("aod", (None, None)),
+	("aoe", (None, None)),
+	("aof", (None, None)),
+	("aog", (None, None)),
+	("aoh", (None, None)),
+	("aoi", (None, None)),
+	("aoj", (None, None)),
+	("aok", (None, None)),
+	("aol", (None, None)),
+	("aom", (None, None)),
+	("aon", (None, None)),
+	("aor", (None, None)),
+	("aos", (None, None)),
+	("aot", (None, None)),
+	("aou", (None, None)),
+	("aox", (None, None)),
+	("aoz", (None, None)),
+	("apa", (None, None)),
+	("apb", (None, None)),
+	("apc", (None, None)),
+	("apd", (None, None)),
+	("ape", (None, None)),
+	("apf", (None, None)),
+	("apg", (None, None)),
+	("aph", (None, None)),
+	("api", (None, None)),
+	("apj", (None, None)),
+	("apk", (None, None)),
+	("apl", (None, None)),
+	("apm", (None, None)),
+	("apn", (None, None)),
+	("apo", (None, None)),
+	("app", (None, None)),
+	("apq", (None, None)),
+	("apr", (None, None)),
+	("aps", (None, None)),
+	("apt", (None, None)),
+	("apu", (None, None)),
+	("apv", (None, None)),
+	("apw", (None, None)),
+	("apx", (None, None)),
+	("apy", (None, None)),
+	("apz", (None, None)),
+	("aqa", (None, None)),
+	("aqc", (None, None)),
+	("aqd", (None, None)),
+	("aqg", (None, None)),
+	("aqk", (None, None)),
+	("aql", (None, None)),
+	("aqm", (None, None)),
+	("aqn", (None, None)),
+	("aqp", (None, None)),
+	("aqr", (None, None)),
+	("aqt", (None, None)),
+	("aqz", (None, None)),
+	("ar", (None, Some("Arab"))),
+	("arb", (None, None)),
+	("arc", (None, None)),
+	("ard", (None, None)),
+	("are", (None, None)),
+	("arh", (None, None)),
+	("ari", (None, None)),
+	("arj", (None, None)),
+	("ark", (None, None)),
+	("arl", (None, None)),
+	("arn", (None, None)),
+	("aro", (None, None)),
+	("arp", (None, None)),
+	("arq", (None, None)),
+	("arr", (None, None)),
+	("ars", (None, None)),
+	("art", (None, None)),
+	("aru", (None, None)),
+	("arv", (None, None)),
+	("arw", (None, None)),
+	("arx", (None, None)),
+	("ary", (None, None)),
+	("arz", (None, None)),
+	("as", (None, Some("Beng"))),
+	("asa", (None, None)),
+	("asb", (None, None)),
+	("asc", (None, None)),
+	("asd", (Some("snz"), None)),
+	("ase", (None, None)),
+	("asf", (None, None)),
+	("asg", (None, None)),
+	("ash", (None, None)),
+	("asi", (None, None)),
+	("asj", (None, None)),
+	("ask", (None, None)),
+	("asl", (None, None)),
+	("asn", (None, None)),
+	("aso", (None, None)),
+	("asp", (None, None)),
+	("asq", (None, None)),
+	("asr", (None, None)),
+	("ass", (None, None)),
+	("ast", (None, None)),
+	("asu", (None, None)),
+	("asv", (None, None)),
+	("asw", (None, None)),
+	("asx", (None, None)),
+	("asy", (None, None)),
+	("asz", (None, None)),
+	("ata", (None, None)),
+	("atb", (None, None)),
+	("atc", (None, None)),
+	("atd", (None, None)),
+	("ate", (None, None)),
+	("atg", (None, None)),
+	("ath", (None, None)),
+	("ati", (None, None)),
+	("atj", (None, None)),
+	("atk", (None, None)),
+	("atl", (None, None)),
+	("atm", (None, None)),
+	("atn", (None, None)),
+	("ato", (None, None)),
+	("atp", (None, None)),
+	("atq", (None, None)),
+	("atr", (None, None)),
+	("ats", (None, None)),
+	("att", (None, None)),
+	("atu", (None, None)),
+	("atv", (None, None)),
+	("atw", (None, None)),
+	("atx", (None, None)),
+	("aty", (None, None)),
+	("atz", (None, None)),
+	("aua", (None, None)),
+	("aub", (None, None)),
+	("auc", (None, None)),
+	("aud", (None, None)),
+	("aue", (Some("ktz"), None)),
+	("auf", (None, None)),
+	("aug", (None, None)),
+	("auh", (None, None)),
+	("aui", (None, None)),
+	("auj", (None, None)),
+	("auk", (None, None)),
+	("aul", (None, None)),
+	("aum", (None, None)),
+	("aun", (None, None)),
+	("auo", (None, None)),
+	("aup", (None, None)),
+	("auq", (None, None)),
+	("aur", (None, None)),
+	("aus", (None, None)),
+	("aut", (None, None)),
+	("auu", (None, None)),
+	("auw", (None, None)),
+	("aux", (None, None)),
+	("auy", (None, None)),
+	("auz", (None, None)),
+	("av", (None, None)),
+	("avb", (None, None)),
+	("avd", (None, None)),
+	("avi", (None, None)),
+	("avk", (None, None)),
+	("avl", (None, None)),
+	("avm", (None, None)),
+	("avn", (None, None)),
+	("avo", (None, None)),
+	("avs", (None, None)),
+	("avt", (None, None)),
+	("avu", (None, None)),
+	("avv", (None, None)),
+	("awa", (None, None)),
+	("awb", (None, None)),
+	("awc", (None, None)),
+	("awd", (None, None)),
+	("awe", (None, None)),
+	("awg", (None, None)),
+	("awh", (None, None)),
+	("awi", (None, None)),
+	("awk", (None, None)),
+	("awm", (None, None)),
+	("awn", (None, None)),
+	("awo", (None, None)),
+	("awr", (None, None)),
+	("aws", (None, None)),
+	("awt", (None, None)),
+	("awu", (None, None)),
+	("awv", (None, None)),
+	("aww", (None, None)),
+	("awx", (None, None)),
+	("awy", (None, None)),
+	("axb", (None, None)),
+	("axe", (None, None)),
+	("axg", (None, None)),
+	("axk", (None, None)),
+	("axl", (None, None)),
+	("axm", (None, None)),
+	("axx", (None, None)),
+	("ay", (None, Some("Latn"))),
+	("aya", (None, None)),
+	("ayb", (None, None)),
+	("ayc", (None, None)),
+	("ayd", (None, None)),
+	("aye", (None, None)),
+	("ayg", (None, None)),
+	("ayh", (None, None)),
+	("ayi", (None, None)),
+	("ayk", (None, None)),
+	("ayl", (None, None)),
+	("ayn", (None, None)),
+	("ayo", (None, None)),
+	("ayp", (None, None)),
+	("ayq", (None, None)),
+	("ayr", (None, None)),
+	("ays", (None, None)),
+	("ayt", (None, None)),
+	("ayu", (None, None)),
+	("ayx", (Some("nun"), None)),
+	("ayy", (None, None)),
+	("ayz", (None, None)),
+	("az", (None, None)),
+	("aza", (None, None)),
+	("azb", (None, None)),
+	("azc", (None, None)),
+	("azd", (None, None)),
+	("azg", (None, None)),
+	("azj", (None, None)),
+	("azm", (None, None)),
+	("azn", (None, None)),
+	("azo", (None, None)),
+	("azt", (None, None)),
+	("azz", (None, None)),
+	("ba", (None, None)),
+	("baa", (None, None)),
+	("bab", (None, None)),
+	("bac", (None, None)),
+	("bad", (None, None)),
+	("bae", (None, None)),
+	("baf", (None, None)),
+	("bag", (None, None)),
+	("bah", (None, None)),
+	("bai", (None, None)),
+	("baj", (None, None)),
+	("bal", (None, None)),
+	("ban", (None, None)),
+	("bao", (None, None)),
+	("bap", (None, None)),
+	("bar", (None, None)),
+	("bas", (None, None)),
+	("bat", (None, None)),
+	("bau", (None, None)),
+	("bav", (None, None)),
+	("baw", (None, None)),
+	("bax", (None, None)),
+	("bay", (None, None)),
+	("baz", (None, None)),
+	("bba", (None, None)),
+	("bbb", (None, None)),
+	("bbc", (None, None)),
+	("bbd", (None, None)),
+	("bbe", (None, None)),
+	("bbf", (None, None)),
+	("bbg", (None, None)),
+	("bbh", (None, None)),
+	("bbi", (None, None)),
+	("bbj", (None, None)),
+	("bbk", (None, None)),
+	("bbl", (None, None)),
+	("bbm", (None, None)),
+	("bbn", (None, None)),
+	("bbo", (None, None)),
+	("bbp", (None, None)),
+	("bbq", (None, None)),
+	("bbr", (None, None)),
+	("bbs", (None, None)),
+	("bbt", (None, None)),
+	("bbu", (None, None)),
+	("bbv", (None, None)),
+	("bbw", (None, None)),
+	("bbx", (None, None)),
+	("bby", (None, None)),
+	("bbz", (None, None)),
+	("bca", (None, None)),
+	("bcb", (None, None)),
+	("bcc", (None, None)),
+	("bcd", (None, None)),
+	("bce", (None, None)),
+	("bcf", (None, None)),
+	("bcg", (None, None)),
+	("bch", (None, None)),
+	("bci", (None, None)),
+	("bcj", (None, None)),
+	("bck", (None, None)),
+	("bcl", (None, None)),
+	("bcm", (None, None)),
+	("bcn", (None, None)),
+	("bco", (None, None)),
+	("bcp", (None, None)),
+	("bcq", (None, None)),
+	("bcr", (None, None)),
+	("bcs", (None, None)),
+	("bct", (None, None)),
+	("bcu", (None, None)),
+	("bcv", (None, None)),
+	("bcw", (None, None)),
+	("bcy", (None, None)),
+	("bcz", (None, None)),
+	("bda", (None, None)),
+	("bdb", (None, None)),
+	("bdc", (None, None)),
+	("bdd", (None, None)),
+	("bde", (None, None)),
+	("bdf", (None, None)),
+	("bdg", (None, None)),
+	("bdh", (None, None)),
+	("bdi", (None, None)),
+	("bdj", (None, None)),
+	("bdk", (None, None)),
+	("bdl", (None, None)),
+	("bdm", (None, None)),
+	("bdn", (None, None)),
+	("bdo", (None, None)),
+	("bdp", (None, None)),
+	("bdq", (None, None)),
+	("bdr", (None, None)),
+	("bds", (None, None)),
+	("bdt", (None, None)),
+	("bdu", (None, None)),
+	("bdv", (None, None)),
+	("bdw", (None, None)),
+	("bdx", (None, None)),
+	("bdy", (None, None)),
+	("bdz", (None, None)),
+	("be", (None, Some("Cyrl"))),
+	("bea", (None, None)),
+	("beb", (None, None)),
+	("bec", (None, None)),
+	("bed", (None, None)),
+	("bee", (None, None)),
+	("bef", (None, None)),
+	("beg", (None, None)),
+	("beh", (None, None)),
+	("bei", (None, None)),
+	("bej", (None, None)),
+	("bek", (None, None)),
+	("bem", (None, None)),
+	("beo", (None, None)),
+	("bep", (None, None)),
+	("beq", (None, None)),
+	("ber", (None, None)),
+	("bes", (None, None)),
+	("bet", (None, None)),
+	("beu", (None, None)),
+	("bev", (None, None)),
+	("bew", (None, None)),
+	("bex", (None, None)),
+	("bey", (None, None)),
+	("bez", (None, None)),
+	("bfa", (None, None)),
+	("bfb", (None, None)),
+	("bfc", (None, None)),
+	("bfd", (None, None)),
+	("bfe", (None, None)),
+	("bff", (None, None)),
+	("bfg", (None, None)),
+	("bfh", (None, None)),
+	("bfi", (None, None)),
+	("bfj", (None, None)),
+	("bfk", (None, None)),
+	("bfl", (None, None)),
+	("bfm", (None, None)),
+	("bfn", (None, None)),
+	("bfo", (None, None)),
+	("bfp", (None, None)),
+	("bfq", (None, None)),
+	("bfr", (None, None)),
+	("bfs", (None, None)),
+	("bft", (None, None)),
+	("bfu", (None, None)),
+	("bfw", (None, None)),
+	("bfx", (None, None)),
+	("bfy", (None, None)),
+	("bfz", (None, None)),
+	("bg", (None, Some("Cyrl"))),
+	("bga", (None, None)),
+	("bgb", (None, None)),
+	("bgc", (None, None)),
+	("bgd", (None, None)),
+	("bge", (None, None)),
+	("bgf", (None, None)),
+	("bgg", (None, None)),
+	("bgi", (None, None)),
+	("bgj", (None, None)),
+	("bgk", (None, None)),
+	("bgl", (None, None)),
+	("bgm", (Some("bcg"), None)),
+	("bgn", (None, None)),
+	("bgo", (None, None)),
+	("bgp", (None, None)),
+	("bgq", (None, None)),
+	("bgr", (None, None)),
+	("bgs", (None, None)),
+	("bgt", (None, None)),
+	("bgu", (None, None)),
+	("bgv", (None, None)),
+	("bgw", (None, None)),
+	("bgx", (None, None)),
+	("bgy", (None, None)),
+	("bgz", (None, None)),
+	("bh", (None, None)),
+	("bha", (None, None)),
+	("bhb", (None, None)),
+	("bhc", (None, None)),
+	("bhd", (None, None)),
+	("bhe", (None, None)),
+	("bhf", (None, None)),
+	("bhg", (None, None)),
+	("bhh", (None, None)),
+	("bhi", (None, None)),
+	("bhj", (None, None)),
+	("bhk", (None, None)),
+	("bhl", (None, None)),
+	("bhm", (None, None)),
+	("bhn", (None, None)),
+	("bho", (None, None)),
+	("bhp", (None, None)),
+	("bhq", (None, None)),
+	("bhr", (None, None)),
+	("bhs", (None, None)),
+	("bht", (None, None)),
+	("bhu", (None, None)),
+	("bhv", (None, None)),
+	("bhw", (None, None)),
+	("bhx", (None, None)),
+	("bhy", (None, None)),
+	("bhz", (None, None)),
+	("bi", (None, None)),
+	("bia", (None, None)),
+	("bib", (None, None)),
+	("bic", (Some("bir"), None)),
+	("bid", (None, None)),
+	("bie", (None, None)),
+	("bif", (None, None)),
+	("big", (None, None)),
+	("bij", (None, None)),
+	("bik", (None, None)),
+	("bil", (None, None)),
+	("bim", (None, None)),
+	("bin", (None, None)),
+	("bio", (None, None)),
+	("bip", (None, None)),
+	("biq", (None, None)),
+	("bir", (None, None)),
+	("bit", (None, None)),
+	("biu", (None, None)),
+	("biv", (None, None)),
+	("biw", (None, None)),
+	("bix", (None, None)),
+	("biy", (None, None)),
+	("biz", (None, None)),
+	("bja", (None, None)),
+	("bjb", (None, None)),
+	("bjc", (None, None)),
+	("bjd", (Some("drl"), None)),
+	("bje", (None, None)),
+	("bjf", (None, None)),
+	("bjg", (None, None)),
+	("bjh", (None, None)),
+	("bji", (None, None)),
+	("bjj", (None, None)),
+	("bjk", (None, None)),
+	("bjl", (None, None)),
+	("bjm", (None, None)),
+	("bjn", (None, None)),
+	("bjo", (None, None)),
+	("bjp", (None, None)),
+	("bjq", (None, None)),
+	("bjr", (None, None)),
+	("bjs", (None, None)),
+	("bjt", (None, None)),
+	("bju", (None, None)),
+	("bjv", (None, None)),
+	("bjw", (None, None)),
+	("bjx", (None, None)),
+	("bjy", (None, None)),
+	("bjz", (None, None)),
+	("bka", (None, None)),
+	("bkb", (None, None)),
+	("bkc", (None, None)),
+	("bkd", (None, None)),
+	("bkf", (None, None)),
+	("bkg", (None, None)),
+	("bkh", (None, None)),
+	("bki", (None, None)),
+	("bkj", (None, None)),
+	("bkk", (None, None)),
+	("bkl", (None, None)),
+	("bkm", (None, None)),
+	("bkn", (None, None)),
+	("bko", (None, None)),
+	("bkp", (None, None)),
+	("bkq", (None, None)),
+	("bkr", (None, None)),
+	("bks", (None, None)),
+	("bkt", (None, None)),
+	("bku", (None, None)),
+	("bkv", (None, None)),
+	("bkw", (None, None)),
+	("bkx", (None, None)),
+	("bky", (None, None)),
+	("bkz", (None, None)),
+	("bla", (None, None)),
+	("blb", (None, None)),
+	("blc", (None, None)),
+	("bld", (None, None)),
+	("ble", (None, None)),
+	("blf", (None, None)),
+	("blg", (Some("iba"), None)),
+	("blh", (None, None)),
+	("bli", (None, None)),
+	("blj", (None, None)),
+	("blk", (None, None)),
+	("bll", (None, None)),
+	("blm", (None, None)),
+	("bln", (None, None)),
+	("blo", (None, None)),
+	("blp", (None, None)),
+	("blq", (None, None)),
+	("blr", (None, None)),
+	("bls", (None, None)),
+	("blt", (None, None)),
+	("blv", (None, None)),
+	("blw", (None, None)),
+	("blx", (None, None)),
+	("bly", (None, None)),
+	("blz", (None, None)),
+	("bm", (None, None)),
+	("bma", (None, None)),
+	("bmb", (None, None)),
+	("bmc", (None, None)),
+	("bmd", (None, None)),
+	("bme", (None, None)),
+	("bmf", (None, None)),
+	("bmg", (None, None)),
+	("bmh", (None, None)),
+	("bmi", (None, None)),
+	("bmj", (None, None)),
+	("bmk", (None, None)),
+	("bml", (None, None)),
+	("bmm", (None, None)),
+	("bmn", (None, None)),
+	("bmo", (None, None)),
+	("bmp", (None, None)),
+	("bmq", (None, None)),
+	("bmr", (None, None)),
+	("bms", (None, None)),
+	("bmt", (None, None)),
+	("bmu", (None, None)),
+	("bmv", (None, None)),
+	("bmw", (None, None)),
+	("bmx", (None, None)),
+	("bmy", (None, None)),
+	("bmz", (None, None)),
+	("bn", (None, Some("Beng"))),
+	("bna", (None, None)),
+	("bnb", (None, None)),
+	("bnc", (None, None)),
+	("bnd", (None, None)),
+	("bne", (None, None)),
+	("bnf", (None, None)),
+	("bng", (None, None)),
+	("bni", (None, None)),
+	("bnj", (None, None)),
+	("bnk", (None, None)),
+	("bnl", (None, None)),
+	("bnm", (None, None)),
+	("bnn", (None, None)),
+	("bno", (None, None)),
+	("bnp", (None, None)),
+	("bnq", (None, None)),
+	("bnr", (None, None)),
+	("bns", (None, None)),
+	("bnt", (None, None)),
+	("bnu", (None, None)),
+	("bnv", (None, None)),
+	("bnw", (None, None)),
+	("bnx", (None, None)),
+	("bny", (None, None)),
+	("bnz", (None, None)),
+	("bo", (None, None)),
+	("boa", (None, None)),
+	("bob", (None, None)),
+	("boe", (None, None)),
+	("bof", (None, None)),
+	("bog", (None, None)),
+	("boh", (None, None)),
+	("boi", (None, None)),
+	("boj", (None, None)),
+	("bok", (None, None)),
+	("bol", (None, None)),
+	("bom", (None, None)),
+	("bon", (None, None)),
+	("boo", (None, None)),
+	("bop", (None, None)),
+	("boq", (None, None)),
+	("bor", (None, None)),
+	("bot", (None, None)),
+	("bou", (None, None)),
+	("bov", (None, None)),
+	("bow", (None, None)),
+	("box", (None, None)),
+	("boy", (None, None)),
+	("boz", (None, None)),
+	("bpa", (None, None)),
+	("bpb", (None, None)),
+	("bpd", (None, None)),
+	("bpe", (None, None)),
+	("bpg", (None, None)),
+	("bph", (None, None)),
+	("bpi", (None, None)),
+	("bpj", (None, None)),
+	("bpk", (None, None)),
+	("bpl", (None, None)),
+	("bpm", (None, None)),
+	("bpn", (None, None)),
+	("bpo", (None, None)),
+	("bpp", (None, None)),
+	("bpq", (None, None)),
+	("bpr", (None, None)),
+	("bps", (None, None)),
+	("bpt", (None, None)),
+	("bpu", (None, None)),
+	("bpv", (None, None)),
+	("bpw", (None, None)),
+	("bpx", (None, None)),
+	("bpy", (None, None)),
+	("bpz", (None, None)),
+	("bqa", (None, None)),
+	("bqb", (None, None)),
+	("bqc", (None, None)),
+	("bqd", (None, None)),
+	("bqf", (None, None)),
+	("bqg", (None, None)),
+	("bqh", (None, None)),
+	("bqi", (None, None)),
+	("bqj", (None, None)),
+	("bqk", (None, None)),
+	("bql", (None, None)),
+	("bqm", (None, None)),
+	("bqn", (None, None)),
+	("bqo", (None, None)),
+	("bqp", (None, None)),
+	("bqq", (None, None)),
+	("bqr", (None, None)),
+	("bqs", (None, None)),
+	("bqt", (None, None)),
+	("bqu", (None, None)),
+	("bqv", (None, None)),
+	("bqw", (None, None)),
+	("bqx", (None, None)),
+	("bqy", (None, None)),
+	("bqz", (None, None)),
+	("br", (None, None)),
+	("bra", (None, None)),
+	("brb", (None, None)),
+	("brc", (None, None)),
+	("brd", (None, None)),
+	("brf", (None, None)),
+	("brg", (None, None)),
+	("brh", (None, None)),
+	("bri", (None, None)),
+	("brj", (None, None)),
+	("brk", (None, None)),
+	("brl", (None, None)),
+	("brm", (None, None)),
+	("brn", (None, None)),
+	("bro", (None, None)),
+	("brp", (None, None)),
+	("brq", (None, None)),
+	("brr", (None, None)),
+	("brs", (None, None)),
+	("brt", (None, None)),
+	("bru", (None, None)),
+	("brv", (None, None)),
+	("brw", (None, None)),
+	("brx", (None, None)),
+	("bry", (None, None)),
+	("brz", (None, None)),
+	("bs", (None, Some("Latn"))),
+	("bsa", (None, None)),
+	("bsb", (None, None)),
+	("bsc", (None, None)),
+	("bse", (None, None)),
+	("bsf", (None, None)),
+	("bsg", (None, None)),
+	("bsh", (None, None)),
+	("bsi", (None, None)),
+	("bsj", (None, None)),
+	("bsk", (None, None)),
+	("bsl", (None, None)),
+	("bsm", (None, None)),
+	("bsn", (None, None)),
+	("bso", (None, None)),
+	("bsp", (None, None)),
+	("bsq", (None, None)),
+	("bsr", (None, None)),
+	("bss", (None, None)),
+	("bst", (None, None)),
+	("bsu", (None, None)),
+	("bsv", (None, None)),
+	("bsw", (None, None)),
+	("bsx", (None, None)),
+	("bsy", (None, None)),
+	("bta", (None, None)),
+	("btb", (None, None)),
+	("btc", (None, None)),
+	("btd", (None, None)),
+	("bte", (None, None)),
+	("btf", (None, None)),
+	("btg", (None, None)),
+	("bth", (None, None)),
+	("bti", (None, None)),
+	("btj", (None, None)),
+	("btk", (None, None)),
+	("btl", (None, None)),
+	("btm", (None, None)),
+	("btn", (None, None)),
+	("bto", (None, None)),
+	("btp", (None, None)),
+	("btq", (None, None)),
+	("btr", (None, None)),
+	("bts", (None, None)),
+	("btt", (None, None)),
+	("btu", (None, None)),
+	("btv", (None, None)),
+	("btw", (None, None)),
+	("btx", (None, None)),
+	("bty", (None, None)),
+	("btz", (None, None)),
+	("bua", (None, None)),
+	("bub", (None, None)),
+	("buc", (None, None)),
+	("bud", (None, None)),
+	("bue", (None, None)),
+	("buf", (None, None)),
+	("bug", (None, None)),
+	("buh", (None, None)),
+	("bui", (None, None)),
+	("buj", (None, None)),
+	("buk", (None, None)),
+	("bum", (None, None)),
+	("bun", (None, None)),
+	("buo", (None, None)),
+	("bup", (None, None)),
+	("buq", (None, None)),
+	("bus", (None, None)),
+	("but", (None, None)),
+	("buu", (None, None)),
+	("buv", (None, None)),
+	("buw", (None, None)),
+	("bux", (None, None)),
+	("buy", (None, None)),
+	("buz", (None, None)),
+	("bva", (None, None)),
+	("bvb", (None, None)),
+	("bvc", (None, None)),
+	("bvd", (None, None)),
+	("bve", (None, None)),
+	("bvf", (None, None)),
+	("bvg", (None, None)),
+	("bvh", (None, None)),
+	("bvi", (None, None)),
+	("bvj", (None, None)),
+	("bvk", (None, None)),
+	("bvl", (None, None)),
+	("bvm", (None, None)),
+	("bvn", (None, None)),
+	("bvo", (None, None)),
+	("bvp", (None, None)),
+	("bvq", (None, None)),
+	("bvr", (None, None)),
+	("bvt", (None, None)),
+	("bvu", (None, None)),
+	("bvv", (None, None)),
+	("bvw", (None, None)),
+	("bvx", (None, None)),
+	("bvy", (None, None)),
+	("bvz", (None, None)),
+	("bwa", (None, None)),
+	("bwb", (None, None)),
+	("bwc", (None, None)),
+	("bwd", (None, None)),
+	("bwe", (None, None)),
+	("bwf", (None, None)),
+	("bwg", (None, None)),
+	("bwh", (None, None)),
+	("bwi", (None, None)),
+	("bwj", (None, None)),
+	("bwk", (None, None)),
+	("bwl", (None, None)),
+	("bwm", (None, None)),
+	("bwn", (None, None)),
+	("bwo", (None, None)),
+	("bwp", (None, None)),
+	("bwq", (None, None)),
+	("bwr", (None, None)),
+	("bws", (None, None)),
+	("bwt", (None, None)),
+	("bwu", (None, None)),
+	("bww", (None, None)),
+	("bwx", (None, None)),
+	("bwy", (None, None)),
+	("bwz", (None, None)),
+	("bxa", (None, None)),
+	("bxb", (None, None)),
+	("bxc", (None, None)),
+	("bxd", (None, None)),
+	("bxe", (None, None)),
+	("bxf", (None, None)),
+	("bxg", (None, None)),
+	("bxh", (None, None)),
+	("bxi", (None, None)),
+	("bxj", (None, None)),
+	("bxk", (None, None)),
+	("bxl", (None, None)),
+	("bxm", (None, None)),
+	("bxn", (None, None)),
+	("bxo", (None, None)),
+	("bxp", (None, None)),
+	("bxq", (None, None)),
+	("bxr", (None, None)),
+	("bxs", (None, None)),
+	("bxu", (None, None)),
+	("bxv", (None, None)),
+	("bxw", (None, None)),
+	("bxx", (None, None)),
+	("bxz", (None, None)),
+	("bya", (None, None)),
+	("byb", (None, None)),
+	("byc", (None, None)),
+	("byd", (None, None)),
+	("bye", (None, None)),
+	("byf", (None, None)),
+	("byg", (None, None)),
+	("byh", (None, None)),
+	("byi", (None, None)),
+	("byj", (None, None)),
+	("byk", (None, None)),
+	("byl", (None, None)),
+	("bym", (None, None)),
+	("byn", (None, None)),
+	("byo", (None, None)),
+	("byp", (None, None)),
+	("byq", (None, None)),
+	("byr", (None, None)),
+	("bys", (None, None)),
+	("byt", (None, None)),
+	("byv", (None, None)),
+	("byw", (None, None)),
+	("byx", (None, None)),
+	("byy", (None, None)),
+	("byz", (None, None)),
+	("bza", (None, None)),
+	("bzb", (None, None)),
+	("bzc", (None, None)),
+	("bzd", (None, None)),
+	("bze", (None, None)),
+	("bzf", (None, None)),
+	("bzg", (None, None)),
+	("bzh", (None, None)),
+	("bzi", (None, None)),
+	("bzj", (None, None)),
+	("bzk", (None, None)),
+	("bzl", (None, None)),
+	("bzm", (None, None)),
+	("bzn", (None, None)),
+	("bzo", (None, None)),
+	("bzp", (None, None)),
+	("bzq", (None, None)),
+	("bzr", (None, None)),
+	("bzs", (None, None)),
+	("bzt", (None, None)),
+	("bzu", (None, None)),
+	("bzv", (None, None)),
+	("bzw", (None, None)),
+	("bzx", (None, None)),
+	("bzy", (None, None)),
+	("bzz", (None, None)),
+	("ca", (None, Some("Latn"))),
+	("caa", (None, None)),
+	("cab", (None, None)),
+	("cac", (None, None)),
+	("cad", (None, None)),
+	("cae", (None, None)),
+	("caf", (None, None)),
+	("cag", (None, None)),
+	("cah", (None, None)),
+	("cai", (None, None)),
+	("caj", (None, None)),
+	("cak", (None, None)),
+	("cal", (None, None)),
+	("cam", (None, None)),
+	("can", (None, None)),
+	("cao", (None, None)),
+	("cap", (None, None)),
+	("caq", (None, None)),
+	("car", (None, None)),
+	("cas", (None, None)),
+	("cau", (None, None)),
+	("cav", (None, None)),
+	("caw", (None, None)),
+	("cax", (None, None)),
+	("cay", (None, None)),
+	("caz", (None, None)),
+	("cba", (None, None)),
+	("cbb", (None, None)),
+	("cbc", (None, None)),
+	("cbd", (None, None)),
+	("cbe", (None, None)),
+	("cbg", (None, None)),
+	("cbh", (None, None)),
+	("cbi", (None, None)),
+	("cbj", (None, None)),
+	("cbk", (None, None)),
+	("cbl", (None, None)),
+	("cbn", (None, None)),
+	("cbo", (None, None)),
+	("cbq", (None, None)),
+	("cbr", (None, None)),
+	("cbs", (None, None)),
+	("cbt", (None, None)),
+	("cbu", (None, None)),
+	("cbv", (None, None)),
+	("cbw", (None, None)),
+	("cby", (None, None)),
+	("cca", (None, None)),
+	("ccc", (None, None)),
+	("ccd", (None, None)),
+	("cce", (None, None)),
+	("ccg", (None, None)),
+	("cch", (None, None)),
+	("ccj", (None, None)),
+	("ccl", (None, None)),
+	("ccm", (None, None)),
+	("ccn", (None, None)),
+	("cco", (None, None)),
+	("ccp", (None, None)),
+	("ccq", (Some("rki"), None)),
+	("ccr", (None, None)),
+	("ccs", (None, None)),
+	("cda", (None, None)),
+	("cdc", (None, None)),
+	("cdd", (None, None)),
+	("cde", (None, None)),
+	("cdf", (None, None)),
+	("cdg", (None, None)),
+	("cdh", (None, None)),
+	("cdi", (None, None)),
+	("cdj", (None, None)),
+	("cdm", (None, None)),
+	("cdn", (None, None)),
+	("cdo", (None, None)),
+	("cdr", (None, None)),
+	("cds", (None, None)),
+	("cdy", (None, None)),
+	("cdz", (None, None)),
+	("ce", (None, None)),
+	("cea", (None, None)),
+	("ceb", (None, None)),
+	("ceg", (None, None)),
+	("cek", (None, None)),
+	("cel", (None, None)),
+	("cen", (None, None)),
+	("cet", (None, None)),
+	("cey", (None, None)),
+	("cfa", (None, None)),
+	("cfd", (None, None)),
+	("cfg", (None, None)),
+	("cfm", (None, None)),
+	("cga", (None, None)),
+	("cgc", (None, None)),
+	("cgg", (None, None)),
+	("cgk", (None, None)),
+	("ch", (None, Some("Latn"))),
+	("chb", (None, None)),
+	("chc", (None, None)),
+	("chd", (None, None)),
+	("chf", (None, None)),
+	("chg", (None, None)),
+	("chh", (None, None)),
+	("chj", (None, None)),
+	("chk", (None, None)),
+	("chl", (None, None)),
+	("chm", (None, None)),
+	("chn", (None, None)),
+	("cho", (None, None)),
+	("chp", (None, None)),
+	("chq", (None, None)),
+	("chr", (None, None)),
+	("cht", (None, None)),
+	("chw", (None, None)),
+	("chx", (None, None)),
+	("chy", (None, None)),
+	("chz", (None, None)),
+	("cia", (None, None)),
+	("cib", (None, None)),
+	("cic", (None, None)),
+	("cid", (None, None)),
+	("cie", (None, None)),
+	("cih", (None, None)),
+	("cik", (None, None)),
+	("cim", (None, None)),
+	("cin", (None, None)),
+	("cip", (None, None)),
+	("cir", (None, None)),
+	("ciw", (None, None)),
+	("ciy", (None, None)),
+	("cja", (None, None)),
+	("cje", (None, None)),
+	("cjh", (None, None)),
+	("cji", (None, None)),
+	("cjk", (None, None)),
+	("cjm", (None, None)),
+	("cjn", (None, None)),
+	("cjo", (None, None)),
+	("cjp", (None, None)),
+	("cjr", (Some("mom"), None)),
+	("cjs", (None, None)),
+	("cjv", (None, None)),
+	("cjy", (None, None)),
+	("cka", (Some("cmr"), None)),
+	("ckb", (None, None)),
+	("ckh", (None, None)),
+	("ckl", (None, None)),
+	("ckm", (None, None)),
+	("ckn", (None, None)),
+	("cko", (None, None)),
+	("ckq", (None, None)),
+	("ckr", (None, None)),
+	("cks", (None, None)),
+	("ckt", (None, None)),
+	("cku", (None, None)),
+	("ckv", (None, None)),
+	("ckx", (None, None)),
+	("cky", (None, None)),
+	("ckz", (None, None)),
+	("cla", (None, None)),
+	("clc", (None, None)),
+	("cld", (None, None)),
+	("cle", (None, None)),
+	("clh", (None, None)),
+	("cli", (None, None)),
+	("clj", (None, None)),
+	("clk", (None, None)),
+	("cll", (None, None)),
+	("clm", (None, None)),
+	("clo", (None, None)),
+	("clt", (None, None)),
+	("clu", (None, None)),
+	("clw", (None, None)),
+	("cly", (None, None)),
+	("cma", (None, None)),
+	("cmc", (None, None)),
+	("cme", (None, None)),
+	("cmg", (None, None)),
+	("cmi", (None, None)),
+	("cmk", (Some("xch"), None)),
+	("cml", (None, None)),
+	("cmm", (None, None)),
+	("cmn", (None, None)),
+	("cmo", (None, None)),
+	("cmr", (None, None)),
+	("cms", (None, None)),
+	("cmt", (None, None)),
+	("cna", (None, None)),
+	("cnb", (None, None)),
+	("cnc", (None, None)),
+	("cng", (None, None)),
+	("cnh", (None, None)),
+	("cni", (None, None)),
+	("cnk", (None, None)),
+	("cnl", (None, None)),
+	("cno", (None, None)),
+	("cnp", (None, None)),
+	("cnr", (None, None)),
+	("cns", (None, None)),
+	("cnt", (None, None)),
+	("cnu", (None, None)),
+	("cnw", (None, None)),
+	("cnx", (None, None)),
+	("co", (None, None)),
+	("coa", (None, None)),
+	("cob", (None, None)),
+	("coc", (None, None)),
+	("cod", (None, None)),
+	("coe", (None, None)),
+	("cof", (None, None)),
+	("cog", (None, None)),
+	("coh", (None, None)),
+	("coj", (None, None)),
+	("cok", (None, None)),
+	("col", (None, None)),
+	("com", (None, None)),
+	("con", (None, None)),
+	("coo", (None, None)),
+	("cop", (None, None)),
+	("coq", (None, None)),
+	("cot", (None, None)),
+	("cou", (None, None)),
+	("cov", (None, None)),
+	("cow", (None, None)),
+	("cox", (None, None)),
+	("coy", (Some("pij"), None)),
+	("coz", (None, None)),
+	("cpa", (None, None)),
+	("cpb", (None, None)),
+	("cpc", (None, None)),
+	("cpe", (None, None)),
+	("cpf", (None, None)),
+	("cpg", (None, None)),
+	("cpi", (None, None)),
+	("cpn", (None, None)),
+	("cpo", (None, None)),
+	("cpp", (None, None)),
+	("cps", (None, None)),
+	("cpu", (None, None)),
+	("cpx", (None, None)),
+	("cpy", (None, None)),
+	("cqd", (None, None)),
+	("cqu", (Some("quh"), None)),
+	("cr", (None, None)),
+	("cra", (None, None)),
+	("crb", (None, None)),
+	("crc", (None, None)),
+	("crd", (None, None)),
+	("crf", (None, None)),
+	("crg", (None, None)),
+	("crh", (None, None)),
+	("cri", (None, None)),
+	("crj", (None, None)),
+	("crk", (None, None)),
+	("crl", (None, None)),
+	("crm", (None, None)),
+	("crn", (None, None)),
+	("cro", (None, None)),
+	("crp", (None, None)),
+	("crq", (None, None)),
+	("crr", (None, None)),
+	("crs", (None, None)),
+	("crt", (None, None)),
+	("crv", (None, None)),
+	("crw", (None, None)),
+	("crx", (None, None)),
+	("cry", (None, None)),
+	("crz", (None, None)),
+	("cs", (None, Some("Latn"))),
+	("csa", (None, None)),
+	("csb", (None, None)),
+	("csc", (None, None)),
+	("csd", (None, None)),
+	("cse", (None, None)),
+	("csf", (None, None)),
+	("csg", (None, None)),
+	("csh", (None, None)),
+	("csi", (None, None)),
+	("csj", (None, None)),
+	("csk", (None, None)),
+	("csl", (None, None)),
+	("csm", (None, None)),
+	("csn", (None, None)),
+	("cso", (None, None)),
+	("csp", (None, None)),
+	("csq", (None, None)),
+	("csr", (None, None)),
+	("css", (None, None)),
+	("cst", (None, None)),
+	("csu", (None, None)),
+	("csv", (None, None)),
+	("csw", (None, None)),
+	("csx", (None, None)),
+	("csy", (None, None)),
+	("csz", (None, None)),
+	("cta", (None, None)),
+	("ctc", (None, None)),
+	("ctd", (None, None)),
+	("cte", (None, None)),
+	("ctg", (None, None)),
+	("cth", (None, None)),
+	("ctl", (None, None)),
+	("ctm", (None, None)),
+	("ctn", (None, None)),
+	("cto", (None, None)),
+	("ctp", (None, None)),
+	("cts", (None, None)),
+	("ctt", (None, None)),
+	("ctu", (None, None)),
+	("cty", (None, None)),
+	("ctz", (None, None)),
+	("cu", (None, None)),
+	("cua", (None, None)),
+	("cub", (None, None)),
+	("cuc", (None, None)),
+	("cug", (None, None)),
+	("cuh", (None, None)),
+	("cui", (None, None)),
+	("cuj", (None, None)),
+	("cuk", (None, None)),
+	("cul", (None, None)),
+	("cum", (None, None)),
+	("cuo", (None, None)),
+	("cup", (None, None)),
+	("cuq", (None, None)),
+	("cur", (None, None)),
+	("cus", (None, None)),
+	("cut", (None, None)),
+	("cuu", (None, None)),
+	("cuv", (None, None)),
+	("cuw", (None, None)),
+	("cux", (None, None)),
+	("cuy", (None, None)),
+	("cv", (None, None)),
+	("cvg", (None, None)),
+	("cvn", (None, None)),
+	("cwa", (None, None)),
+	("cwb", (None, None)),
+	("cwd", (None, None)),
+	("cwe", (None, None)),
+	("cwg", (None, None)),
+	("cwt", (None, None)),
+	("cy", (None, Some("Latn"))),
+	("cya", (None, None)),
+	("cyb", (None, None)),
+	("cyo", (None, None)),
+	("czh", (None, None)),
+	("czk", (None, None)),
+	("czn", (None, None)),
+	("czo", (None, None)),
+	("czt", (None, None)),
+	("da", (None, Some("Latn"))),
+	("daa", (None, None)),
+	("dac", (None, None)),
+	("dad", (None, None)),
+	("dae", (None, None)),
+	("daf", (None, None)),
+	("dag", (None, None)),
+	("dah", (None, None)),
+	("dai", (None, None)),
+	("daj", (None, None)),
+	("dak", (None, None)),
+	("dal", (None, None)),
+	("dam", (None, None)),
+	("dao", (None, None)),
+	("dap", (None, None)),
+	("daq", (None, None)),
+	("dar", (None, None)),
+	("das", (None, None)),
+	("dau", (None, None)),
+	("dav", (None, None)),
+	("daw", (None, None)),
+	("dax", (None, None)),
+	("day", (None, None)),
+	("daz", (None, None)),
+	("dba", (None, None)),
+	("dbb", (None, None)),
+	("dbd", (None, None)),
+	("dbe", (None, None)),
+	("dbf", (None, None)),
+	("dbg", (None, None)),
+	("dbi", (None, None)),
+	("dbj", (None, None)),
+	("dbl", (None, None)),
+	("dbm", (None, None)),
+	("dbn", (None, None)),
+	("dbo", (None, None)),
+	("dbp", (None, None)),
+	("dbq", (None, None)),
+	("dbr", (None, None)),
+	("dbt", (None, None)),
+	("dbu", (None, None)),
+	("dbv", (None, None)),
+	("dbw", (None, None)),
+	("dby", (None, None)),
+	("dcc", (None, None)),
+	("dcr", (None, None)),
+	("dda", (None, None)),
+	("ddd", (None, None)),
+	("dde", (None, None)),
+	("ddg", (None, None)),
+	("ddi", (None, None)),
+	("ddj", (None, None)),
+	("ddn", (None, None)),
+	("ddo", (None, None)),
+	("ddr", (None, None)),
+	("dds", (None, None)),
+	("ddw", (None, None)),
+	("de", (None, Some("Latn"))),
+	("dec", (None, None)),
+	("ded", (None, None)),
+	("dee", (None, None)),
+	("def", (None, None)),
+	("deg", (None, None)),
+	("deh", (None, None)),
+	("dei", (None, None)),
+	("dek", (None, None)),
+	("del", (None, None)),
+	("dem", (None, None)),
+	("den", (None, None)),
+	("dep", (None, None)),
+	("deq", (None, None)),
+	("der", (None, None)),
+	("des", (None, None)),
+	("dev", (None, None)),
+	("dez", (None, None)),
+	("dga", (None, None)),
+	("dgb", (None, None)),
+	("dgc", (None, None)),
+	("dgd", (None, None)),
+	("dge", (None, None)),
+	("dgg", (None, None)),
+	("dgh", (None, None)),
+	("dgi", (None, None)),
+	("dgk", (None, None)),
+	("dgl", (None, None)),
+	("dgn", (None, None)),
+	("dgo", (None, None)),
+	("dgr", (None, None)),
+	("dgs", (None, None)),
+	("dgt", (None, None)),
+	("dgu", (None, None)),
+	("dgw", (None, None)),
+	("dgx", (None, None)),
+	("dgz", (None, None)),
+	("dha", (None, None)),
+	("dhd", (None, None)),
+	("dhg", (None, None)),
+	("dhi", (None, None)),
+	("dhl", (None, None)),
+	("dhm", (None, None)),
+	("dhn", (None, None)),
+	("dho", (None, None)),
+	("dhr", (None, None)),
+	("dhs", (None, None)),
+	("dhu", (None, None)),
+	("dhv", (None, None)),
+	("dhw", (None, None)),
+	("dhx", (None, None)),
+	("dia", (None, None)),
+	("dib", (None, None)),
+	("dic", (None, None)),
+	("did", (None, None)),
+	("dif", (None, None)),
+	("dig", (None, None)),
+	("dih", (None, None)),
+	("dii", (None, None)),
+	("dij", (None, None)),
+	("dik", (None, None)),
+	("dil", (None, None)),
+	("dim", (None, None)),
+	("din", (None, None)),
+	("dio", (None, None)),
+	("dip", (None, None)),
+	("diq", (None, None)),
+	("dir", (None, None)),
+	("dis", (None, None)),
+	("dit", (Some("dif"), None)),
+	("diu", (None, None)),
+	("diw", (None, None)),
+	("dix", (None, None)),
+	("diy", (None, None)),
+	("diz", (None, None)),
+	("dja", (None, None)),
+	("djb", (None, None)),
+	("djc", (None, None)),
+	("djd", (None, None)),
+	("dje", (None, None)),
+	("djf", (None, None)),
+	("dji", (None, None)),
+	("djj", (None, None)),
+	("djk", (None, None)),
+	("djl", (None, None)),
+	("djm", (None, None)),
+	("djn", (None, None)),
+	("djo", (None, None)),
+	("djr", (None, None)),
+	("dju", (None, None)),
+	("djw", (None, None)),
+	("dka", (None, None)),
+	("dkg", (None, None)),
+	("dkk", (None, None)),
+	("dkl", (None, None)),
+	("dkr", (None, None)),
+	("dks", (None, None)),
+	("dkx", (None, None)),
+	("dlg", (None, None)),
+	("dlk", (None, None)),
+	("dlm", (None, None)),
+	("dln", (None, None)),
+	("dma", (None, None)),
+	("dmb", (None, None)),
+	("dmc", (None, None)),
+	("dmd", (None, None)),
+	("dme", (None, None)),
+	("dmf", (None, None)),
+	("dmg", (None, None)),
+	("dmk", (None, None)),
+	("dml", (None, None)),
+	("dmm", (None, None)),
+	("dmn", (None, None)),
+	("dmo", (None, None)),
+	("dmr", (None, None)),
+	("dms", (None, None)),
+	("dmu", (None, None)),
+	("dmv", (None, None)),
+	("dmw", (None, None)),
+	("dmx", (None, None)),
+	("dmy", (None, None)),
+	("dna", (None, None)),
+	("dnd", (None, None)),
+	("dne", (None, None)),
+	("dng", (None, None)),
+	("dni", (None, None)),
+	("dnj", (None, None)),
+	("dnk", (None, None)),
+	("dnn", (None, None)),
+	("dno", (None, None)),
+	("dnr", (None, None)),
+	("dnt", (None, None)),
+	("dnu", (None, None)),
+	("dnv", (None, None)),
+	("dnw", (None, None)),
+	("dny", (None, None)),
+	("doa", (None, None)),
+	("dob", (None, None)),
+	("doc", (None, None)),
+	("doe", (None, None)),
+	("dof", (None, None)),
+	("doh", (None, None)),
+	("doi", (None, None)),
+	("dok", (None, None)),
+	("dol", (None, None)),
+	("don", (None, None)),
+	("doo", (None, None)),
+	("dop", (None, None)),
+	("doq", (None, None)),
+	("dor", (None, None)),
+	("dos", (None, None)),
+	("dot", (None, None)),
+	("dov", (None, None)),
+	("dow", (None, None)),
+	("dox", (None, None)),
+	("doy", (None, None)),
+	("doz", (None, None)),
+	("dpp", (None, None)),
+	("dra", (None, None)),
+	("drb", (None, None)),
+	("drc", (None, None)),
+	("drd", (None, None)),
+	("dre", (None, None)),
+	("drg", (None, None)),
+	("drh", (Some("khk"), None)),
+	("dri", (None, None)),
+	("drl", (None, None)),
+	("drn", (None, None)),
+	("dro", (None, None)),
+	("drq", (None, None)),
+	("drr", (Some("kzk"), None)),
+	("drs", (None, None)),
+	("drt", (None, None)),
+	("dru", (None, None)),
+	("drw", (Some("prs"), None)),
+	("dry", (None, None)),
+	("dsb", (None, Some("Latn"))),
+	("dse", (None, None)),
+	("dsh", (None, None)),
+	("dsi", (None, None)),
+	("dsl", (None, None)),
+	("dsn", (None, None)),
+	("dso", (None, None)),
+	("dsq", (None, None)),
+	("dta", (None, None)),
+	("dtb", (None, None)),
+	("dtd", (None, None)),
+	("dth", (None, None)),
+	("dti", (None, None)),
+	("dtk", (None, None)),
+	("dtm", (None, None)),
+	("dtn", (None, None)),
+	("dto", (None, None)),
+	("dtp", (None, None)),
+	("dtr", (None, None)),
+	("dts", (None, None)),
+	("dtt", (None, None)),
+	("dtu", (None, None)),
+	("dty", (None, None)),
+	("dua", (None, None)),
+	("dub", (None, None)),
+	("duc", (None, None)),
+	("dud", (None, None)),
+	("due", (None, None)),
+	("duf", (None, None)),
+	("dug", (None, None)),
+	("duh", (None, None)),
+	("dui", (None, None)),
+	("duj", (None, None)),
+	("duk", (None, None)),
+	("dul", (None, None)),
+	("dum", (None, None)),
+	("dun", (None, None)),
+	("duo", (None, None)),
+	("dup", (None, None)),
+	("duq", (None, None)),
+	("dur", (None, None)),
+	("dus", (None, None)),
+	("duu", (None, None)),
+	("duv", (None, None)),
+	("duw", (None, None)),
+	("dux", (None, None)),
+	("duy", (None, None)),
+	("duz", (None, None)),
+	("dv", (None, Some("Thaa"))),
+	("dva", (None, None)),
+	("dwa", (None, None)),
+	("dwk", (None, None)),
+	("dwl", (None, None)),
+	("dwr", (None, None)),
+	("dws", (None, None)),
+	("dwu", (None, None)),
+	("dww", (None, None)),
+	("dwy", (None, None)),
+	("dwz", (None, None)),
+	("dya", (None, None)),
+	("dyb", (None, None)),
+	("dyd", (None, None)),
+	("dyg", (None, None)),
+	("dyi", (None, None)),
+	("dym", (None, None)),
+	("dyn", (None, None)),
+	("dyo", (None, None)),
+	("dyu", (None, None)),
+	("dyy", (None, None)),
+	("dz", (None, Some("Tibt"))),
+	("dza", (None, None)),
+	("dzd", (None, None)),
+	("dze", (None, None)),
+	("dzg", (None, None)),
+	("dzl", (None, None)),
+	("dzn", (None, None)),
+	("eaa", (None, None)),
+	("ebc", (None, None)),
+	("ebg", (None, None)),
+	("ebk", (None, None)),
+	("ebo", (None, None)),
+	("ebr", (None, None)),
+	("ebu", (None, None)),
+	("ecr", (None, None)),
+	("ecs", (None, None)),
+	("ecy", (None, None)),
+	("ee", (None, None)),
+	("eee", (None, None)),
+	("efa", (None, None)),
+	("efe", (None, None)),
+	("efi", (None, None)),
+	("ega", (None, None)),
+	("egl", (None, None)),
+	("ego", (None, None)),
+	("egx", (None, None)),
+	("egy", (None, None)),
+	("ehs", (None, None)),
+	("ehu", (None, None)),
+	("eip", (None, None)),
+	("eit", (None, None)),
+	("eiv", (None, None)),
+	("eja", (None, None)),
+	("eka", (None, None)),
+	("ekc", (None, None)),
+	("eke", (None, None)),
+	("ekg", (None, None)),
+	("eki", (None, None)),
+	("ekk", (None, None)),
+	("ekl", (None, None)),
+	("ekm", (None, None)),
+	("eko", (None, None)),
+	("ekp", (None, None)),
+	("ekr", (None, None)),
+	("eky", (None, None)),
+	("el", (None, Some("Grek"))),
+	("ele", (None, None)),
+	("elh", (None, None)),
+	("eli", (None, None)),
+	("elk", (None, None)),
+	("elm", (None, None)),
+	("elo", (None, None)),
+	("elp", (None, None)),
+	("elu", (None, None)),
+	("elx", (None, None)),
+	("ema", (None, None)),
+	("emb", (None, None)),
+	("eme", (None, None)),
+	("emg", (None, None)),
+	("emi", (None, None)),
+	("emk", (None, None)),
+	("emm", (None, None)),
+	("emn", (None, None)),
+	("emo", (None, None)),
+	("emp", (None, None)),
+	("emq", (None, None)),
+	("ems", (None, None)),
+	("emu", (None, None)),
+	("emw", (None, None)),
+	("emx", (None, None)),
+	("emy", (None, None)),
+	("emz", (None, None)),
+	("en", (None, Some("Latn"))),
+	("ena", (None, None)),
+	("enb", (None, None)),
+	("enc", (None, None)),
+	("end", (None, None)),
+	("enf", (None, None)),
+	("enh", (None, None)),
+	("enl", (None, None)),
+	("enm", (None, None)),
+	("enn", (None, None)),
+	("eno", (None, None)),
+	("enq", (None, None)),
+	("enr", (None, None)),
+	("enu", (None, None)),
+	("env", (None, None)),
+	("enw", (None, None)),
+	("enx", (None, None)),
+	("eo", (None, Some("Latn"))),
+	("eot", (None, None)),
+	("epi", (None, None)),
+	("era", (None, None)),
+	("erg", (None, None)),
+	("erh", (None, None)),
+	("eri", (None, None)),
+	("erk", (None, None)),
+	("ero", (None, None)),
+	("err", (None, None)),
+	("ers", (None, None)),
+	("ert", (None, None)),
+	("erw", (None, None)),
+	("es", (None, Some("Latn"))),
+	("ese", (None, None)),
+	("esg", (None, None)),
+	("esh", (None, None)),
+	("esi", (None, None)),
+	("esk", (None, None)),
+	("esl", (None, None)),
+	("esm", (None, None)),
+	("esn", (None, None)),
+	("eso", (None, None)),
+	("esq", (None, None)),
+	("ess", (None, None)),
+	("esu", (None, None)),
+	("esx", (None, None)),
+	("esy", (None, None)),
+	("et", (None, Some("Latn"))),
+	("etb", (None, None)),
+	("etc", (None, None)),
+	("eth", (None, None)),
+	("etn", (None, None)),
+	("eto", (None, None)),
+	("etr", (None, None)),
+	("ets", (None, None)),
+	("ett", (None, None)),
+	("etu", (None, None)),
+	("etx", (None, None)),
+	("etz", (None, None)),
+	("eu", (None, Some("Latn"))),
+	("euq", (None, None)),
+	("eve", (None, None)),
+	("evh", (None, None)),
+	("evn", (None, None)),
+	("ewo", (None, None)),
+	("ext", (None, None)),
+	("eya", (None, None)),
+	("eyo", (None, None)),
+	("eza", (None, None)),
+	("eze", (None, None)),
+	("fa", (None, Some("Arab"))),
+	("faa", (None, None)),
+	("fab", (None, None)),
+	("fad", (None, None)),
+	("faf", (None, None)),
+	("fag", (None, None)),
+	("fah", (None, None)),
+	("fai", (None, None)),
+	("faj", (None, None)),
+	("fak", (None, None)),
+	("fal", (None, None)),
+	("fam", (None, None)),
+	("fan", (None, None)),
+	("fap", (None, None)),
+	("far", (None, None)),
+	("fat", (None, None)),
+	("fau", (None, None)),
+	("fax", (None, None)),
+	("fay", (None, None)),
+	("faz", (None, None)),
+	("fbl", (None, None)),
+	("fcs", (None, None)),
+	("fer", (None, None)),
+	("ff", (None, None)),
+	("ffi", (None, None)),
+	("ffm", (None, None)),
+	("fgr", (None, None)),
+	("fi", (None, Some("Latn"))),
+	("fia", (None, None)),
+	("fie", (None, None)),
+	("fif", (None, None)),
+	("fil", (None, None)),
+	("fip", (None, None)),
+	("fir", (None, None)),
+	("fit", (None, None)),
+	("fiu", (None, None)),
+	("fiw", (None, None)),
+	("fj", (None, Some("Latn"))),
+	("fkk", (None, None)),
+	("fkv", (None, None)),
+	("fla", (None, None)),
+	("flh", (None, None)),
+	("fli", (None, None)),
+	("fll", (None, None)),
+	("fln", (None, None)),
+	("flr", (None, None)),
+	("fly", (None, None)),
+	("fmp", (None, None)),
+	("fmu", (None, None)),
+	("fnb", (None, None)),
+	("fng", (None, None)),
+	("fni", (None, None)),
+	("fo", (None, Some("Latn"))),
+	("fod", (None, None)),
+	("foi", (None, None)),
+	("fom", (None, None)),
+	("fon", (None, None)),
+	("for", (None, None)),
+	("fos", (None, None)),
+	("fox", (None, None)),
+	("fpe", (None, None)),
+	("fqs", (None, None)),
+	("fr", (None, Some("Latn"))),
+	("frc", (None, None)),
+	("frd", (None, None)),
+	("frk", (None, None)),
+	("frm", (None, None)),
+	("fro", (None, None)),
+	("frp", (None, None)),
+	("frq", (None, None)),
+	("frr", (None, Some("Latn"))),
+	("frs", (None, Some("Latn"))),
+	("frt", (None, None)),
+	("fse", (None, None)),
+	("fsl", (None, None)),
+	("fss", (None, None)),
+	("fub", (None, None)),
+	("fuc", (None, None)),
+	("fud", (None, None)),
+	("fue", (None, None)),
+	("fuf", (None, None)),
+	("fuh", (None, None)),
+	("fui", (None, None)),
+	("fuj", (None, None)),
+	("fum", (None, None)),
+	("fun", (None, None)),
+	("fuq", (None, None)),
+	("fur", (None, None)),
+	("fut", (None, None)),
+	("fuu", (None, None)),
+	("fuv", (None, None)),
+	("fuy", (None, None)),
+	("fvr", (None, None)),
+	("fwa", (None, None)),
+	("fwe", (None, None)),
+	("fy", (None, Some("Latn"))),
+	("ga", (None, Some("Latn"))),
+	("gaa", (None, None)),
+	("gab", (None, None)),
+	("gac", (None, None)),
+	("gad", (None, None)),
+	("gae", (None, None)),
+	("gaf", (None, None)),
+	("gag", (None, None)),
+	("gah", (None, None)),
+	("gai", (None, None)),
+	("gaj", (None, None)),
+	("gak", (None, None)),
+	("gal", (None, None)),
+	("gam", (None, None)),
+	("gan", (None, None)),
+	("gao", (None, None)),
+	("gap", (None, None)),
+	("gaq", (None, None)),
+	("gar", (None, None)),
+	("gas", (None, None)),
+	("gat", (None, None)),
+	("gau", (None, None)),
+	("gav", (Some("dev"), None)),
+	("gaw", (None, None)),
+	("gax", (None, None)),
+	("gay", (None, None)),
+	("gaz", (None, None)),
+	("gba", (None, None)),
+	("gbb", (None, None)),
+	("gbc", (None, None)),
+	("gbd", (None, None)),
+	("gbe", (None, None)),
+	("gbf", (None, None)),
+	("gbg", (None, None)),
+	("gbh", (None, None)),
+	("gbi", (None, None)),
+	("gbj", (None, None)),
+	("gbk", (None, None)),
+	("gbl", (None, None)),
+	("gbm", (None, None)),
+	("gbn", (None, None)),
+	("gbo", (None, None)),
+	("gbp", (None, None)),
+	("gbq", (None, None)),
+	("gbr", (None, None)),
+	("gbs", (None, None)),
+	("gbu", (None, None)),
+	("gbv", (None, None)),
+	("gbw", (None, None)),
+	("gbx", (None, None)),
+	("gby", (None, None)),
+	("gbz", (None, None)),
+	("gcc", (None, None)),
+	("gcd", (None, None)),
+	("gce", (None, None)),
+	("gcf", (None, None)),
+	("gcl", (None, None)),
+	("gcn", (None, None)),
+	("gcr", (None, None)),
+	("gct", (None, None)),
+	("gd", (None, None)),
+	("gda", (None, None)),
+	("gdb", (None, None)),
+	("gdc", (None, None)),
+	("gdd", (None, None)),
+	("gde", (None, None)),
+	("gdf", (None, None)),
+	("gdg", (None, None)),
+	("gdh", (None, None)),
+	("gdi", (None, None)),
+	("gdj", (None, None)),
+	("gdk", (None, None)),
+	("gdl", (None, None)),
+	("gdm", (None, None)),
+	("gdn", (None, None)),
+	("gdo", (None, None)),
+	("gdq", (None, None)),
+	("gdr", (None, None)),
+	("gds", (None, None)),
+	("gdt", (None, None)),
+	("gdu", (None, None)),
+	("gdx", (None, None)),
+	("gea", (None, None)),
+	("geb", (None, None)),
+	("gec", (None, None)),
+	("ged", (None, None)),
+	("gef", (None, None)),
+	("geg", (None, None)),
+	("geh", (None, None)),
+	("gei", (None, None)),
+	("gej", (None, None)),
+	("gek", (None, None)),
+	("gel", (None, None)),
+	("gem", (None, None)),
+	("geq", (None, None)),
+	("ges", (None, None)),
+	("gev", (None, None)),
+	("gew", (None, None)),
+	("gex", (None, None)),
+	("gey", (None, None)),
+	("gez", (None, None)),
+	("gfk", (None, None)),
+	("gft", (None, None)),
+	("gfx", (Some("vaj"), None)),
+	("gga", (None, None)),
+	("ggb", (None, None)),
+	("ggd", (None, None)),
+	("gge", (None, None)),
+	("ggg", (None, None)),
+	("ggk", (None, None)),
+	("ggl", (None, None)),
+	("ggn", (Some("gvr"), None)),
+	("ggo", (None, None)),
+	("ggr", (None, None)),
+	("ggt", (None, None)),
+	("ggu", (None, None)),
+	("ggw", (None, None)),
+	("gha", (None, None)),
+	("ghc", (None, None)),
+	("ghe", (None, None)),
+	("ghh", (None, None)),
+	("ghk", (None, None)),
+	("ghl", (None, None)),
+	("ghn", (None, None)),
+	("gho", (None, None)),
+	("ghr", (None, None)),
+	("ghs", (None, None)),
+	("ght", (None, None)),
+	("gia", (None, None)),
+	("gib", (None, None)),
+	("gic", (None, None)),
+	("gid", (None, None)),
+	("gie", (None, None)),
+	("gig", (None, None)),
+	("gih", (None, None)),
+	("gii", (None, None)),
+	("gil", (None, None)),
+	("gim", (None, None)),
+	("gin", (None, None)),
+	("gio", (None, None)),
+	("gip", (None, None)),
+	("giq", (None, None)),
+	("gir", (None, None)),
+	("gis", (None, None)),
+	("git", (None, None)),
+	("giu", (None, None)),
+	("giw", (None, None)),
+	("gix", (None, None)),
+	("giy", (None, None)),
+	("giz", (None, None)),
+	("gji", (None, None)),
+	("gjk", (None, None)),
+	("gjm", (None, None)),
+	("gjn", (None, None)),
+	("gjr", (None, None)),
+	("gju", (None, None)),
+	("gka", (None, None)),
+	("gkd", (None, None)),
+	("gke", (None, None)),
+	("gkn", (None, None)),
+	("gko", (None, None)),
+	("gkp", (None, None)),
+	("gku", (None, None)),
+	("gl", (None, Some("Latn"))),
+	("glb", (None, None)),
+	("glc", (None, None)),
+	("gld", (None, None)),
+	("glh", (None, None)),
+	("gli", (Some("kzk"), None)),
+	("glj", (None, None)),
+	("glk", (None, None)),
+	("gll", (None, None)),
+	("glo", (None, None)),
+	("glr", (None, None)),
+	("glu", (None, None)),
+	("glw", (None, None)),
+	("gly", (None, None)),
+	("gma", (None, None)),
+	("gmb", (None, None)),
+	("gmd", (None, None)),
+	("gme", (None, None)),
+	("gmg", (None, None)),
+	("gmh", (None, None)),
+	("gml", (None, None)),
+	("gmm", (None, None)),
+	("gmn", (None, None)),
+	("gmq", (None, None)),
+	("gmr", (None, None)),
+	("gmu", (None, None)),
+	("gmv", (None, None)),
+	("gmw", (None, None)),
+	("gmx", (None, None)),
+	("gmy", (None, None)),
+	("gmz", (None, None)),
+	("gn", (None, Some("Latn"))),
+	("gna", (None, None)),
+	("gnb", (None, None)),
+	("gnc", (None, None)),
+	("gnd", (None, None)),
+	("gne", (None, None)),
+	("gng", (None, None)),
+	("gnh", (None, None)),
+	("gni", (None, None)),
+	("gnj", (None, None)),
+	("gnk", (None, None)),
+	("gnl", (None, None)),
+	("gnm", (None, None)),
+	("gnn", (None, None)),
+	("gno", (None, None)),
+	("gnq", (None, None)),
+	("gnr", (None, None)),
+	("gnt", (None, None)),
+	("gnu", (None, None)),
+	("gnw", (None, None)),
+	("gnz", (None, None)),
+	("goa", (None, None)),
+	("gob", (None, None)),
+	("goc", (None, None)),
+	("god", (None, None)),
+	("goe", (None, None)),
+	("gof", (None, None)),
+	("gog", (None, None)),
+	("goh", (None, None)),
+	("goi", (None, None)),
+	("goj", (None, None)),
+	("gok", (None, None)),
+	("gol", (None, None)),
+	("gom", (None, None)),
+	("gon", (None, None)),
+	("goo", (None, None)),
+	("gop", (None, None)),
+	("goq", (None, None)),
+	("gor", (None, None)),
+	("gos", (None, None)),
+	("got", (None, None)),
+	("gou", (None, None)),
+	("gow", (None, None)),
+	("gox", (None, None)),
+	("goy", (None, None)),
+	("goz", (None, None)),
+	("gpa", (None, None)),
+	("gpe", (None, None)),
+	("gpn", (None, None)),
+	("gqa", (None, None)),
+	("gqi", (None, None)),
+	("gqn", (None, None)),
+	("gqr", (None, None)),
+	("gqu", (None, None)),
+	("gra", (None, None)),
+	("grb", (None, None)),
+	("grc", (None, None)),
+	("grd", (None, None)),
+	("grg", (None, None)),
+	("grh", (None, None)),
+	("gri", (None, None)),
+	("grj", (None, None)),
+	("grk", (None, None)),
+	("grm", (None, None)),
+	("gro", (None, None)),
+	("grq", (None, None)),
+	("grr", (None, None)),
+	("grs", (None, None)),
+	("grt", (None, None)),
+	("gru", (None, None)),
+	("grv", (None, None)),
+	("grw", (None, None)),
+	("grx", (None, None)),
+	("gry", (None, None)),
+	("grz", (None, None)),
+	("gse", (None, None)),
+	("gsg", (None, None)),
+	("gsl", (None, None)),
+	("gsm", (None, None)),
+	("gsn", (None, None)),
+	("gso", (None, None)),
+	("gsp", (None, None)),
+	("gss", (None, None)),
+	("gsw", (None, Some("Latn"))),
+	("gta", (None, None)),
+	("gti", (Some("nyc"), None)),
+	("gtu", (None, None)),
+	("gu", (None, Some("Gujr"))),
+	("gua", (None, None)),
+	("gub", (None, None)),
+	("guc", (None, None)),
+	("gud", (None, None)),
+	("gue", (None, None)),
+	("guf", (None, None)),
+	("gug", (None, None)),
+	("guh", (None, None)),
+	("gui", (None, None)),
+	("guk", (None, None)),
+	("gul", (None, None)),
+	("gum", (None, None)),
+	("gun", (None, None)),
+	("guo", (None, None)),
+	("gup", (None, None)),
+	("guq", (None, None)),
+	("gur", (None, None)),
+	("gus", (None, None)),
+	("gut", (None, None)),
+	("guu", (None, None)),
+	("guv", (Some("duz"), None)),
+	("guw", (None, None)),
+	("gux", (None, None)),
+	("guz", (None, None)),
+	("gv", (None, Some("Latn"))),
+	("gva", (None, None)),
+	("gvc", (None, None)),
+	("gve", (None, None)),
+	("gvf", (None, None)),
+	("gvj", (None, None)),
+	("gvl", (None, None)),
+	("gvm", (None, None)),
+	("gvn", (None, None)),
+	("gvo", (None, None)),
+	("gvp", (None, None)),
+	("gvr", (None, None)),
+	("gvs", (None, None)),
+	("gvy", (None, None)),
+	("gwa", (None, None)),
+	("gwb", (None, None)),
+	("gwc", (None, None)),
+	("gwd", (None, None)),
+	("gwe", (None, None)),
+	("gwf", (None, None)),
+	("gwg", (None, None)),
+	("gwi", (None, None)),
+	("gwj", (None, None)),
+	("gwm", (None, None)),
+	("gwn", (None, None)),
+	("gwr", (None, None)),
+	("gwt", (None, None)),
+	("gwu", (None, None)),
+	("gww", (None, None)),
+	("gwx", (None, None)),
+	("gxx", (None, None)),
+	("gya", (None, None)),
+	("gyb", (None, None)),
+	("gyd", (None, None)),
+	("gye", (None, None)),
+	("gyf", (None, None)),
+	("gyg", (None, None)),
+	("gyi", (None, None)),
+	("gyl", (None, None)),
+	("gym", (None, None)),
+	("gyn", (None, None)),
+	("gyo", (None, None)),
+	("gyr", (None, None)),
+	("gyy", (None, None)),
+	("gyz", (None, None)),
+	("gza", (None, None)),
+	("gzi", (None, None)),
+	("gzn", (None, None)),
+	("ha", (None, None)),
+	("haa", (None, None)),
+	("hab", (None, None)),
+	("hac", (None, None)),
+	("had", (None, None)),
+	("hae", (None, None)),
+	("haf", (None, None)),
+	("hag", (None, None)),
+	("hah", (None, None)),
+	("hai", (None, None)),
+	("haj", (None, None)),
+	("hak", (None, None)),
+	("hal", (None, None)),
+	("ham", (None, None)),
+	("han", (None, None)),
+	("hao", (None, None)),
+	("hap", (None, None)),
+	("haq", (None, None)),
+	("har", (None, None)),
+	("has", (None, None)),
+	("hav", (None, None)),
+	("haw", (None, None)),
+	("hax", (None, None)),
+	("hay", (None, None)),
+	("haz", (None, None)),
+	("hba", (None, None)),
+	("hbb", (None, None)),
+	("hbn", (None, None)),
+	("hbo", (None, None)),
+	("hbu", (None, None)),
+	("hca", (None, None)),
+	("hch", (None, None)),
+	("hdn", (None, None)),
+	("hds", (None, None)),
+	("hdy", (None, None)),
+	("he", (None, Some("Hebr"))),
+	("hea", (None, None)),
+	("hed", (None, None)),
+	("heg", (None, None)),
+	("heh", (None, None)),
+	("hei", (None, None)),
+	("hem", (None, None)),
+	("hgm", (None, None)),
+	("hgw", (None, None)),
+	("hhi", (None, None)),
+	("hhr", (None, None)),
+	("hhy", (None, None)),
+	("hi", (None, Some("Deva"))),
+	("hia", (None, None)),
+	("hib", (None, None)),
+	("hid", (None, None)),
+	("hif", (None, None)),
+	("hig", (None, None)),
+	("hih", (None, None)),
+	("hii", (None, None)),
+	("hij", (None, None)),
+	("hik", (None, None)),
+	("hil", (None, None)),
+	("him", (None, None)),
+	("hio", (None, None)),
+	("hir", (None, None)),
+	("hit", (None, None)),
+	("hiw", (None, None)),
+	("hix", (None, None)),
+	("hji", (None, None)),
+	("hka", (None, None)),
+	("hke", (None, None)),
+	("hkh", (None, None)),
+	("hkk", (None, None)),
+	("hkn", (None, None)),
+	("hks", (None, None)),
+	("hla", (None, None)),
+	("hlb", (None, None)),
+	("hld", (None, None)),
+	("hle", (None, None)),
+	("hlt", (None, None)),
+	("hlu", (None, None)),
+	("hma", (None, None)),
+	("hmb", (None, None)),
+	("hmc", (None, None)),
+	("hmd", (None, None)),
+	("hme", (None, None)),
+	("hmf", (None, None)),
+	("hmg", (None, None)),
+	("hmh", (None, None)),
+	("hmi", (None, None)),
+	("hmj", (None, None)),
+	("hmk", (None, None)),
+	("hml", (None, None)),
+	("hmm", (None, None)),
+	("hmn", (None, None)),
+	("hmp", (None, None)),
+	("hmq", (None, None)),
+	("hmr", (None, None)),
+	("hms", (None, None)),
+	("hmt", (None, None)),
+	("hmu", (None, None)),
+	("hmv", (None, None)),
+	("hmw", (None, None)),
+	("hmx", (None, None)),
+	("hmy", (None, None)),
+	("hmz", (None, None)),
+	("hna", (None, None)),
+	("hnd", (None, None)),
+	("hne", (None, None)),
+	("hng", (None, None)),
+	("hnh", (None, None)),
+	("hni", (None, None)),
+	("hnj", (None, None)),
+	("hnn", (None, None)),
+	("hno", (None, None)),
+	("hns", (None, None)),
+	("hnu", (None, None)),
+	("ho", (None, None)),
+	("hoa", (None, None)),
+	("hob", (None, None)),
+	("hoc", (None, None)),
+	("hod", (None, None)),
+	("hoe", (None, None)),
+	("hoh", (None, None)),
+	("hoi", (None, None)),
+	("hoj", (None, None)),
+	("hok", (None, None)),
+	("hol", (None, None)),
+	("hom", (None, None)),
+	("hoo", (None, None)),
+	("hop", (None, None)),
+	("hor", (None, None)),
+	("hos", (None, None)),
+	("hot", (None, None)),
+	("hov", (None, None)),
+	("how", (None, None)),
+	("hoy", (None, None)),
+	("hoz", (None, None)),
+	("hpo", (None, None)),
+	("hps", (None, None)),
+	("hr", (None, Some("Latn"))),
+	("hra", (None, None)),
+	("hrc", (None, None)),
+	("hre", (None, None)),
+	("hrk", (None, None)),
+	("hrm", (None, None)),
+	("hro", (None, None)),
+	("hrp", (None, None)),
+	("hrr", (Some("jal"), None)),
+	("hrt", (None, None)),
+	("hru", (None, None)),
+	("hrw", (None, None)),
+	("hrx", (None, None)),
+	("hrz", (None, None)),
+	("hsb", (None, Some("Latn"))),
+	("hsh", (None, None)),
+	("hsl", (None, None)),
+	("hsn", (None, None)),
+	("hss", (None, None)),
+	("ht", (None, Some("Latn"))),
+	("hti", (None, None)),
+	("hto", (None, None)),
+	("hts", (None, None)),
+	("htu", (None, None)),
+	("htx", (None, None)),
+	("hu", (None, Some("Latn"))),
+	("hub", (None, None)),
+	("huc", (None, None)),
+	("hud", (None, None)),
+	("hue", (None, None)),
+	("huf", (None, None)),
+	("hug", (None, None)),
+	("huh", (None, None)),
+	("hui", (None, None)),
+	("huj", (None, None)),
+	("huk", (None, None)),
+	("hul", (None, None)),
+	("hum", (None, None)),
+	("huo", (None, None)),
+	("hup", (None, None)),
+	("huq", (None, None)),
+	("hur", (None, None)),
+	("hus", (None, None)),
+	("hut", (None, None)),
+	("huu", (None, None)),
+	("huv", (None, None)),
+	("huw", (None, None)),
+	("hux", (None, None)),
+	("huy", (None, None)),
+	("huz", (None, None)),
+	("hvc", (None, None)),
+	("hve", (None, None)),
+	("hvk", (None, None)),
+	("hvn", (None, None)),
+	("hvv", (None, None)),
+	("hwa", (None, None)),
+	("hwc", (None, None)),
+	("hwo", (None, None)),
+	("hy", (None, Some("Armn"))),
+	("hya", (None, None)),
+	("hyw", (None, None)),
+	("hyx", (None, None)),
+	("hz", (None, None)),
+	("ia", (None, None)),
+	("iai", (None, None)),
+	("ian", (None, None)),
+	("iap", (None, None)),
+	("iar", (None, None)),
+	("iba", (None, None)),
+	("ibb", (None, None)),
+	("ibd", (None, None)),
+	("ibe", (None, None)),
+	("ibg", (None, None)),
+	("ibh", (None, None)),
+	("ibi", (Some("opa"), None)),
+	("ibl", (None, None)),
+	("ibm", (None, None)),
+	("ibn", (None, None)),
+	("ibr", (None, None)),
+	("ibu", (None, None)),
+	("iby", (None, None)),
+	("ica", (None, None)),
+	("ich", (None, None)),
+	("icl", (None, None)),
+	("icr", (None, None)),
+	("id", (None, Some("Latn"))),
+	("ida", (None, None)),
+	("idb", (None, None)),
+	("idc", (None, None)),
+	("idd", (None, None)),
+	("ide", (None, None)),
+	("idi", (None, None)),
+	("idr", (None, None)),
+	("ids", (None, None)),
+	("idt", (None, None)),
+	("idu", (None, None)),
+	("ie", (None, None)),
+	("ifa", (None, None)),
+	("ifb", (None, None)),
+	("ife", (None, None)),
+	("iff", (None, None)),
+	("ifk", (None, None)),
+	("ifm", (None, None)),
+	("ifu", (None, None)),
+	("ify", (None, None)),
+	("ig", (None, None)),
+	("igb", (None, None)),
+	("ige", (None, None)),
+	("igg", (None, None)),
+	("igl", (None, None)),
+	("igm", (None, None)),
+	("ign", (None, None)),
+	("igo", (None, None)),
+	("igs", (None, None)),
+	("igw", (None, None)),
+	("ihb", (None, None)),
+	("ihi", (None, None)),
+	("ihp", (None, None)),
+	("ihw", (None, None)),
+	("ii", (None, None)),
+	("iin", (None, None)),
+	("iir", (None, None)),
+	("ijc", (None, None)),
+	("ije", (None, None)),
+	("ijj", (None, None)),
+	("ijn", (None, None)),
+	("ijo", (None, None)),
+	("ijs", (None, None)),
+	("ik", (None, None)),
+	("ike", (None, None)),
+	("iki", (None, None)),
+	("ikk", (None, None)),
+	("ikl", (None, None)),
+	("iko", (None, None)),
+	("ikp", (None, None)),
+	("ikr", (None, None)),
+	("iks", (None, None)),
+	("ikt", (None, None)),
+	("ikv", (None, None)),
+	("ikw", (None, None)),
+	("ikx", (None, None)),
+	("ikz", (None, None)),
+	("ila", (None, None)),
+	("ilb", (None, None)),
+	("ilg", (None, None)),
+	("ili", (None, None)),
+	("ilk", (None, None)),
+	("ill", (None, None)),
+	("ilm", (None, None)),
+	("ilo", (None, None)),
+	("ilp", (None, None)),
+	("ils", (None, None)),
+	("ilu", (None, None)),
+	("ilv", (None, None)),
+	("ilw", (Some("gal"), None)),
+	("ima", (None, None)),
+	("ime", (None, None)),
+	("imi", (None, None)),
+	("iml", (None, None)),
+	("imn", (None, None)),
+	("imo", (None, None)),
+	("imr", (None, None)),
+	("ims", (None, None)),
+	("imy", (None, None)),
+	("in", (Some("id"), Some("Latn"))),
+	("inb", (None, None)),
+	("inc", (None, None)),
+	("ine", (None, None)),
+	("ing", (None, None)),
+	("inh", (None, None)),
+	("inj", (None, None)),
+	("inl", (None, None)),
+	("inm", (None, None)),
+	("inn", (None, None)),
+	("ino", (None, None)),
+	("inp", (None, None)),
+	("ins", (None, None)),
+	("int", (None, None)),
+	("inz", (None, None)),
+	("io", (None, None)),
+	("ior", (None, None)),
+	("iou", (None, None)),
+	("iow", (None, None)),
+	("ipi", (None, None)),
+	("ipo", (None, None)),
+	("iqu", (None, None)),
+	("iqw", (None, None)),
+	("ira", (None, None)),
+	("ire", (None, None)),
+	("irh", (None, None)),
+	("iri", (None, None)),
+	("irk", (None, None)),
+	("irn", (None, None)),
+	("iro", (None, None)),
+	("irr", (None, None)),
+	("iru", (None, None)),
+	("irx", (None, None)),
+	("iry", (None, None)),
+	("is", (None, Some("Latn"))),
+	("isa", (None, None)),
+	("isc", (None, None)),
+	("isd", (None, None)),
+	("ise", (None, None)),
+	("isg", (None, None)),
+	("ish", (None, None)),
+	("isi", (None, None)),
+	("isk", (None, None)),
+	("ism", (None, None)),
+	("isn", (None, None)),
+	("iso", (None, None)),
+	("isr", (None, None)),
+	("ist", (None, None)),
+	("isu", (None, None)),
+	("it", (None, Some("Latn"))),
+	("itb", (None, None)),
+	("itc", (None, None)),
+	("itd", (None, None)),
+	("ite", (None, None)),
+	("iti", (None, None)),
+	("itk", (None, None)),
+	("itl", (None, None)),
+	("itm", (None, None)),
+	("ito", (None, None)),
+	("itr", (None, None)),
+	("its", (None, None)),
+	("itt", (None, None)),
+	("itv", (None, None)),
+	("itw", (None, None)),
+	("itx", (None, None)),
+	("ity", (None, None)),
+	("itz", (None, None)),
+	("iu", (None, None)),
+	("ium", (None, None)),
+	("ivb", (None, None)),
+	("ivv", (None, None)),
+	("iw", (Some("he"), Some("Hebr"))),
+	("iwk", (None, None)),
+	("iwm", (None, None)),
+	("iwo", (None, None)),
+	("iws", (None, None)),
+	("ixc", (None, None)),
+	("ixl", (None, None)),
+	("iya", (None, None)),
+	("iyo", (None, None)),
+	("iyx", (None, None)),
+	("izh", (None, None)),
+	("izi", (None, None)),
+	("izr", (None, None)),
+	("izz", (None, None)),
+	("ja", (None, Some("Jpan"))),
+	("jaa", (None, None)),
+	("jab", (None, None)),
+	("jac", (None, None)),
+	("jad", (None, None)),
+	("jae", (None, None)),
+	("jaf", (None, None)),
+	("jah", (None, None)),
+	("jaj", (None, None)),
+	("jak", (None, None)),
+	("jal", (None, None)),
+	("jam", (None, None)),
+	("jan", (None, None)),
+	("jao", (None, None)),
+	("jaq", (None, None)),
+	("jar", (None, None)),
+	("jas", (None, None)),
+	("jat", (None, None)),
+	("jau", (None, None)),
+	("jax", (None, None)),
+	("jay", (None, None)),
+	("jaz", (None, None)),
+	("jbe", (None, None)),
+	("jbi", (None, None)),
+	("jbj", (None, None)),
+	("jbk", (None, None)),
+	("jbm", (None, None)),
+	("jbn", (None, None)),
+	("jbo", (None, None)),
+	("jbr", (None, None)),
+	("jbt", (None, None)),
+	("jbu", (None, None)),
+	("jbw", (None, None)),
+	("jcs", (None, None)),
+	("jct", (None, None)),
+	("jda", (None, None)),
+	("jdg", (None, None)),
+	("jdt", (None, None)),
+	("jeb", (None, None)),
+	("jee", (None, None)),
+	("jeg", (Some("oyb"), None)),
+	("jeh", (None, None)),
+	("jei", (None, None)),
+	("jek", (None, None)),
+	("jel", (None, None)),
+	("jen", (None, None)),
+	("jer", (None, None)),
+	("jet", (None, None)),
+	("jeu", (None, None)),
+	("jgb", (None, None)),
+	("jge", (None, None)),
+	("jgk", (None, None)),
+	("jgo", (None, None)),
+	("jhi", (None, None)),
+	("jhs", (None, None)),
+	("ji", (Some("yi"), None)),
+	("jia", (None, None)),
+	("jib", (None, None)),
+	("jic", (None, None)),
+	("jid", (None, None)),
+	("jie", (None, None)),
+	("jig", (None, None)),
+	("jih", (None, None)),
+	("jii", (None, None)),
+	("jil", (None, None)),
+	("jim", (None, None)),
+	("jio", (None, None)),
+	("jiq", (None, None)),
+	("jit", (None, None)),
+	("jiu", (None, None)),
+	("jiv", (None, None)),
+	("jiy", (None, None)),
+	("jje", (None, None)),
+	("jjr", (None, None)),
+	("jka", (None, None)),
+	("jkm", (None, None)),
+	("jko", (None, None)),
+	("jkp", (None, None)),
+	("jkr", (None, None)),
+	("jks", (None, None)),
+	("jku", (None, None)),
+	("jle", (None, None)),
+	("jls", (None, None)),
+	("jma", (None, None)),
+	("jmb", (None, None)),
+	("jmc", (None, None)),
+	("jmd", (None, None)),
+	("jmi", (None, None)),
+	("jml", (None, None)),
+	("jmn", (None, None)),
+	("jmr", (None, None)),
+	("jms", (None, None)),
+	("jmw", (None, None)),
+	("jmx", (None, None)),
+	("jna", (None, None)),
+	("jnd", (None, None)),
+	("jng", (None, None)),
+	("jni", (None, None)),
+	("jnj", (None, None)),
+	("jnl", (None, None)),
+	("jns", (None, None)),
+	("job", (None, None)),
+	("jod", (None, None)),
+	("jog", (None, None)),
+	("jor", (None, None)),
+	("jos", (None, None)),
+	("jow", (None, None)),
+	("jpa", (None, None)),
+	("jpr", (None, None)),
+	("jpx", (None, None)),
+	("jqr", (None, None)),
+	("jra", (None, None)),
+	("jrb", (None, None)),
+	("jrr", (None, None)),
+	("jrt", (None, None)),
+	("jru", (None, None)),
+	("jsl", (None, None)),
+	("jua", (None, None)),
+	("jub", (None, None)),
+	("juc", (None, None)),
+	("jud", (None, None)),
+	("juh", (None, None)),
+	("jui", (None, None)),
+	("juk", (None, None)),
+	("jul", (None, None)),
+	("jum", (None, None)),
+	("jun", (None, None)),
+	("juo", (None, None)),
+	("jup", (None, None)),
+	("jur", (None, None)),
+	("jus", (None, None)),
+	("jut", (None, None)),
+	("juu", (None, None)),
+	("juw", (None, None)),
+	("juy", (None, None)),
+	("jv", (None, None)),
+	("jvd", (None, None)),
+	("jvn", (None, None)),
+	("jw", (Some("jv"), None)),
+	("jwi", (None, None)),
+	("jya", (None, None)),
+	("jye", (None, None)),
+	("jyy", (None, None)),
+	("ka", (None, Some("Geor"))),
+	("kaa", (None, None)),
+	("kab", (None, None)),
+	("kac", (None, None)),
+	("kad", (None, None)),
+	("kae", (None, None)),
+	("kaf", (None, None)),
+	("kag", (None, None)),
+	("kah", (None, None)),
+	("kai", (None, None)),
+	("kaj", (None, None)),
+	("kak", (None, None)),
+	("kam", (None, None)),
+	("kao", (None, None)),
+	("kap", (None, None)),
+	("kaq", (None, None)),
+	("kar", (None, None)),
+	("kav", (None, None)),
+	("kaw", (None, None)),
+	("kax", (None, None)),
+	("kay", (None, None)),
+	("kba", (None, None)),
+	("kbb", (None, None)),
+	("kbc", (None, None)),
+	("kbd", (None, None)),
+	("kbe", (None, None)),
+	("kbf", (None, None)),
+	("kbg", (None, None)),
+	("kbh", (None, None)),
+	("kbi", (None, None)),
+	("kbj", (None, None)),
+	("kbk", (None, None)),
+	("kbl", (None, None)),
+	("kbm", (None, None)),
+	("kbn", (None, None)),
+	("kbo", (None, None)),
+	("kbp", (None, None)),
+	("kbq", (None, None)),
+	("kbr", (None, None)),
+	("kbs", (None, None)),
+	("kbt", (None, None)),
+	("kbu", (None, None)),
+	("kbv", (None, None)),
+	("kbw", (None, None)),
+	("kbx", (None, None)),
+	("kby", (None, None)),
+	("kbz", (None, None)),
+	("kca", (None, None)),
+	("kcb", (None, None)),
+	("kcc", (None, None)),
+	("kcd", (None, None)),
+	("kce", (None, None)),
+	("kcf", (None, None)),
+	("kcg", (None, None)),
+	("kch", (None, None)),
+	("kci", (None, None)),
+	("kcj", (None, None)),
+	("kck", (None, None)),
+	("kcl", (None, None)),
+	("kcm", (None, None)),
+	("kcn", (None, None)),
+	("kco", (None, None)),
+	("kcp", (None, None)),
+	("kcq", (None, None)),
+	("kcr", (None, None)),
+	("kcs", (None, None)),
+	("kct", (None, None)),
+	("kcu", (None, None)),
+	("kcv", (None, None)),
+	("kcw", (None, None)),
+	("kcx", (None, None)),
+	("kcy", (None, None)),
+	("kcz", (None, None)),
+	("kda", (None, None)),
+	("kdc", (None, None)),
+	("kdd", (None, None)),
+	("kde", (None, None)),
+	("kdf", (None, None)),
+	("kdg", (None, None)),
+	("kdh", (None, None)),
+	("kdi", (None, None)),
+	("kdj", (None, None)),
+	("kdk", (None, None)),
+	("kdl", (None, None)),
+	("kdm", (None, None)),
+	("kdn", (None, None)),
+	("kdo", (None, None)),
+	("kdp", (None, None)),
+	("kdq", (None, None)),
+	("kdr", (None, None)),
+	("kdt", (None, None)),
+	("kdu", (None, None)),
+	("kdv", (None, None)),
+	("kdw", (None, None)),
+	("kdx", (None, None)),
+	("kdy", (None, None)),
+	("kdz", (None, None)),
+	("kea", (None, None)),
+	("keb", (None, None)),
+	("kec", (None, None)),
+	("ked", (None, None)),
+	("kee", (None, None)),
+	("kef", (None, None)),
+	("keg", (None, None)),
+	("keh", (None, None)),
+	("kei", (None, None)),
+	("kej", (None, None)),
+	("kek", (None, None)),
+	("kel", (None, None)),
+	("kem", (None, None)),
+	("ken", (None, None)),
+	("keo", (None, None)),
+	("kep", (None, None)),
+	("keq", (None, None)),
+	("ker", (None, None)),
+	("kes", (None, None)),
+	("ket", (None, None)),
+	("keu", (None, None)),
+	("kev", (None, None)),
+	("kew", (None, None)),
+	("kex", (None, None)),
+	("key", (None, None)),
+	("kez", (None, None)),
+	("kfa", (None, None)),
+	("kfb", (None, None)),
+	("kfc", (None, None)),
+	("kfd", (None, None)),
+	("kfe", (None, None)),
+	("kff", (None, None)),
+	("kfg", (None, None)),
+	("kfh", (None, None)),
+	("kfi", (None, None)),
+	("kfj", (None, None)),
+	("kfk", (None, None)),
+	("kfl", (None, None)),
+	("kfm", (None, None)),
+	("kfn", (None, None)),
+	("kfo", (None, None)),
+	("kfp", (None, None)),
+	("kfq", (None, None)),
+	("kfr", (None, None)),
+	("kfs", (None, None)),
+	("kft", (None, None)),
+	("kfu", (None, None)),
+	("kfv", (None, None)),
+	("kfw", (None, None)),
+	("kfx", (None, None)),
+	("kfy", (None, None)),
+	("kfz", (None, None)),
+	("kg", (None, None)),
+	("kga", (None, None)),
+	("kgb", (None, None)),
+	("kgc", (Some("tdf"), None)),
+	("kgd", (None, None)),
+	("kge", (None, None)),
+	("kgf", (None, None)),
+	("kgg", (None, None)),
+	("kgh", (Some("kml"), None)),
+	("kgi", (None, None)),
+	("kgj", (None, None)),
+	("kgk", (None, None)),
+	("kgl", (None, None)),
+	("kgm", (None, None)),
+	("kgn", (None, None)),
+	("kgo", (None, None)),
+	("kgp", (None, None)),
+	("kgq", (None, None)),
+	("kgr", (None, None)),
+	("kgs", (None, None)),
+	("kgt", (None, None)),
+	("kgu", (None, None)),
+	("kgv", (None, None)),
+	("kgw", (None, None)),
+	("kgx", (None, None)),
+	("kgy", (None, None)),
+	("kha", (None, None)),
+	("khb", (None, None)),
+	("khc", (None, None)),
+	("khd", (None, None)),
+	("khe", (None, None)),
+	("khf", (None, None)),
+	("khg", (None, None)),
+	("khh", (None, None)),
+	("khi", (None, None)),
+	("khj", (None, None)),
+	("khk", (None, None)),
+	("khl", (None, None)),
+	("khn", (None, None)),
+	("kho", (None, None)),
+	("khp", (None, None)),
+	("khq", (None, None)),
+	("khr", (None, None)),
+	("khs", (None, None)),
+	("kht", (None, None)),
+	("khu", (None, None)),
+	("khv", (None, None)),
+	("khw", (None, None)),
+	("khx", (None, None)),
+	("khy", (None, None)),
+	("khz", (None, None)),
+	("ki", (None, None)),
+	("kia", (None, None)),
+	("kib", (None, None)),
+	("kic", (None, None)),
+	("kid", (None, None)),
+	("kie", (None, None)),
+	("kif", (None, None)),
+	("kig", (None, None)),
+	("kih", (None, None)),
+	("kii", (None, None)),
+	("kij", (None, None)),
+	("kil", (None, None)),
+	("kim", (None, None)),
+	("kio", (None, None)),
+	("kip", (None, None)),
+	("kiq", (None, None)),
+	("kis", (None, None)),
+	("kit", (None, None)),
+	("kiu", (None, None)),
+	("kiv", (None, None)),
+	("kiw", (None, None)),
+	("kix", (None, None)),
+	("kiy", (None, None)),
+	("kiz", (None, None)),
+	("kj", (None, None)),
+	("kja", (None, None)),
+	("kjb", (None, None)),
+	("kjc", (None, None)),
+	("kjd", (None, None)),
+	("kje", (None, None)),
+	("kjf", (None, None)),
+	("kjg", (None, None)),
+	("kjh", (None, None)),
+	("kji", (None, None)),
+	("kjj", (None, None)),
+	("kjk", (None, None)),
+	("kjl", (None, None)),
+	("kjm", (None, None)),
+	("kjn", (None, None)),
+	("kjo", (None, None)),
+	("kjp", (None, None)),
+	("kjq", (None, None)),
+	("kjr", (None, None)),
+	("kjs", (None, None)),
+	("kjt", (None, None)),
+	("kju", (None, None)),
+	("kjv", (None, None)),
+	("kjx", (None, None)),
+	("kjy", (None, None)),
+	("kjz", (None, None)),
+	("kk", (None, Some("Cyrl"))),
+	("kka", (None, None)),
+	("kkb", (None, None)),
+	("kkc", (None, None)),
+	("kkd", (None, None)),
+	("kke", (None, None)),
+	("kkf", (None, None)),
+	("kkg", (None, None)),
+	("kkh", (None, None)),
+	("kki", (None, None)),
+	("kkj", (None, None)),
+	("kkk", (None, None)),
+	("kkl", (None, None)),
+	("kkm", (None, None)),
+	("kkn", (None, None)),
+	("kko", (None, None)),
+	("kkp", (None, None)),
+	("kkq", (None, None)),
+	("kkr", (None, None)),
+	("kks", (None, None)),
+	("kkt", (None, None)),
+	("kku", (None, None)),
+	("kkv", (None, None)),
+	("kkw", (None, None)),
+	("kkx", (None, None)),
+	("kky", (None, None)),
+	("kkz", (None, None)),
+	("kl", (None, Some("Latn"))),
+	("kla", (None, None)),
+	("klb", (None, None)),
+	("klc", (None, None)),
+	("kld", (None, None)),
+	("kle", (None, None)),
+	("klf", (None, None)),
+	("klg", (None, None)),
+	("klh", (None, None)),
+	("kli", (None, None)),
+	("klj", (None, None)),
+	("klk", (None, None)),
+	("kll", (None, None)),
+	("klm", (None, None)),
+	("kln", (None, None)),
+	("klo", (None, None)),
+	("klp", (None, None)),
+	("klq", (None, None)),
+	("klr", (None, None)),
+	("kls", (None, None)),
+	("klt", (None, None)),
+	("klu", (None, None)),
+	("klv", (None, None)),
+	("klw", (None, None)),
+	("klx", (None, None)),
+	("kly", (None, None)),
+	("klz", (None, None)),
+	("km", (None, Some("Khmr"))),
+	("kma", (None, None)),
+	("kmb", (None, None)),
+	("kmc", (None, None)),
+	("kmd", (None, None)),
+	("kme", (None, None)),
+	("kmf", (None, None)),
+	("kmg", (None, None)),
+	("kmh", (None, None)),
+	("kmi", (None, None)),
+	("kmj", (None, None)),
+	("kmk", (None, None)),
+	("kml", (None, None)),
+	("kmm", (None, None)),
+	("kmn", (None, None)),
+	("kmo", (None, None)),
+	("kmp", (None, None)),
+	("kmq", (None, None)),
+	("kmr", (None, None)),
+	("kms", (None, None)),
+	("kmt", (None, None)),
+	("kmu", (None, None)),
+	("kmv", (None, None)),
+	("kmw", (None, None)),
+	("kmx", (None, None)),
+	("kmy", (None, None)),
+	("kmz", (None, None)),
+	("kn", (None, Some("Knda"))),
+	("kna", (None, None)),
+	("knb", (None, None)),
+	("knc", (None, None)),
+	("knd", (None, None)),
+	("kne", (None, None)),
+	("knf", (None, None)),
+	("kng", (None, None)),
+	("kni", (None, None)),
+	("knj", (None, None)),
+	("knk", (None, None)),
+	("knl", (None, None)),
+	("knm", (None, None)),
+	("knn", (None, None)),
+	("kno", (None, None)),
+	("knp", (None, None)),
+	("knq", (None, None)),
+	("knr", (None, None)),
+	("kns", (None, None)),
+	("knt", (None, None)),
+	("knu", (None, None)),
+	("knv", (None, None)),
+	("knw", (None, None)),
+	("knx", (None, None)),
+	("kny", (None, None)),
+	("knz", (None, None)),
+	("ko", (None, Some("Kore"))),
+	("koa", (None, None)),
+	("koc", (None, None)),
+	("kod", (None, None)),
+	("koe", (None, None)),
+	("kof", (None, None)),
+	("kog", (None, None)),
+	("koh", (None, None)),
+	("koi", (None, None)),
+	("koj", (Some("kwv"), None)),
+	("kok", (None, Some("Deva"))),
+	("kol", (None, None)),
+	("koo", (None, None)),
+	("kop", (None, None)),
+	("koq", (None, None)),
+	("kos", (None, None)),
+	("kot", (None, None)),
+	("kou", (None, None)),
+	("kov", (None, None)),
+	("kow", (None, None)),
+	("kox", (None, None)),
+	("koy", (None, None)),
+	("koz", (None, None)),
+	("kpa", (None, None)),
+	("kpb", (None, None)),
+	("kpc", (None, None)),
+	("kpd", (None, None)),
+	("kpe", (None, None)),
+	("kpf", (None, None)),
+	("kpg", (None, None)),
+	("kph", (None, None)),
+	("kpi", (None, None)),
+	("kpj", (None, None)),
+	("kpk", (None, None)),
+	("kpl", (None, None)),
+	("kpm", (None, None)),
+	("kpn", (None, None)),
+	("kpo", (None, None)),
+	("kpp", (None, None)),
+	("kpq", (None, None)),
+	("kpr", (None, None)),
+	("kps", (None, None)),
+	("kpt", (None, None)),
+	("kpu", (None, None)),
+	("kpv", (None, None)),
+	("kpw", (None, None)),
+	("kpx", (None, None)),
+	("kpy", (None, None)),
+	("kpz", (None, None)),
+	("kqa", (None, None)),
+	("kqb", (None, None)),
+	("kqc", (None, None)),
+	("kqd", (None, None)),
+	("kqe", (None, None)),
+	("kqf", (None, None)),
+	("kqg", (None, None)),
+	("kqh", (None, None)),
+	("kqi", (None, None)),
+	("kqj", (None, None)),
+	("kqk", (None, None)),
+	("kql", (None, None)),
+	("kqm", (None, None)),
+	("kqn", (None, None)),
+	("kqo", (None, None)),
+	("kqp", (None, None)),
+	("kqq", (None, None)),
+	("kqr", (None, None)),
+	("kqs", (None, None)),
+	("kqt", (None, None)),
+	("kqu", (None, None)),
+	("kqv", (None, None)),
+	("kqw", (None, None)),
+	("kqx", (None, None)),
+	("kqy", (None, None)),
+	("kqz", (None, None)),
+	("kr", (None, None)),
+	("kra", (None, None)),
+	("krb", (None, None)),
+	("krc", (None, None)),
+	("krd", (None, None)),
+	("kre", (None, None)),
+	("krf", (None, None)),
+	("krh", (None, None)),
+	("kri", (None, None)),
+	("krj", (None, None)),
+	("krk", (None, None)),
+	("krl", (None, None)),
+	("krm", (Some("bmf"), None)),
+	("krn", (None, None)),
+	("kro", (None, None)),
+	("krp", (None, None)),
+	("krr", (None, None)),
+	("krs", (None, None)),
+	("krt", (None, None)),
+	("kru", (None, None)),
+	("krv", (None, None)),
+	("krw", (None, None)),
+	("krx", (None, None)),
+	("kry", (None, None)),
+	("krz", (None, None)),
+	("ks", (None, None)),
+	("ksa", (None, None)),
+	("ksb", (None, None)),
+	("ksc", (None, None)),
+	("ksd", (None, None)),
+	("kse", (None, None)),
+	("ksf", (None, None)),
+	("ksg", (None, None)),
+	("ksh", (None, None)),
+	("ksi", (None, None)),
+	("ksj", (None, None)),
+	("ksk", (None, None)),
+	("ksl", (None, None)),
+	("ksm", (None, None)),
+	("ksn", (None, None)),
+	("kso", (None, None)),
+	("ksp", (None, None)),
+	("ksq", (None, None)),
+	("ksr", (None, None)),
+	("kss", (None, None)),
+	("kst", (None, None)),
+	("ksu", (None, None)),
+	("ksv", (None, None)),
+	("ksw", (None, None)),
+	("ksx", (None, None)),
+	("ksy", (None, None)),
+	("ksz", (None, None)),
+	("kta", (None, None)),
+	("ktb", (None, None)),
+	("ktc", (None, None)),
+	("ktd", (None, None)),
+	("kte", (None, None)),
+	("ktf", (None, None)),
+	("ktg", (None, None)),
+	("kth", (None, None)),
+	("kti", (None, None)),
+	("ktj", (None, None)),
+	("ktk", (None, None)),
+	("ktl", (None, None)),
+	("ktm", (None, None)),
+	("ktn", (None, None)),
+	("kto", (None, None)),
+	("ktp", (None, None)),
+	("ktq", (None, None)),
+	("ktr", (Some("dtp"), None)),
+	("kts", (None, None)),
+	("ktt", (None, None)),
+	("ktu", (None, None)),
+	("ktv", (None, None)),
+	("ktw", (None, None)),
+	("ktx", (None, None)),
+	("kty", (None, None)),
+	("ktz", (None, None)),
+	("ku", (None, None)),
+	("kub", (None, None)),
+	("kuc", (None, None)),
+	("kud", (None, None)),
+	("kue", (None, None)),
+	("kuf", (None, None)),
+	("kug", (None, None)),
+	("kuh", (None, None)),
+	("kui", (None, None)),
+	("kuj", (None, None)),
+	("kuk", (None, None)),
+	("kul", (None, None)),
+	("kum", (None, None)),
+	("kun", (None, None)),
+	("kuo", (None, None)),
+	("kup", (None, None)),
+	("kuq", (None, None)),
+	("kus", (None, None)),
+	("kut", (None, None)),
+	("kuu", (None, None)),
+	("kuv", (None, None)),
+	("kuw", (None, None)),
+	("kux", (None, None)),
+	("kuy", (None, None)),
+	("kuz", (None, None)),
+	("kv", (None, None)),
+	("kva", (None, None)),
+	("kvb", (None, None)),
+	("kvc", (None, None)),
+	("kvd", (None, None)),
+	("kve", (None, None)),
+	("kvf", (None, None)),
+	("kvg", (None, None)),
+	("kvh", (None, None)),
+	("kvi", (None, None)),
+	("kvj", (None, None)),
+	("kvk", (None, None)),
+	("kvl", (None, None)),
+	("kvm", (None, None)),
+	("kvn", (None, None)),
+	("kvo", (None, None)),
+	("kvp", (None, None)),
+	("kvq", (None, None)),
+	("kvr", (None, None)),
+	("kvs", (Some("gdj"), None)),
+	("kvt", (None, None)),
+	("kvu", (None, None)),
+	("kvv", (None, None)),
+	("kvw", (None, None)),
+	("kvx", (None, None)),
+	("kvy", (None, None)),
+	("kvz", (None, None)),
+	("kw", (None, None)),
+	("kwa", (None, None)),
+	("kwb", (None, None)),
+	("kwc", (None, None)),
+	("kwd", (None, None)),
+	("kwe", (None, None)),
+	("kwf", (None, None)),
+	("kwg", (None, None)),
+	("kwh", (None, None)),
+	("kwi", (None, None)),
+	("kwj", (None, None)),
+	("kwk", (None, None)),
+	("kwl", (None, None)),
+	("kwm", (None, None)),
+	("kwn", (None, None)),
+	("kwo", (None, None)),
+	("kwp", (None, None)),
+	("kwq", (Some("yam"), None)),
+	("kwr", (None, None)),
+	("kws", (None, None)),
+	("kwt", (None, None)),
+	("kwu", (None, None)),
+	("kwv", (None, None)),
+	("kww", (None, None)),
+	("kwx", (None, None)),
+	("kwy", (None, None)),
+	("kwz", (None, None)),
+	("kxa", (None, None)),
+	("kxb", (None, None)),
+	("kxc", (None, None)),
+	("kxd", (None, None)),
+	("kxe", (Some("tvd"), None)),
+	("kxf", (None, None)),
+	("kxh", (None, None)),
+	("kxi", (None, None)),
+	("kxj", (None, None)),
+	("kxk", (None, None)),
+	("kxl", (Some("kru"), None)),
+	("kxm", (None, None)),
+	("kxn", (None, None)),
+	("kxo", (None, None)),
+	("kxp", (None, None)),
+	("kxq", (None, None)),
+	("kxr", (None, None)),
+	("kxs", (None, None)),
+	("kxt", (None, None)),
+	("kxu", (None, None)),
+	("kxv", (None, None)),
+	("kxw", (None, None)),
+	("kxx", (None, None)),
+	("kxy", (None, None)),
+	("kxz", (None, None)),
+	("ky", (None, None)),
+	("kya", (None, None)),
+	("kyb", (None, None)),
+	("kyc", (None, None)),
+	("kyd", (None, None)),
+	("kye", (None, None)),
+	("kyf", (None, None)),
+	("kyg", (None, None)),
+	("kyh", (None, None)),
+	("kyi", (None, None)),
+	("kyj", (None, None)),
+	("kyk", (None, None)),
+	("kyl", (None, None)),
+	("kym", (None, None)),
+	("kyn", (None, None)),
+	("kyo", (None, None)),
+	("kyp", (None, None)),
+	("kyq", (None, None)),
+	("kyr", (None, None)),
+	("kys", (None, None)),
+	("kyt", (None, None)),
+	("kyu", (None, None)),
+	("kyv", (None, None)),
+	("kyw", (None, None)),
+	("kyx", (None, None)),
+	("kyy", (None, None)),
+	("kyz", (None, None)),
+	("kza", (None, None)),
+	("kzb", (None, None)),
+	("kzc", (None, None)),
+	("kzd", (None, None)),
+	("kze", (None, None)),
+	("kzf", (None, None)),
+	("kzg", (None, None)),
+	("kzh", (None, None)),
+	("kzi", (None, None)),
+	("kzj", (Some("dtp"), None)),
+	("kzk", (None, None)),
+	("kzl", (None, None)),
+	("kzm", (None, None)),
+	("kzn", (None, None)),
+	("kzo", (None, None)),
+	("kzp", (None, None)),
+	("kzq", (None, None)),
+	("kzr", (None, None)),
+	("kzs", (None, None)),
+	("kzt", (Some("dtp"), None)),
+	("kzu", (None, None)),
+	("kzv", (None, None)),
+	("kzw", (None, None)),
+	("kzx", (None, None)),
+	("kzy", (None, None)),
+	("kzz", (None, None)),
+	("la", (None, Some("Latn"))),
+	("laa", (None, None)),
+	("lab", (None, None)),
+	("lac", (None, None)),
+	("lad", (None, None)),
+	("lae", (None, None)),
+	("laf", (None, None)),
+	("lag", (None, None)),
+	("lah", (None, None)),
+	("lai", (None, None)),
+	("laj", (None, None)),
+	("lak", (None, None)),
+	("lal", (None, None)),
+	("lam", (None, None)),
+	("lan", (None, None)),
+	("lap", (None, None)),
+	("laq", (None, None)),
+	("lar", (None, None)),
+	("las", (None, None)),
+	("lau", (None, None)),
+	("law", (None, None)),
+	("lax", (None, None)),
+	("lay", (None, None)),
+	("laz", (None, None)),
+	("lb", (None, Some("Latn"))),
+	("lba", (None, None)),
+	("lbb", (None, None)),
+	("lbc", (None, None)),
+	("lbe", (None, None)),
+	("lbf", (None, None)),
+	("lbg", (None, None)),
+	("lbi", (None, None)),
+	("lbj", (None, None)),
+	("lbk", (None, None)),
+	("lbl", (None, None)),
+	("lbm", (None, None)),
+	("lbn", (None, None)),
+	("lbo", (None, None)),
+	("lbq", (None, None)),
+	("lbr", (None, None)),
+	("lbs", (None, None)),
+	("lbt", (None, None)),
+	("lbu", (None, None)),
+	("lbv", (None, None)),
+	("lbw", (None, None)),
+	("lbx", (None, None)),
+	("lby", (None, None)),
+	("lbz", (None, None)),
+	("lcc", (None, None)),
+	("lcd", (None, None)),
+	("lce", (None, None)),
+	("lcf", (None, None)),
+	("lch", (None, None)),
+	("lcl", (None, None)),
+	("lcm", (None, None)),
+	("lcp", (None, None)),
+	("lcq", (None, None)),
+	("lcs", (None, None)),
+	("lda", (None, None)),
+	("ldb", (None, None)),
+	("ldd", (None, None)),
+	("ldg", (None, None)),
+	("ldh", (None, None)),
+	("ldi", (None, None)),
+	("ldj", (None, None)),
+	("ldk", (None, None)),
+	("ldl", (None, None)),
+	("ldm", (None, None)),
+	("ldn", (None, None)),
+	("ldo", (None, None)),
+	("ldp", (None, None)),
+	("ldq", (None, None)),
+	("lea", (None, None)),
+	("leb", (None, None)),
+	("lec", (None, None)),
+	("led", (None, None)),
+	("lee", (None, None)),
+	("lef", (None, None)),
+	("leg", (None, None)),
+	("leh", (None, None)),
+	("lei", (None, None)),
+	("lej", (None, None)),
+	("lek", (None, None)),
+	("lel", (None, None)),
+	("lem", (None, None)),
+	("len", (None, None)),
+	("leo", (None, None)),
+	("lep", (None, None)),
+	("leq", (None, None)),
+	("ler", (None, None)),
+	("les", (None, None)),
+	("let", (None, None)),
+	("leu", (None, None)),
+	("lev", (None, None)),
+	("lew", (None, None)),
+	("lex", (None, None)),
+	("ley", (None, None)),
+	("lez", (None, None)),
+	("lfa", (None, None)),
+	("lfn", (None, None)),
+	("lg", (None, None)),
+	("lga", (None, None)),
+	("lgb", (None, None)),
+	("lgg", (None, None)),
+	("lgh", (None, None)),
+	("lgi", (None, None)),
+	("lgk", (None, None)),
+	("lgl", (None, None)),
+	("lgm", (None, None)),
+	("lgn", (None, None)),
+	("lgq", (None, None)),
+	("lgr", (None, None)),
+	("lgt", (None, None)),
+	("lgu", (None, None)),
+	("lgz", (None, None)),
+	("lha", (None, None)),
+	("lhh", (None, None)),
+	("lhi", (None, None)),
+	("lhl", (None, None)),
+	("lhm", (None, None)),
+	("lhn", (None, None)),
+	("lhp", (None, None)),
+	("lhs", (None, None)),
+	("lht", (None, None)),
+	("lhu", (None, None)),
+	("li", (None, None)),
+	("lia", (None, None)),
+	("lib", (None, None)),
+	("lic", (None, None)),
+	("lid", (None, None)),
+	("lie", (None, None)),
+	("lif", (None, None)),
+	("lig", (None, None)),
+	("lih", (None, None)),
+	("lii", (Some("raq"), None)),
+	("lij", (None, None)),
+	("lik", (None, None)),
+	("lil", (None, None)),
+	("lio", (None, None)),
+	("lip", (None, None)),
+	("liq", (None, None)),
+	("lir", (None, None)),
+	("lis", (None, None)),
+	("liu", (None, None)),
+	("liv", (None, None)),
+	("liw", (None, None)),
+	("lix", (None, None)),
+	("liy", (None, None)),
+	("liz", (None, None)),
+	("lja", (None, None)),
+	("lje", (None, None)),
+	("lji", (None, None)),
+	("ljl", (None, None)),
+	("ljp", (None, None)),
+	("ljw", (None, None)),
+	("ljx", (None, None)),
+	("lka", (None, None)),
+	("lkb", (None, None)),
+	("lkc", (None, None)),
+	("lkd", (None, None)),
+	("lke", (None, None)),
+	("lkh", (None, None)),
+	("lki", (None, None)),
+	("lkj", (None, None)),
+	("lkl", (None, None)),
+	("lkm", (None, None)),
+	("lkn", (None, None)),
+	("lko", (None, None)),
+	("lkr", (None, None)),
+	("lks", (None, None)),
+	("lkt", (None, None)),
+	("lku", (None, None)),
+	("lky", (None, None)),
+	("lla", (None, None)),
+	("llb", (None, None)),
+	("llc", (None, None)),
+	("lld", (None, None)),
+	("lle", (None, None)),
+	("llf", (None, None)),
+	("llg", (None, None)),
+	("llh", (None, None)),
+	("lli", (None, None)),
+	("llj", (None, None)),
+	("llk", (None, None)),
+	("lll", (None, None)),
+	("llm", (None, None)),
+	("lln", (None, None)),
+	("llo", (Some("ngt"), None)),
+	("llp", (None, None)),
+	("llq", (None, None)),
+	("lls", (None, None)),
+	("llu", (None, None)),
+	("llx", (None, None)),
+	("lma", (None, None)),
+	("lmb", (None, None)),
+	("lmc", (None, None)),
+	("lmd", (None, None)),
+	("lme", (None, None)),
+	("lmf", (None, None)),
+	("lmg", (None, None)),
+	("lmh", (None, None)),
+	("lmi", (None, None)),
+	("lmj", (None, None)),
+	("lmk", (None, None)),
+	("lml", (None, None)),
+	("lmm", (Some("rmx"), None)),
+	("lmn", (None, None)),
+	("lmo", (None, None)),
+	("lmp", (None, None)),
+	("lmq", (None, None)),
+	("lmr", (None, None)),
+	("lmu", (None, None)),
+	("lmv", (None, None)),
+	("lmw", (None, None)),
+	("lmx", (None, None)),
+	("lmy", (None, None)),
+	("lmz", (None, None)),
+	("ln", (None, Some("Latn"))),
+	("lna", (None, None)),
+	("lnb", (None, None)),
+	("lnd", (None, None)),
+	("lng", (None, None)),
+	("lnh", (None, None)),
+	("lni", (None, None)),
+	("lnj", (None, None)),
+	("lnl", (None, None)),
+	("lnm", (None, None)),
+	("lnn", (None, None)),
+	("lno", (None, None)),
+	("lns", (None, None)),
+	("lnu", (None, None)),
+	("lnw", (None, None)),
+	("lnz", (None, None)),
+	("lo", (None, Some("Laoo"))),
+	("loa", (None, None)),
+	("lob", (None, None)),
+	("loc", (None, None)),
+	("loe", (None, None)),
+	("lof", (None, None)),
+	("log", (None, None)),
+	("loh", (None, None)),
+	("loi", (None, None)),
+	("loj", (None, None)),
+	("lok", (None, None)),
+	("lol", (None, None)),
+	("lom", (None, None)),
+	("lon", (None, None)),
+	("loo", (None, None)),
+	("lop", (None, None)),
+	("loq", (None, None)),
+	("lor", (None, None)),
+	("los", (None, None)),
+	("lot", (None, None)),
+	("lou", (None, None)),
+	("lov", (None, None)),
+	("low", (None, None)),
+	("lox", (None, None)),
+	("loy", (None, None)),
+	("loz", (None, None)),
+	("lpa", (None, None)),
+	("lpe", (None, None)),
+	("lpn", (None, None)),
+	("lpo", (None, None)),
+	("lpx", (None, None)),
+	("lra", (None, None)),
+	("lrc", (None, None)),
+	("lre", (None, None)),
+	("lrg", (None, None)),
+	("lri", (None, None)),
+	("lrk", (None, None)),
+	("lrl", (None, None)),
+	("lrm", (None, None)),
+	("lrn", (None, None)),
+	("lro", (None, None)),
+	("lrr", (None, None)),
+	("lrt", (None, None)),
+	("lrv", (None, None)),
+	("lrz", (None, None)),
+	("lsa", (None, None)),
+	("lsb", (None, None)),
+	("lsd", (None, None)),
+	("lse", (None, None)),
+	("lsg", (None, None)),
+	("lsh", (None, None)),
+	("lsi", (None, None)),
+	("lsl", (None, None)),
+	("lsm", (None, None)),
+	("lsn", (None, None)),
+	("lso", (None, None)),
+	("lsp", (None, None)),
+	("lsr", (None, None)),
+	("lss", (None, None)),
+	("lst", (None, None)),
+	("lsv", (None, None)),
+	("lsy", (None, None)),
+	("lt", (None, Some("Latn"))),
+	("ltc", (None, None)),
+	("ltg", (None, None)),
+	("lth", (None, None)),
+	("lti", (None, None)),
+	("ltn", (None, None)),
+	("lto", (None, None)),
+	("lts", (None, None)),
+	("ltu", (None, None)),
+	("lu", (None, None)),
+	("lua", (None, None)),
+	("luc", (None, None)),
+	("lud", (None, None)),
+	("lue", (None, None)),
+	("luf", (None, None)),
+	("lui", (None, None)),
+	("luj", (None, None)),
+	("luk", (None, None)),
+	("lul", (None, None)),
+	("lum", (None, None)),
+	("lun", (None, None)),
+	("luo", (None, None)),
+	("lup", (None, None)),
+	("luq", (None, None)),
+	("lur", (None, None)),
+	("lus", (None, None)),
+	("lut", (None, None)),
+	("luu", (None, None)),
+	("luv", (None, None)),
+	("luw", (None, None)),
+	("luy", (None, None)),
+	("luz", (None, None)),
+	("lv", (None, Some("Latn"))),
+	("lva", (None, None)),
+	("lvi", (None, None)),
+	("lvk", (None, None)),
+	("lvs", (None, None)),
+	("lvu", (None, None)),
+	("lwa", (None, None)),
+	("lwe", (None, None)),
+	("lwg", (None, None)),
+	("lwh", (None, None)),
+	("lwl", (None, None)),
+	("lwm", (None, None)),
+	("lwo", (None, None)),
+	("lws", (None, None)),
+	("lwt", (None, None)),
+	("lwu", (None, None)),
+	("lww", (None, None)),
+	("lxm", (None, None)),
+	("lya", (None, None)),
+	("lyg", (None, None)),
+	("lyn", (None, None)),
+	("lzh", (None, None)),
+	("lzl", (None, None)),
+	("lzn", (None, None)),
+	("lzz", (None, None)),
+	("maa", (None, None)),
+	("mab", (None, None)),
+	("mad", (None, None)),
+	("mae", (None, None)),
+	("maf", (None, None)),
+	("mag", (None, None)),
+	("mai", (None, Some("Deva"))),
+	("maj", (None, None)),
+	("mak", (None, None)),
+	("mam", (None, None)),
+	("man", (None, None)),
+	("map", (None, None)),
+	("maq", (None, None)),
+	("mas", (None, None)),
+	("mat", (None, None)),
+	("mau", (None, None)),
+	("mav", (None, None)),
+	("maw", (None, None)),
+	("max", (None, None)),
+	("maz", (None, None)),
+	("mba", (None, None)),
+	("mbb", (None, None)),
+	("mbc", (None, None)),
+	("mbd", (None, None)),
+	("mbe", (None, None)),
+	("mbf", (None, None)),
+	("mbh", (None, None)),
+	("mbi", (None, None)),
+	("mbj", (None, None)),
+	("mbk", (None, None)),
+	("mbl", (None, None)),
+	("mbm", (None, None)),
+	("mbn", (None, None)),
+	("mbo", (None, None)),
+	("mbp", (None, None)),
+	("mbq", (None, None)),
+	("mbr", (None, None)),
+	("mbs", (None, None)),
+	("mbt", (None, None)),
+	("mbu", (None, None)),
+	("mbv", (None, None)),
+	("mbw", (None, None)),
+	("mbx", (None, None)),
+	("mby", (None, None)),
+	("mbz", (None, None)),
+	("mca", (None, None)),
+	("mcb", (None, None)),
+	("mcc", (None, None)),
+	("mcd", (None, None)),
+	("mce", (None, None)),
+	("mcf", (None, None)),
+	("mcg", (None, None)),
+	("mch", (None, None)),
+	("mci", (None, None)),
+	("mcj", (None, None)),
+	("mck", (None, None)),
+	("mcl", (None, None)),
+	("mcm", (None, None)),
+	("mcn", (None, None)),
+	("mco", (None, None)),
+	("mcp", (None, None)),
+	("mcq", (None, None)),
+	("mcr", (None, None)),
+	("mcs", (None, None)),
+	("mct", (None, None)),
+	("mcu", (None, None)),
+	("mcv", (None, None)),
+	("mcw", (None, None)),
+	("mcx", (None, None)),
+	("mcy", (None, None)),
+	("mcz", (None, None)),
+	("mda", (None, None)),
+	("mdb", (None, None)),
+	("mdc", (None, None)),
+	("mdd", (None, None)),
+	("mde", (None, None)),
+	("mdf", (None, None)),
+	("mdg", (None, None)),
+	("mdh", (None, None)),
+	("mdi", (None, None)),
+	("mdj", (None, None)),
+	("mdk", (None, None)),
+	("mdl", (None, None)),
+	("mdm", (None, None)),
+	("mdn", (None, None)),
+	("mdp", (None, None)),
+	("mdq", (None, None)),
+	("mdr", (None, None)),
+	("mds", (None, None)),
+	("mdt", (None, None)),
+	("mdu", (None, None)),
+	("mdv", (None, None)),
+	("mdw", (None, None)),
+	("mdx", (None, None)),
+	("mdy", (None, None)),
+	("mdz", (None, None)),
+	("mea", (None, None)),
+	("meb", (None, None)),
+	("mec", (None, None)),
+	("med", (None, None)),
+	("mee", (None, None)),
+	("mef", (None, None)),
+	("meg", (Some("cir"), None)),
+	("meh", (None, None)),
+	("mei", (None, None)),
+	("mej", (None, None)),
+	("mek", (None, None)),
+	("mel", (None, None)),
+	("mem", (None, None)),
+	("men", (None, Some("Latn"))),
+	("meo", (None, None)),
+	("mep", (None, None)),
+	("meq", (None, None)),
+	("mer", (None, None)),
+	("mes", (None, None)),
+	("met", (None, None)),
+	("meu", (None, None)),
+	("mev", (None, None)),
+	("mew", (None, None)),
+	("mey", (None, None)),
+	("mez", (None, None)),
+	("mfa", (None, None)),
+	("mfb", (None, None)),
+	("mfc", (None, None)),
+	("mfd", (None, None)),
+	("mfe", (None, None)),
+	("mff", (None, None)),
+	("mfg", (None, None)),
+	("mfh", (None, None)),
+	("mfi", (None, None)),
+	("mfj", (None, None)),
+	("mfk", (None, None)),
+	("mfl", (None, None)),
+	("mfm", (None, None)),
+	("mfn", (None, None)),
+	("mfo", (None, None)),
+	("mfp", (None, None)),
+	("mfq", (None, None)),
+	("mfr", (None, None)),
+	("mfs", (None, None)),
+	("mft", (None, None)),
+	("mfu", (None, None)),
+	("mfv", (None, None)),
+	("mfw", (None, None)),
+	("mfx", (None, None)),
+	("mfy", (None, None)),
+	("mfz", (None, None)),
+	("mg", (None, Some("Latn"))),
+	("mga", (None, None)),
+	("mgb", (None, None)),
+	("mgc", (None, None)),
+	("mgd", (None, None)),
+	("mge", (None, None)),
+	("mgf", (None, None)),
+	("mgg", (None, None)),
+	("mgh", (None, None)),
+	("mgi", (None, None)),
+	("mgj", (None, None)),
+	("mgk", (None, None)),
+	("mgl", (None, None)),
+	("mgm", (None, None)),
+	("mgn", (None, None)),
+	("mgo", (None, None)),
+	("mgp", (None, None)),
+	("mgq", (None, None)),
+	("mgr", (None, None)),
+	("mgs", (None, None)),
+	("mgt", (None, None)),
+	("mgu", (None, None)),
+	("mgv", (None, None)),
+	("mgw", (None, None)),
+	("mgx", (None, None)),
+	("mgy", (None, None)),
+	("mgz", (None, None)),
+	("mh", (None, Some("Latn"))),
+	("mha", (None, None)),
+	("mhb", (None, None)),
+	("mhc", (None, None)),
+	("mhd", (None, None)),
+	("mhe", (None, None)),
+	("mhf", (None, None)),
+	("mhg", (None, None)),
+	("mhh", (None, None)),
+	("mhi", (None, None)),
+	("mhj", (None, None)),
+	("mhk", (None, None)),
+	("mhl", (None, None)),
+	("mhm", (None, None)),
+	("mhn", (None, None)),
+	("mho", (None, None)),
+	("mhp", (None, None)),
+	("mhq", (None, None)),
+	("mhr", (None, None)),
+	("mhs", (None, None)),
+	("mht", (None, None)),
+	("mhu", (None, None)),
+	("mhw", (None, None)),
+	("mhx", (None, None)),
+	("mhy", (None, None)),
+	("mhz", (None, None)),
+	("mi", (None, None)),
+	("mia", (None, None)),
+	("mib", (None, None)),
+	("mic", (None, None)),
+	("mid", (None, None)),
+	("mie", (None, None)),
+	("mif", (None, None)),
+	("mig", (None, None)),
+	("mih", (None, None)),
+	("mii", (None, None)),
+	("mij", (None, None)),
+	("mik", (None, None)),
+	("mil", (None, None)),
+	("mim", (None, None)),
+	("min", (None, None)),
+	("mio", (None, None)),
+	("mip", (None, None)),
+	("miq", (None, None)),
+	("mir", (None, None)),
+	("mis", (None, None)),
+	("mit", (None, None)),
+	("miu", (None, None)),
+	("miw", (None, None)),
+	("mix", (None, None)),
+	("miy", (None, None)),
+	("miz", (None, None)),
+	("mja", (None, None)),
+	("mjb", (None, None)),
+	("mjc", (None, None)),
+	("mjd", (None, None)),
+	("mje", (None, None)),
+	("mjg", (None, None)),
+	("mjh", (None, None)),
+	("mji", (None, None)),
+	("mjj", (None, None)),
+	("mjk", (None, None)),
+	("mjl", (None, None)),
+	("mjm", (None, None)),
+	("mjn", (None, None)),
+	("mjo", (None, None)),
+	("mjp", (None, None)),
+	("mjq", (None, None)),
+	("mjr", (None, None)),
+	("mjs", (None, None)),
+	("mjt", (None, None)),
+	("mju", (None, None)),
+	("mjv", (None, None)),
+	("mjw", (None, None)),
+	("mjx", (None, None)),
+	("mjy", (None, None)),
+	("mjz", (None, None)),
+	("mk", (None, Some("Cyrl"))),
+	("mka", (None, None)),
+	("mkb", (None, None)),
+	("mkc", (None, None)),
+	("mke", (None, None)),
+	("mkf", (None, None)),
+	("mkg", (None, None)),
+	("mkh", (None, None)),
+	("mki", (None, None)),
+	("mkj", (None, None)),
+	("mkk", (None, None)),
+	("mkl", (None, None)),
+	("mkm", (None, None)),
+	("mkn", (None, None)),
+	("mko", (None, None)),
+	("mkp", (None, None)),
+	("mkq", (None, None)),
+	("mkr", (None, None)),
+	("mks", (None, None)),
+	("mkt", (None, None)),
+	("mku", (None, None)),
+	("mkv", (None, None)),
+	("mkw", (None, None)),
+	("mkx", (None, None)),
+	("mky", (None, None)),
+	("mkz", (None, None)),
+	("ml", (None, Some("Mlym"))),
+	("mla", (None, None)),
+	("mlb", (None, None)),
+	("mlc", (None, None)),
+	("mld", (None, None)),
+	("mle", (None, None)),
+	("mlf", (None, None)),
+	("mlh", (None, None)),
+	("mli", (None, None)),
+	("mlj", (None, None)),
+	("mlk", (None, None)),
+	("mll", (None, None)),
+	("mlm", (None, None)),
+	("mln", (None, None)),
+	("mlo", (None, None)),
+	("mlp", (None, None)),
+	("mlq", (None, None)),
+	("mlr", (None, None)),
+	("mls", (None, None)),
+	("mlu", (None, None)),
+	("mlv", (None, None)),
+	("mlw", (None, None)),
+	("mlx", (None, None)),
+	("mlz", (None, None)),
+	("mma", (None, None)),
+	("mmb", (None, None)),
+	("mmc", (None, None)),
+	("mmd", (None, None)),
+	("mme", (None, None)),
+	("mmf", (None, None)),
+	("mmg", (None, None)),
+	("mmh", (None, None)),
+	("mmi", (None, None)),
+	("mmj", (None, None)),
+	("mmk", (None, None)),
+	("mml", (None, None)),
+	("mmm", (None, None)),
+	("mmn", (None, None)),
+	("mmo", (None, None)),
+	("mmp", (None, None)),
+	("mmq", (None, None)),
+	("mmr", (None, None)),
+	("mmt", (None, None)),
+	("mmu", (None, None)),
+	("mmv", (None, None)),
+	("mmw", (None, None)),
+	("mmx", (None, None)),
+	("mmy", (None, None)),
+	("mmz", (None, None)),
+	("mn", (None, None)),
+	("mna", (None, None)),
+	("mnb", (None, None)),
+	("mnc", (None, None)),
+	("mnd", (None, None)),
+	("mne", (None, None)),
+	("mnf", (None, None)),
+	("mng", (None, None)),
+	("mnh", (None, None)),
+	("mni", (None, None)),
+	("mnj", (None, None)),
+	("mnk", (None, None)),
+	("mnl", (None, None)),
+	("mnm", (None, None)),
+	("mnn", (None, None)),
+	("mno", (None, None)),
+	("mnp", (None, None)),
+	("mnq", (None, None)),
+	("mnr", (None, None)),
+	("mns", (None, None)),
+	("mnt", (None, None)),
+	("mnu", (None, None)),
+	("mnv", (None, None)),
+	("mnw", (None, None)),
+	("mnx", (None, None)),
+	("mny", (None, None)),
+	("mnz", (None, None)),
+	("mo", (Some("ro"), Some("Latn"))),
+	("moa", (None, None)),
+	("moc", (None, None)),
+	("mod", (None, None)),
+	("moe", (None, None)),
+	("mof", (None, None)),
+	("mog", (None, None)),
+	("moh", (None, None)),
+	("moi", (None, None)),
+	("moj", (None, None)),
+	("mok", (None, None)),
+	("mom", (None, None)),
+	("moo", (None, None)),
+	("mop", (None, None)),
+	("moq", (None, None)),
+	("mor", (None, None)),
+	("mos", (None, None)),
+	("mot", (None, None)),
+	("mou", (None, None)),
+	("mov", (None, None)),
+	("mow", (None, None)),
+	("mox", (None, None)),
+	("moy", (None, None)),
+	("moz", (None, None)),
+	("mpa", (None, None)),
+	("mpb", (None, None)),
+	("mpc", (None, None)),
+	("mpd", (None, None)),
+	("mpe", (None, None)),
+	("mpg", (None, None)),
+	("mph", (None, None)),
+	("mpi", (None, None)),
+	("mpj", (None, None)),
+	("mpk", (None, None)),
+	("mpl", (None, None)),
+	("mpm", (None, None)),
+	("mpn", (None, None)),
+	("mpo", (None, None)),
+	("mpp", (None, None)),
+	("mpq", (None, None)),
+	("mpr", (None, None)),
+	("mps", (None, None)),
+	("mpt", (None, None)),
+	("mpu", (None, None)),
+	("mpv", (None, None)),
+	("mpw", (None, None)),
+	("mpx", (None, None)),
+	("mpy", (None, None)),
+	("mpz", (None, None)),
+	("mqa", (None, None)),
+	("mqb", (None, None)),
+	("mqc", (None, None)),
+	("mqe", (None, None)),
+	("mqf", (None, None)),
+	("mqg", (None, None)),
+	("mqh", (None, None)),
+	("mqi", (None, None)),
+	("mqj", (None, None)),
+	("mqk", (None, None)),
+	("mql", (None, None)),
+	("mqm", (None, None)),
+	("mqn", (None, None)),
+	("mqo", (None, None)),
+	("mqp", (None, None)),
+	("mqq", (None, None)),
+	("mqr", (None, None)),
+	("mqs", (None, None)),
+	("mqt", (None, None)),
+	("mqu", (None, None)),
+	("mqv", (None, None)),
+	("mqw", (None, None)),
+	("mqx", (None, None)),
+	("mqy", (None, None)),
+	("mqz", (None, None)),
+	("mr", (None, Some("Deva"))),
+	("mra", (None, None)),
+	("mrb", (None, None)),
+	("mrc", (None, None)),
+	("mrd", (None, None)),
+	("mre", (None, None)),
+	("mrf", (None, None)),
+	("mrg", (None, None)),
+	("mrh", (None, None)),
+	("mrj", (None, None)),
+	("mrk", (None, None)),
+	("mrl", (None, None)),
+	("mrm", (None, None)),
+	("mrn", (None, None)),
+	("mro", (None, None)),
+	("mrp", (None, None)),
+	("mrq", (None, None)),
+	("mrr", (None, None)),
+	("mrs", (None, None)),
+	("mrt", (None, None)),
+	("mru", (None, None)),
+	("mrv", (None, None)),
+	("mrw", (None, None)),
+	("mrx", (None, None)),
+	("mry", (None, None)),
+	("mrz", (None, None)),
+	("ms", (None, Some("Latn"))),
+	("msb", (None, None)),
+	("msc", (None, None)),
+	("msd", (None, None)),
+	("mse", (None, None)),
+	("msf", (None, None)),
+	("msg", (None, None)),
+	("msh", (None, None)),
+	("msi", (None, None)),
+	("msj", (None, None)),
+	("msk", (None, None)),
+	("msl", (None, None)),
+	("msm", (None, None)),
+	("msn", (None, None)),
+	("mso", (None, None)),
+	("msp", (None, None)),
+	("msq", (None, None)),
+	("msr", (None, None)),
+	("mss", (None, None)),
+	("mst", (Some("mry"), None)),
+	("msu", (None, None)),
+	("msv", (None, None)),
+	("msw", (None, None)),
+	("msx", (None, None)),
+	("msy", (None, None)),
+	("msz", (None, None)),
+	("mt", (None, Some("Latn"))),
+	("mta", (None, None)),
+	("mtb", (None, None)),
+	("mtc", (None, None)),
+	("mtd", (None, None)),
+	("mte", (None, None)),
+	("mtf", (None, None)),
+	("mtg", (None, None)),
+	("mth", (None, None)),
+	("mti", (None, None)),
+	("mtj", (None, None)),
+	("mtk", (None, None)),
+	("mtl", (None, None)),
+	("mtm", (None, None)),
+	("mtn", (None, None)),
+	("mto", (None, None)),
+	("mtp", (None, None)),
+	("mtq", (None, None)),
+	("mtr", (None, None)),
+	("mts", (None, None)),
+	("mtt", (None, None)),
+	("mtu", (None, None)),
+	("mtv", (None, None)),
+	("mtw", (None, None)),
+	("mtx", (None, None)),
+	("mty", (None, None)),
+	("mua", (None, None)),
+	("mub", (None, None)),
+	("muc", (None, None)),
+	("mud", (None, None)),
+	("mue", (None, None)),
+	("mug", (None, None)),
+	("muh", (None, None)),
+	("mui", (None, None)),
+	("muj", (None, None)),
+	("muk", (None, None)),
+	("mul", (None, None)),
+	("mum", (None, None)),
+	("mun", (None, None)),
+	("muo", (None, None)),
+	("mup", (None, None)),
+	("muq", (None, None)),
+	("mur", (None, None)),
+	("mus", (None, None)),
+	("mut", (None, None)),
+	("muu", (None, None)),
+	("muv", (None, None)),
+	("mux", (None, None)),
+	("muy", (None, None)),
+	("muz", (None, None)),
+	("mva", (None, None)),
+	("mvb", (None, None)),
+	("mvd", (None, None)),
+	("mve", (None, None)),
+	("mvf", (None, None)),
+	("mvg", (None, None)),
+	("mvh", (None, None)),
+	("mvi", (None, None)),
+	("mvk", (None, None)),
+	("mvl", (None, None)),
+	("mvm", (None, None)),
+	("mvn", (None, None)),
+	("mvo", (None, None)),
+	("mvp", (None, None)),
+	("mvq", (None, None)),
+	("mvr", (None, None)),
+	("mvs", (None, None)),
+	("mvt", (None, None)),
+	("mvu", (None, None)),
+	("mvv", (None, None)),
+	("mvw", (None, None)),
+	("mvx", (None, None)),
+	("mvy", (None, None)),
+	("mvz", (None, None)),
+	("mwa", (None, None)),
+	("mwb", (None, None)),
+	("mwc", (None, None)),
+	("mwd", (None, None)),
+	("mwe", (None, None)),
+	("mwf", (None, None)),
+	("mwg", (None, None)),
+	("mwh", (None, None)),
+	("mwi", (None, None)),
+	("mwj", (Some("vaj"), None)),
+	("mwk", (None, None)),
+	("mwl", (None, None)),
+	("mwm", (None, None)),
+	("mwn", (None, None)),
+	("mwo", (None, None)),
+	("mwp", (None, None)),
+	("mwq", (None, None)),
+	("mwr", (None, None)),
+	("mws", (None, None)),
+	("mwt", (None, None)),
+	("mwu", (None, None)),
+	("mwv", (None, None)),
+	("mww", (None, None)),
+	("mwx", (None, None)),
+	("mwy", (None, None)),
+	("mwz", (None, None)),
+	("mxa", (None, None)),
+	("mxb", (None, None)),
+	("mxc", (None, None)),
+	("mxd", (None, None)),
+	("mxe", (None, None)),
+	("mxf", (None, None)),
+	("mxg", (None, None)),
+	("mxh", (None, None)),
+	("mxi", (None, None)),
+	("mxj", (None, None)),
+	("mxk", (None, None)),
+	("mxl", (None, None)),
+	("mxm", (None, None)),
+	("mxn", (None, None)),
+	("mxo", (None, None)),
+	("mxp", (None, None)),
+	("mxq", (None, None)),
+	("mxr", (None, None)),
+	("mxs", (None, None)),
+	("mxt", (None, None)),
+	("mxu", (None, None)),
+	("mxv", (None, None)),
+	("mxw", (None, None)),
+	("mxx", (None, None)),
+	("mxy", (None, None)),
+	("mxz", (None, None)),
+	("my", (None, Some("Mymr"))),
+	("myb", (None, None)),
+	("myc", (None, None)),
+	("myd", (Some("aog"), None)),
+	("mye", (None, None)),
+	("myf", (None, None)),
+	("myg", (None, None)),
+	("myh", (None, None)),
+	("myi", (None, None)),
+	("myj", (None, None)),
+	("myk", (None, None)),
+	("myl", (None, None)),
+	("mym", (None, None)),
+	("myn", (None, None)),
+	("myo", (None, None)),
+	("myp", (None, None)),
+	("myq", (None, None)),
+	("myr", (None, None)),
+	("mys", (None, None)),
+	("myt", (Some("mry"), None)),
+	("myu", (None, None)),
+	("myv", (None, None)),
+	("myw", (None, None)),
+	("myx", (None, None)),
+	("myy", (None, None)),
+	("myz", (None, None)),
+	("mza", (None, None)),
+	("mzb", (None, None)),
+	("mzc", (None, None)),
+	("mzd", (None, None)),
+	("mze", (None, None)),
+	("mzg", (None, None)),
+	("mzh", (None, None)),
+	("mzi", (None, None)),
+	("mzj", (None, None)),
+	("mzk", (None, None)),
+	("mzl", (None, None)),
+	("mzm", (None, None)),
+	("mzn", (None, None)),
+	("mzo", (None, None)),
+	("mzp", (None, None)),
+	("mzq", (None, None)),
+	("mzr", (None, None)),
+	("mzs", (None, None)),
+	("mzt", (None, None)),
+	("mzu", (None, None)),
+	("mzv", (None, None)),
+	("mzw", (None, None)),
+	("mzx", (None, None)),
+	("mzy", (None, None)),
+	("mzz", (None, None)),
+	("na", (None, Some("Latn"))),
+	("naa", (None, None)),
+	("nab", (None, None)),
+	("nac", (None, None)),
+	("nad", (Some("xny"), None)),
+	("nae", (None, None)),
+	("naf", (None, None)),
+	("nag", (None, None)),
+	("nah", (None, None)),
+	("nai", (None, None)),
+	("naj", (None, None)),
+	("nak", (None, None)),
+	("nal", (None, None)),
+	("nam", (None, None)),
+	("nan", (None, None)),
+	("nao", (None, None)),
+	("nap", (None, None)),
+	("naq", (None, None)),
+	("nar", (None, None)),
+	("nas", (None, None)),
+	("nat", (None, None)),
+	("naw", (None, None)),
+	("nax", (None, None)),
+	("nay", (None, None)),
+	("naz", (None, None)),
+	("nb", (None, Some("Latn"))),
+	("nba", (None, None)),
+	("nbb", (None, None)),
+	("nbc", (None, None)),
+	("nbd", (None, None)),
+	("nbe", (None, None)),
+	("nbf", (None, None)),
+	("nbg", (None, None)),
+	("nbh", (None, None)),
+	("nbi", (None, None)),
+	("nbj", (None, None)),
+	("nbk", (None, None)),
+	("nbm", (None, None)),
+	("nbn", (None, None)),
+	("nbo", (None, None)),
+	("nbp", (None, None)),
+	("nbq", (None, None)),
+	("nbr", (None, None)),
+	("nbs", (None, None)),
+	("nbt", (None, None)),
+	("nbu", (None, None)),
+	("nbv", (None, None)),
+	("nbw", (None, None)),
+	("nbx", (None, None)),
+	("nby", (None, None)),
+	("nca", (None, None)),
+	("ncb", (None, None)),
+	("ncc", (None, None)),
+	("ncd", (None, None)),
+	("nce", (None, None)),
+	("ncf", (None, None)),
+	("ncg", (None, None)),
+	("nch", (None, None)),
+	("nci", (None, None)),
+	("ncj", (None, None)),
+	("nck", (None, None)),
+	("ncl", (None, None)),
+	("ncm", (None, None)),
+	("ncn", (None, None)),
+	("nco", (None, None)),
+	("ncp", (Some("kdz"), None)),
+	("ncq", (None, None)),
+	("ncr", (None, None)),
+	("ncs", (None, None)),
+	("nct", (None, None)),
+	("ncu", (None, None)),
+	("ncx", (None, None)),
+	("ncz", (None, None)),
+	("nd", (None, Some("Latn"))),
+	("nda", (None, None)),
+	("ndb", (None, None)),
+	("ndc", (None, None)),
+	("ndd", (None, None)),
+	("ndf", (None, None)),
+	("ndg", (None, None)),
+	("ndh", (None, None)),
+	("ndi", (None, None)),
+	("ndj", (None, None)),
+	("ndk", (None, None)),
+	("ndl", (None, None)),
+	("ndm", (None, None)),
+	("ndn", (None, None)),
+	("ndp", (None, None)),
+	("ndq", (None, None)),
+	("ndr", (None, None)),
+	("nds", (None, Some("Latn"))),
+	("ndt", (None, None)),
+	("ndu", (None, None)),
+	("ndv", (None, None)),
+	("ndw", (None, None)),
+	("ndx", (None, None)),
+	("ndy", (None, None)),
+	("ndz", (None, None)),
+	("ne", (None, Some("Deva"))),
+	("nea", (None, None)),
+	("neb", (None, None)),
+	("nec", (None, None)),
+	("ned", (None, None)),
+	("nee", (None, None)),
+	("nef", (None, None)),
+	("neg", (None, None)),
+	("neh", (None, None)),
+	("nei", (None, None)),
+	("nej", (None, None)),
+	("nek", (None, None)),
+	("nem", (None, None)),
+	("nen", (None, None)),
+	("neo", (None, None)),
+	("neq", (None, None)),
+	("ner", (None, None)),
+	("nes", (None, None)),
+	("net", (None, None)),
+	("neu", (None, None)),
+	("nev", (None, None)),
+	("new", (None, None)),
+	("nex", (None, None)),
+	("ney", (None, None)),
+	("nez", (None, None)),
+	("nfa", (None, None)),
+	("nfd", (None, None)),
+	("nfl", (None, None)),
+	("nfr", (None, None)),
+	("nfu", (None, None)),
+	("ng", (None, None)),
+	("nga", (None, None)),
+	("ngb", (None, None)),
+	("ngc", (None, None)),
+	("ngd", (None, None)),
+	("nge", (None, None)),
+	("ngf", (None, None)),
+	("ngg", (None, None)),
+	("ngh", (None, None)),
+	("ngi", (None, None)),
+	("ngj", (None, None)),
+	("ngk", (None, None)),
+	("ngl", (None, None)),
+	("ngm", (None, None)),
+	("ngn", (None, None)),
+	("ngo", (None, None)),
+	("ngp", (None, None)),
+	("ngq", (None, None)),
+	("ngr", (None, None)),
+	("ngs", (None, None)),
+	("ngt", (None, None)),
+	("ngu", (None, None)),
+	("ngv", (None, None)),
+	("ngw", (None, None)),
+	("ngx", (None, None)),
+	("ngy", (None, None)),
+	("ngz", (None, None)),
+	("nha", (None, None)),
+	("nhb", (None, None)),
+	("nhc", (None, None)),
+	("nhd", (None, None)),
+	("nhe", (None, None)),
+	("nhf", (None, None)),
+	("nhg", (None, None)),
+	("nhh", (None, None)),
+	("nhi", (None, None)),
+	("nhk", (None, None)),
+	("nhm", (None, None)),
+	("nhn", (None, None)),
+	("nho", (None, None)),
+	("nhp", (None, None)),
+	("nhq", (None, None)),
+	("nhr", (None, None)),
+	("nht", (None, None)),
+	("nhu", (None, None)),
+	("nhv", (None, None)),
+	("nhw", (None, None)),
+	("nhx", (None, None)),
+	("nhy", (None, None)),
+	("nhz", (None, None)),
+	("nia", (None, None)),
+	("nib", (None, None)),
+	("nic", (None, None)),
+	("nid", (None, None)),
+	("nie", (None, None)),
+	("nif", (None, None)),
+	("nig", (None, None)),
+	("nih", (None, None)),
+	("nii", (None, None)),
+	("nij", (None, None)),
+	("nik", (None, None)),
+	("nil", (None, None)),
+	("nim", (None, None)),
+	("nin", (None, None)),
+	("nio", (None, None)),
+	("niq", (None, None)),
+	("nir", (None, None)),
+	("nis", (None, None)),
+	("nit", (None, None)),
+	("niu", (None, Some("Latn"))),
+	("niv", (None, None)),
+	("niw", (None, None)),
+	("nix", (None, None)),
+	("niy", (None, None)),
+	("niz", (None, None)),
+	("nja", (None, None)),
+	("njb", (None, None)),
+	("njd", (None, None)),
+	("njh", (None, None)),
+	("nji", (None, None)),
+	("njj", (None, None)),
+	("njl", (None, None)),
+	("njm", (None, None)),
+	("njn", (None, None)),
+	("njo", (None, None)),
+	("njr", (None, None)),
+	("njs", (None, None)),
+	("njt", (None, None)),
+	("nju", (None, None)),
+	("njx", (None, None)),
+	("njy", (None, None)),
+	("njz", (None, None)),
+	("nka", (None, None)),
+	("nkb", (None, None)),
+	("nkc", (None, None)),
+	("nkd", (None, None)),
+	("nke", (None, None)),
+	("nkf", (None, None)),
+	("nkg", (None, None)),
+	("nkh", (None, None)),
+	("nki", (None, None)),
+	("nkj", (None, None)),
+	("nkk", (None, None)),
+	("nkm", (None, None)),
+	("nkn", (None, None)),
+	("nko", (None, None)),
+	("nkp", (None, None)),
+	("nkq", (None, None)),
+	("nkr", (None, None)),
+	("nks", (None, None)),
+	("nkt", (None, None)),
+	("nku", (None, None)),
+	("nkv", (None, None)),
+	("nkw", (None, None)),
+	("nkx", (None, None)),
+	("nkz", (None, None)),
+	("nl", (None, Some("Latn"))),
+	("nla", (None, None)),
+	("nlc", (None, None)),
+	("nle", (None, None)),
+	("nlg", (None, None)),
+	("nli", (None, None)),
+	("nlj", (None, None)),
+	("nlk", (None, None)),
+	("nll", (None, None)),
+	("nlm", (None, None)),
+	("nln", (None, None)),
+	("nlo", (None, None)),
+	("nlq", (None, None)),
+	("nlr", (None, None)),
+	("nlu", (None, None)),
+	("nlv", (None, None)),
+	("nlw", (None, None)),
+	("nlx", (None, None)),
+	("nly", (None, None)),
+	("nlz", (None, None)),
+	("nma", (None, None)),
+	("nmb", (None, None)),
+	("nmc", (None, None)),
+	("nmd", (None, None)),
+	("nme", (None, None)),
+	("nmf", (None, None)),
+	("nmg", (None, None)),
+	("nmh", (None, None)),
+	("nmi", (None, None)),
+	("nmj", (None, None)),
+	("nmk", (None, None)),
+	("nml", (None, None)),
+	("nmm", (None, None)),
+	("nmn", (None, None)),
+	("nmo", (None, None)),
+	("nmp", (None, None)),
+	("nmq", (None, None)),
+	("nmr", (None, None)),
+	("nms", (None, None)),
+	("nmt", (None, None)),
+	("nmu", (None, None)),
+	("nmv", (None, None)),
+	("nmw", (None, None)),
+	("nmx", (None, None)),
+	("nmy", (None, None)),
+	("nmz", (None, None)),
+	("nn", (None, Some("Latn"))),
+	("nna", (None, None)),
+	("nnb", (None, None)),
+	("nnc", (None, None)),
+	("nnd", (None, None)),
+	("nne", (None, None)),
+	("nnf", (None, None)),
+	("nng", (None, None)),
+	("nnh", (None, None)),
+	("nni", (None, None)),
+	("nnj", (None, None)),
+	("nnk", (None, None)),
+	("nnl", (None, None)),
+	("nnm", (None, None)),
+	("nnn", (None, None)),
+	("nnp", (None, None)),
+	("nnq", (None, None)),
+	("nnr", (None, None)),
+	("nns", (Some("nbr"), None)),
+	("nnt", (None, None)),
+	("nnu", (None, None)),
+	("nnv", (None, None)),
+	("nnw", (None, None)),
+	("nnx", (Some("ngv"), None)),
+	("nny", (None, None)),
+	("nnz", (None, None)),
+	("no", (None, Some("Latn"))),
+	("noa", (None, None)),
+	("noc", (None, None)),
+	("nod", (None, None)),
+	("noe", (None, None)),
+	("nof", (None, None)),
+	("nog", (None, None)),
+	("noh", (None, None)),
+	("noi", (None, None)),
+	("noj", (None, None)),
+	("nok", (None, None)),
+	("nol", (None, None)),
+	("nom", (None, None)),
+	("non", (None, None)),
+	("noo", (None, None)),
+	("nop", (None, None)),
+	("noq", (None, None)),
+	("nos", (None, None)),
+	("not", (None, None)),
+	("nou", (None, None)),
+	("nov", (None, None)),
+	("now", (None, None)),
+	("noy", (None, None)),
+	("noz", (None, None)),
+	("npa", (None, None)),
+	("npb", (None, None)),
+	("npg", (None, None)),
+	("nph", (None, None)),
+	("npi", (None, None)),
+	("npl", (None, None)),
+	("npn", (None, None)),
+	("npo", (None, None)),
+	("nps", (None, None)),
+	("npu", (None, None)),
+	("npx", (None, None)),
+	("npy", (None, None)),
+	("nqg", (None, None)),
+	("nqk", (None, None)),
+	("nql", (None, None)),
+	("nqm", (None, None)),
+	("nqn", (None, None)),
+	("nqo", (None, Some("Nkoo"))),
+	("nqq", (None, None)),
+	("nqt", (None, None)),
+	("nqy", (None, None)),
+	("nr", (None, Some("Latn"))),
+	("nra", (None, None)),
+	("nrb", (None, None)),
+	("nrc", (None, None)),
+	("nre", (None, None)),
+	("nrf", (None, None)),
+	("nrg", (None, None)),
+	("nri", (None, None)),
+	("nrk", (None, None)),
+	("nrl", (None, None)),
+	("nrm", (None, None)),
+	("nrn", (None, None)),
+	("nrp", (None, None)),
+	("nrr", (None, None)),
+	("nrt", (None, None)),
+	("nru", (None, None)),
+	("nrx", (None, None)),
+	("nrz", (None, None)),
+	("nsa", (None, None)),
+	("nsb", (None, None)),
+	("nsc", (None, None)),
+	("nsd", (None, None)),
+	("nse", (None, None)),
+	("nsf", (None, None)),
+	("nsg", (None, None)),
+	("nsh", (None, None)),
+	("nsi", (None, None)),
+	("nsk", (None, None)),
+	("nsl", (None, None)),
+	("nsm", (None, None)),
+	("nsn", (None, None)),
+	("nso", (None, Some("Latn"))),
+	("nsp", (None, None)),
+	("nsq", (None, None)),
+	("nsr", (None, None)),
+	("nss", (None, None)),
+	("nst", (None, None)),
+	("nsu", (None, None)),
+	("nsv", (None, None)),
+	("nsw", (None, None)),
+	("nsx", (None, None)),
+	("nsy", (None, None)),
+	("nsz", (None, None)),
+	("ntd", (None, None)),
+	("nte", (None, None)),
+	("ntg", (None, None)),
+	("nti", (None, None)),
+	("ntj", (None, None)),
+	("ntk", (None, None)),
+	("ntm", (None, None)),
+	("nto", (None, None)),
+	("ntp", (None, None)),
+	("ntr", (None, None)),
+	("nts", (Some("pij"), None)),
+	("ntu", (None, None)),
+	("ntw", (None, None)),
+	("ntx", (None, None)),
+	("nty", (None, None)),
+	("ntz", (None, None)),
+	("nua", (None, None)),
+	("nub", (None, None)),
+	("nuc", (None, None)),
+	("nud", (None, None)),
+	("nue", (None, None)),
+	("nuf", (None, None)),
+	("nug", (None, None)),
+	("nuh", (None, None)),
+	("nui", (None, None)),
+	("nuj", (None, None)),
+	("nuk", (None, None)),
+	("nul", (None, None)),
+	("num", (None, None)),
+	("nun", (None, None)),
+	("nuo", (None, None)),
+	("nup", (None, None)),
+	("nuq", (None, None)),
+	("nur", (None, None)),
+	("nus", (None, None)),
+	("nut", (None, None)),
+	("nuu", (None, None)),
+	("nuv", (None, None)),
+	("nuw", (None, None)),
+	("nux", (None, None)),
+	("nuy", (None, None)),
+	("nuz", (None, None)),
+	("nv", (None, None)),
+	("nvh", (None, None)),
+	("nvm", (None, None)),
+	("nvo", (None, None)),
+	("nwa", (None, None)),
+	("nwb", (None, None)),
+	("nwc", (None, None)),
+	("nwe", (None, None)),
+	("nwg", (None, None)),
+	("nwi", (None, None)),
+	("nwm", (None, None)),
+	("nwo", (None, None)),
+	("nwr", (None, None)),
+	("nwx", (None, None)),
+	("nwy", (None, None)),
+	("nxa", (None, None)),
+	("nxd", (None, None)),
+	("nxe", (None, None)),
+	("nxg", (None, None)),
+	("nxi", (None, None)),
+	("nxk", (None, None)),
+	("nxl", (None, None)),
+	("nxm", (None, None)),
+	("nxn", (None, None)),
+	("nxo", (None, None)),
+	("nxq", (None, None)),
+	("nxr", (None, None)),
+	("nxu", (Some("bpp"), None)),
+	("nxx", (None, None)),
+	("ny", (None, Some("Latn"))),
+	("nyb", (None, None)),
+	("nyc", (None, None)),
+	("nyd", (None, None)),
+	("nye", (None, None)),
+	("nyf", (None, None)),
+	("nyg", (None, None)),
+	("nyh", (None, None)),
+	("nyi", (None, None)),
+	("nyj", (None, None)),
+	("nyk", (None, None)),
+	("nyl", (None, None)),
+	("nym", (None, None)),
+	("nyn", (None, None)),
+	("nyo", (None, None)),
+	("nyp", (None, None)),
+	("nyq", (None, None)),
+	("nyr", (None, None)),
+	("nys", (None, None)),
+	("nyt", (None, None)),
+	("nyu", (None, None)),
+	("nyv", (None, None)),
+	("nyw", (None, None)),
+	("nyx", (None, None)),
+	("nyy", (None, None)),
+	("nza", (None, None)),
+	("nzb", (None, None)),
+	("nzd", (None, None)),
+	("nzi", (None, None)),
+	("nzk", (None, None)),
+	("nzm", (None, None)),
+	("nzs", (None, None)),
+	("nzu", (None, None)),
+	("nzy", (None, None)),
+	("nzz", (None, None)),
+	("oaa", (None, None)),
+	("oac", (None, None)),
+	("oar", (None, None)),
+	("oav", (None, None)),
+	("obi", (None, None)),
+	("obk", (None, None)),
+	("obl", (None, None)),
+	("obm", (None, None)),
+	("obo", (None, None)),
+	("obr", (None, None)),
+	("obt", (None, None)),
+	("obu", (None, None)),
+	("oc", (None, None)),
+	("oca", (None, None)),
+	("och", (None, None)),
+	("ocm", (None, None)),
+	("oco", (None, None)),
+	("ocu", (None, None)),
+	("oda", (None, None)),
+	("odk", (None, None)),
+	("odt", (None, None)),
+	("odu", (None, None)),
+	("ofo", (None, None)),
+	("ofs", (None, None)),
+	("ofu", (None, None)),
+	("ogb", (None, None)),
+	("ogc", (None, None)),
+	("oge", (None, None)),
+	("ogg", (None, None)),
+	("ogo", (None, None)),
+	("ogu", (None, None)),
+	("oht", (None, None)),
+	("ohu", (None, None)),
+	("oia", (None, None)),
+	("oin", (None, None)),
+	("oj", (None, None)),
+	("ojb", (None, None)),
+	("ojc", (None, None)),
+	("ojg", (None, None)),
+	("ojp", (None, None)),
+	("ojs", (None, None)),
+	("ojv", (None, None)),
+	("ojw", (None, None)),
+	("oka", (None, None)),
+	("okb", (None, None)),
+	("okc", (None, None)),
+	("okd", (None, None)),
+	("oke", (None, None)),
+	("okg", (None, None)),
+	("okh", (None, None)),
+	("oki", (None, None)),
+	("okj", (None, None)),
+	("okk", (None, None)),
+	("okl", (None, None)),
+	("okm", (None, None)),
+	("okn", (None, None)),
+	("oko", (None, None)),
+	("okr", (None, None)),
+	("oks", (None, None)),
+	("oku", (None, None)),
+	("okv", (None, None)),
+	("okx", (None, None)),
+	("okz", (None, None)),
+	("ola", (None, None)),
+	("old", (None, None)),
+	("ole", (None, None)),
+	("olk", (None, None)),
+	("olm", (None, None)),
+	("olo", (None, None)),
+	("olr", (None, None)),
+	("olt", (None, None)),
+	("olu", (None, None)),
+	("om", (None, Some("Latn"))),
+	("oma", (None, None)),
+	("omb", (None, None)),
+	("omc", (None, None)),
+	("ome", (None, None)),
+	("omg", (None, None)),
+	("omi", (None, None)),
+	("omk", (None, None)),
+	("oml", (None, None)),
+	("omn", (None, None)),
+	("omo", (None, None)),
+	("omp", (None, None)),
+	("omq", (None, None)),
+	("omr", (None, None)),
+	("omt", (None, None)),
+	("omu", (None, None)),
+	("omv", (None, None)),
+	("omw", (None, None)),
+	("omx", (None, None)),
+	("omy", (None, None)),
+	("ona", (None, None)),
+	("onb", (None, None)),
+	("one", (None, None)),
+	("ong", (None, None)),
+	("oni", (None, None)),
+	("onj", (None, None)),
+	("onk", (None, None)),
+	("onn", (None, None)),
+	("ono", (None, None)),
+	("onp", (None, None)),
+	("onr", (None, None)),
+	("ons", (None, None)),
+	("ont", (None, None)),
+	("onu", (None, None)),
+	("onw", (None, None)),
+	("onx", (None, None)),
+	("ood", (None, None)),
+	("oog", (None, None)),
+	("oon", (None, None)),
+	("oor", (None, None)),
+	("oos", (None, None)),
+	("opa", (None, None)),
+	("opk", (None, None)),
+	("opm", (None, None)),
+	("opo", (None, None)),
+	("opt", (None, None)),
+	("opy", (None, None)),
+	("or", (None, Some("Orya"))),
+	("ora", (None, None)),
+	("orc", (None, None)),
+	("ore", (None, None)),
+	("org", (None, None)),
+	("orh", (None, None)),
+	("orn", (None, None)),
+	("oro", (None, None)),
+	("orr", (None, None)),
+	("ors", (None, None)),
+	("ort", (None, None)),
+	("oru", (None, None)),
+	("orv", (None, None)),
+	("orw", (None, None)),
+	("orx", (None, None)),
+	("ory", (None, None)),
+	("orz", (None, None)),
+	("os", (None, None)),
+	("osa", (None, None)),
+	("osc", (None, None)),
+	("osi", (None, None)),
+	("osn", (None, None)),
+	("oso", (None, None)),
+	("osp", (None, None)),
+	("ost", (None, None)),
+	("osu", (None, None)),
+	("osx", (None, None)),
+	("ota", (None, None)),
+	("otb", (None, None)),
+	("otd", (None, None)),
+	("ote", (None, None)),
+	("oti", (None, None)),
+	("otk", (None, None)),
+	("otl", (None, None)),
+	("otm", (None, None)),
+	("otn", (None, None)),
+	("oto", (None, None)),
+	("otq", (None, None)),
+	("otr", (None, None)),
+	("ots", (None, None)),
+	("ott", (None, None)),
+	("otu", (None, None)),
+	("otw", (None, None)),
+	("otx", (None, None)),
+	("oty", (None, None)),
+	("otz", (None, None)),
+	("oua", (None, None)),
+	("oub", (None, None)),
+	("oue", (None, None)),
+	("oui", (None, None)),
+	("oum", (None, None)),
+	("oun", (Some("vaj"), None)),
+	("ovd", (None, None)),
+	("owi", (None, None)),
+	("owl", (None, None)),
+	("oyb", (None, None)),
+	("oyd", (None, None)),
+	("oym", (None, None)),
+	("oyy", (None, None)),
+	("ozm", (None, None)),
+	("pa", (None, Some("Guru"))),
+	("paa", (None, None)),
+	("pab", (None, None)),
+	("pac", (None, None)),
+	("pad", (None, None)),
+	("pae", (None, None)),
+	("paf", (None, None)),
+	("pag", (None, None)),
+	("pah", (None, None)),
+	("pai", (None, None)),
+	("pak", (None, None)),
+	("pal", (None, None)),
+	("pam", (None, None)),
+	("pao", (None, None)),
+	("pap", (None, None)),
+	("paq", (None, None)),
+	("par", (None, None)),
+	("pas", (None, None)),
+	("pat", (Some("kxr"), None)),
+	("pau", (None, None)),
+	("pav", (None, None)),
+	("paw", (None, None)),
+	("pax", (None, None)),
+	("pay", (None, None)),
+	("paz", (None, None)),
+	("pbb", (None, None)),
+	("pbc", (None, None)),
+	("pbe", (None, None)),
+	("pbf", (None, None)),
+	("pbg", (None, None)),
+	("pbh", (None, None)),
+	("pbi", (None, None)),
+	("pbl", (None, None)),
+	("pbm", (None, None)),
+	("pbn", (None, None)),
+	("pbo", (None, None)),
+	("pbp", (None, None)),
+	("pbr", (None, None)),
+	("pbs", (None, None)),
+	("pbt", (None, None)),
+	("pbu", (None, None)),
+	("pbv", (None, None)),
+	("pby", (None, None)),
+	("pbz", (None, None)),
+	("pca", (None, None)),
+	("pcb", (None, None)),
+	("pcc", (None, None)),
+	("pcd", (None, None)),
+	("pce", (None, None)),
+	("pcf", (None, None)),
+	("pcg", (None, None)),
+	("pch", (None, None)),
+	("pci", (None, None)),
+	("pcj", (None, None)),
+	("pck", (None, None)),
+	("pcl", (None, None)),
+	("pcm", (None, None)),
+	("pcn", (None, None)),
+	("pcp", (None, None)),
+	("pcr", (Some("adx"), None)),
+	("pcw", (None, None)),
+	("pda", (None, None)),
+	("pdc", (None, None)),
+	("pdi", (None, None)),
+	("pdn", (None, None)),
+	("pdo", (None, None)),
+	("pdt", (None, None)),
+	("pdu", (None, None)),
+	("pea", (None, None)),
+	("peb", (None, None)),
+	("ped", (None, None)),
+	("pee", (None, None)),
+	("pef", (None, None)),
+	("peg", (None, None)),
+	("peh", (None, None)),
+	("pei", (None, None)),
+	("pej", (None, None)),
+	("pek", (None, None)),
+	("pel", (None, None)),
+	("pem", (None, None)),
+	("peo", (None, None)),
+	("pep", (None, None)),
+	("peq", (None, None)),
+	("pes", (None, None)),
+	("pev", (None, None)),
+	("pex", (None, None)),
+	("pey", (None, None)),
+	("pez", (None, None)),
+	("pfa", (None, None)),
+	("pfe", (None, None)),
+	("pfl", (None, None)),
+	("pga", (None, None)),
+	("pgd", (None, None)),
+	("pgg", (None, None)),
+	("pgi", (None, None)),
+	("pgk", (None, None)),
+	("pgl", (None, None)),
+	("pgn", (None, None)),
+	("pgs", (None, None)),
+	("pgu", (None, None)),
+	("pgy", (None, None)),
+	("pgz", (None, None)),
+	("pha", (None, None)),
+	("phd", (None, None)),
+	("phg", (None, None)),
+	("phh", (None, None)),
+	("phi", (None, None)),
+	("phk", (None, None)),
+	("phl", (None, None)),
+	("phm", (None, None)),
+	("phn", (None, None)),
+	("pho", (None, None)),
+	("phq", (None, None)),
+	("phr", (None, None)),
+	("pht", (None, None)),
+	("phu", (None, None)),
+	("phv", (None, None)),
+	("phw", (None, None)),
+	("pi", (None, None)),
+	("pia", (None, None)),
+	("pib", (None, None)),
+	("pic", (None, None)),
+	("pid", (None, None)),
+	("pie", (None, None)),
+	("pif", (None, None)),
+	("pig", (None, None)),
+	("pih", (None, None)),
+	("pii", (None, None)),
+	("pij", (None, None)),
+	("pil", (None, None)),
+	("pim", (None, None)),
+	("pin", (None, None)),
+	("pio", (None, None)),
+	("pip", (None, None)),
+	("pir", (None, None)),
+	("pis", (None, None)),
+	("pit", (None, None)),
+	("piu", (None, None)),
+	("piv", (None, None)),
+	("piw", (None, None)),
+	("pix", (None, None)),
+	("piy", (None, None)),
+	("piz", (None, None)),
+	("pjt", (None, None)),
+	("pka", (None, None)),
+	("pkb", (None, None)),
+	("pkc", (None, None)),
+	("pkg", (None, None)),
+	("pkh", (None, None)),
+	("pkn", (None, None)),
+	("pko", (None, None)),
+	("pkp", (None, None)),
+	("pkr", (None, None)),
+	("pks", (None, None)),
+	("pkt", (None, None)),
+	("pku", (None, None)),
+	("pl", (None, Some("Latn"))),
+	("pla", (None, None)),
+	("plb", (None, None)),
+	("plc", (None, None)),
+	("pld", (None, None)),
+	("ple", (None, None)),
+	("plf", (None, None)),
+	("plg", (None, None)),
+	("plh", (None, None)),
+	("plj", (None, None)),
+	("plk", (None, None)),
+	("pll", (None, None)),
+	("pln", (None, None)),
+	("plo", (None, None)),
+	("plp", (None, None)),
+	("plq", (None, None)),
+	("plr", (None, None)),
+	("pls", (None, None)),
+	("plt", (None, None)),
+	("plu", (None, None)),
+	("plv", (None, None)),
+	("plw", (None, None)),
+	("ply", (None, None)),
+	("plz", (None, None)),
+	("pma", (None, None)),
+	("pmb", (None, None)),
+	("pmc", (Some("huw"), None)),
+	("pmd", (None, None)),
+	("pme", (None, None)),
+	("pmf", (None, None)),
+	("pmh", (None, None)),
+	("pmi", (None, None)),
+	("pmj", (None, None)),
+	("pmk", (None, None)),
+	("pml", (None, None)),
+	("pmm", (None, None)),
+	("pmn", (None, None)),
+	("pmo", (None, None)),
+	("pmq", (None, None)),
+	("pmr", (None, None)),
+	("pms", (None, None)),
+	("pmt", (None, None)),
+	("pmu", (Some("phr"), None)),
+	("pmw", (None, None)),
+	("pmx", (None, None)),
+	("pmy", (None, None)),
+	("pmz", (None, None)),
+	("pna", (None, None)),
+	("pnb", (None, None)),
+	("pnc", (None, None)),
+	("pnd", (None, None)),
+	("pne", (None, None)),
+	("png", (None, None)),
+	("pnh", (None, None)),
+	("pni", (None, None)),
+	("pnj", (None, None)),
+	("pnk", (None, None)),
+	("pnl", (None, None)),
+	("pnm", (None, None)),
+	("pnn", (None, None)),
+	("pno", (None, None)),
+	("pnp", (None, None)),
+	("pnq", (None, None)),
+	("pnr", (None, None)),
+	("pns", (None, None)),
+	("pnt", (None, None)),
+	("pnu", (None, None)),
+	("pnv", (None, None)),
+	("pnw", (None, None)),
+	("pnx", (None, None)),
+	("pny", (None, None)),
+	("pnz", (None, None)),
+	("poc", (None, None)),
+	("pod", (None, None)),
+	("poe", (None, None)),
+	("pof", (None, None)),
+	("pog", (None, None)),
+	("poh", (None, None)),
+	("poi", (None, None)),
+	("pok", (None, None)),
+	("pom", (None, None)),
+	("pon", (None, None)),
+	("poo", (None, None)),
+	("pop", (None, None)),
+	("poq", (None, None)),
+	("pos", (None, None)),
+	("pot", (None, None)),
+	("pov", (None, None)),
+	("pow", (None, None)),
+	("pox", (None, None)),
+	("poy", (None, None)),
+	("poz", (None, None)),
+	("ppa", (Some("bfy"), None)),
+	("ppe", (None, None)),
+	("ppi", (None, None)),
+	("ppk", (None, None)),
+	("ppl", (None, None)),
+	("ppm", (None, None)),
+	("ppn", (None, None)),
+	("ppo", (None, None)),
+	("ppp", (None, None)),
+	("ppq", (None, None)),
+	("ppr", (Some("lcq"), None)),
+	("pps", (None, None)),
+	("ppt", (None, None)),
+	("ppu", (None, None)),
+	("pqa", (None, None)),
+	("pqe", (None, None)),
+	("pqm", (None, None)),
+	("pqw", (None, None)),
+	("pra", (None, None)),
+	("prb", (None, None)),
+	("prc", (None, None)),
+	("prd", (None, None)),
+	("pre", (None, None)),
+	("prf", (None, None)),
+	("prg", (None, None)),
+	("prh", (None, None)),
+	("pri", (None, None)),
+	("prk", (None, None)),
+	("prl", (None, None)),
+	("prm", (None, None)),
+	("prn", (None, None)),
+	("pro", (None, None)),
+	("prp", (None, None)),
+	("prq", (None, None)),
+	("prr", (None, None)),
+	("prs", (None, None)),
+	("prt", (None, None)),
+	("pru", (None, None)),
+	("prw", (None, None)),
+	("prx", (None, None)),
+	("pry", (Some("prt"), None)),
+	("prz", (None, None)),
+	("ps", (None, Some("Arab"))),
+	("psa", (None, None)),
+	("psc", (None, None)),
+	("psd", (None, None)),
+	("pse", (None, None)),
+	("psg", (None, None)),
+	("psh", (None, None)),
+	("psi", (None, None)),
+	("psl", (None, None)),
+	("psm", (None, None)),
+	("psn", (None, None)),
+	("pso", (None, None)),
+	("psp", (None, None)),
+	("psq", (None, None)),
+	("psr", (None, None)),
+	("pss", (None, None)),
+	("pst", (None, None)),
+	("psu", (None, None)),
+	("psw", (None, None)),
+	("psy", (None, None)),
+	("pt", (None, Some("Latn"))),
+	("pta", (None, None)),
+	("pth", (None, None)),
+	("pti", (None, None)),
+	("ptn", (None, None)),
+	("pto", (None, None)),
+	("ptp", (None, None)),
+	("ptq", (None, None)),
+	("ptr", (None, None)),
+	("ptt", (None, None)),
+	("ptu", (None, None)),
+	("ptv", (None, None)),
+	("ptw", (None, None)),
+	("pty", (None, None)),
+	("pua", (None, None)),
+	("pub", (None, None)),
+	("puc", (None, None)),
+	("pud", (None, None)),
+	("pue", (None, None)),
+	("puf", (None, None)),
+	("pug", (None, None)),
+	("pui", (None, None)),
+	("puj", (None, None)),
+	("puk", (None, None)),
+	("pum", (None, None)),
+	("puo", (None, None)),
+	("pup", (None, None)),
+	("puq", (None, None)),
+	("pur", (None, None)),
+	("put", (None, None)),
+	("puu", (None, None)),
+	("puw", (None, None)),
+	("pux", (None, None)),
+	("puy", (None, None)),
+	("puz", (Some("pub"), None)),
+	("pwa", (None, None)),
+	("pwb", (None, None)),
+	("pwg", (None, None)),
+	("pwi", (None, None)),
+	("pwm", (None, None)),
+	("pwn", (None, None)),
+	("pwo", (None, None)),
+	("pwr", (None, None)),
+	("pww", (None, None)),
+	("pxm", (None, None)),
+	("pye", (None, None)),
+	("pym", (None, None)),
+	("pyn", (None, None)),
+	("pys", (None, None)),
+	("pyu", (None, None)),
+	("pyx", (None, None)),
+	("pyy", (None, None)),
+	("pzn", (None, None)),
+	("qu", (None, Some("Latn"))),
+	("qua", (None, None)),
+	("qub", (None, None)),
+	("quc", (None, None)),
+	("qud", (None, None)),
+	("quf", (None, None)),
+	("qug", (None, None)),
+	("quh", (None, None)),
+	("qui", (None, None)),
+	("quk", (None, None)),
+	("qul", (None, None)),
+	("qum", (None, None)),
+	("qun", (None, None)),
+	("qup", (None, None)),
+	("quq", (None, None)),
+	("qur", (None, None)),
+	("qus", (None, None)),
+	("quv", (None, None)),
+	("quw", (None, None)),
+	("qux", (None, None)),
+	("quy", (None, None)),
+	("quz", (None, None)),
+	("qva", (None, None)),
+	("qvc", (None, None)),
+	("qve", (None, None)),
+	("qvh", (None, None)),
+	("qvi", (None, None)),
+	("qvj", (None, None)),
+	("qvl", (None, None)),
+	("qvm", (None, None)),
+	("qvn", (None, None)),
+	("qvo", (None, None)),
+	("qvp", (None, None)),
+	("qvs", (None, None)),
+	("qvw", (None, None)),
+	("qvy", (None, None)),
+	("qvz", (None, None)),
+	("qwa", (None, None)),
+	("qwc", (None, None)),
+	("qwe", (None, None)),
+	("qwh", (None, None)),
+	("qwm", (None, None)),
+	("qws", (None, None)),
+	("qwt", (None, None)),
+	("qxa", (None, None)),
+	("qxc", (None, None)),
+	("qxh", (None, None)),
+	("qxl", (None, None)),
+	("qxn", (None, None)),
+	("qxo", (None, None)),
+	("qxp", (None, None)),
+	("qxq", (None, None)),
+	("qxr", (None, None)),
+	("qxs", (None, None)),
+	("qxt", (None, None)),
+	("qxu", (None, None)),
+	("qxw", (None, None)),
+	("qya", (None, None)),
+	("qyp", (None, None)),
+	("raa", (None, None)),
+	("rab", (None, None)),
+	("rac", (None, None)),
+	("rad", (None, None)),
+	("raf", (None, None)),
+	("rag", (None, None)),
+	("rah", (None, None)),
+	("rai", (None, None)),
+	("raj", (None, None)),
+	("rak", (None, None)),
+	("ral", (None, None)),
+	("ram", (None, None)),
+	("ran", (None, None)),
+	("rao", (None, None)),
+	("rap", (None, None)),
+	("raq", (None, None)),
+	("rar", (None, None)),
+	("ras", (None, None)),
+	("rat", (None, None)),
+	("rau", (None, None)),
+	("rav", (None, None)),
+	("raw", (None, None)),
+	("rax", (None, None)),
+	("ray", (None, None)),
+	("raz", (None, None)),
+	("rbb", (None, None)),
+	("rbk", (None, None)),
+	("rbl", (None, None)),
+	("rbp", (None, None)),
+	("rcf", (None, None)),
+	("rdb", (None, None)),
+	("rea", (None, None)),
+	("reb", (None, None)),
+	("ree", (None, None)),
+	("reg", (None, None)),
+	("rei", (None, None)),
+	("rej", (None, None)),
+	("rel", (None, None)),
+	("rem", (None, None)),
+	("ren", (None, None)),
+	("rer", (None, None)),
+	("res", (None, None)),
+	("ret", (None, None)),
+	("rey", (None, None)),
+	("rga", (None, None)),
+	("rge", (None, None)),
+	("rgk", (None, None)),
+	("rgn", (None, None)),
+	("rgr", (None, None)),
+	("rgs", (None, None)),
+	("rgu", (None, None)),
+	("rhg", (None, None)),
+	("rhp", (None, None)),
+	("ria", (None, None)),
+	("rie", (None, None)),
+	("rif", (None, None)),
+	("ril", (None, None)),
+	("rim", (None, None)),
+	("rin", (None, None)),
+	("rir", (None, None)),
+	("rit", (None, None)),
+	("riu", (None, None)),
+	("rjg", (None, None)),
+	("rji", (None, None)),
+	("rjs", (None, None)),
+	("rka", (None, None)),
+	("rkb", (None, None)),
+	("rkh", (None, None)),
+	("rki", (None, None)),
+	("rkm", (None, None)),
+	("rkt", (None, None)),
+	("rkw", (None, None)),
+	("rm", (None, Some("Latn"))),
+	("rma", (None, None)),
+	("rmb", (None, None)),
+	("rmc", (None, None)),
+	("rmd", (None, None)),
+	("rme", (None, None)),
+	("rmf", (None, None)),
+	("rmg", (None, None)),
+	("rmh", (None, None)),
+	("rmi", (None, None)),
+	("rmk", (None, None)),
+	("rml", (None, None)),
+	("rmm", (None, None)),
+	("rmn", (None, None)),
+	("rmo", (None, None)),
+	("rmp", (None, None)),
+	("rmq", (None, None)),
+	("rmr", (None, None)),
+	("rms", (None, None)),
+	("rmt", (None, None)),
+	("rmu", (None, None)),
+	("rmv", (None, None)),
+	("rmw", (None, None)),
+	("rmx", (None, None)),
+	("rmy", (None, None)),
+	("rmz", (None, None)),
+	("rn", (None, Some("Latn"))),
+	("rna", (None, None)),
+	("rnd", (None, None)),
+	("rng", (None, None)),
+	("rnl", (None, None)),
+	("rnn", (None, None)),
+	("rnp", (None, None)),
+	("rnr", (None, None)),
+	("rnw", (None, None)),
+	("ro", (None, Some("Latn"))),
+	("roa", (None, None)),
+	("rob", (None, None)),
+	("roc", (None, None)),
+	("rod", (None, None)),
+	("roe", (None, None)),
+	("rof", (None, None)),
+	("rog", (None, None)),
+	("rol", (None, None)),
+	("rom", (None, None)),
+	("roo", (None, None)),
+	("rop", (None, None)),
+	("ror", (None, None)),
+	("rou", (None, None)),
+	("row", (None, None)),
+	("rpn", (None, None)),
+	("rpt", (None, None)),
+	("rri", (None, None)),
+	("rro", (None, None)),
+	("rrt", (None, None)),
+	("rsb", (None, None)),
+	("rsi", (None, None)),
+	("rsl", (None, None)),
+	("rsm", (None, None)),
+	("rtc", (None, None)),
+	("rth", (None, None)),
+	("rtm", (None, None)),
+	("rts", (None, None)),
+	("rtw", (None, None)),
+	("ru", (None, Some("Cyrl"))),
+	("rub", (None, None)),
+	("ruc", (None, None)),
+	("rue", (None, None)),
+	("ruf", (None, None)),
+	("rug", (None, None)),
+	("ruh", (None, None)),
+	("rui", (None, None)),
+	("ruk", (None, None)),
+	("ruo", (None, None)),
+	("rup", (None, None)),
+	("ruq", (None, None)),
+	("rut", (None, None)),
+	("ruu", (None, None)),
+	("ruy", (None, None)),
+	("ruz", (None, None)),
+	("rw", (None, Some("Latn"))),
+	("rwa", (None, None)),
+	("rwk", (None, None)),
+	("rwl", (None, None)),
+	("rwm", (None, None)),
+	("rwo", (None, None)),
+	("rwr", (None, None)),
+	("rxd", (None, None)),
+	("rxw", (None, None)),
+	("ryn", (None, None)),
+	("rys", (None, None)),
+	("ryu", (None, None)),
+	("rzh", (None, None)),
+	("sa", (None, None)),
+	("saa", (None, None)),
+	("sab", (None, None)),
+	("sac", (None, None)),
+	("sad", (None, None)),
+	("sae", (None, None)),
+	("saf", (None, None)),
+	("sah", (None, None)),
+	("sai", (None, None)),
+	("saj", (None, None)),
+	("sak", (None, None)),
+	("sal", (None, None)),
+	("sam", (None, None)),
+	("sao", (None, None)),
+	("sap", (None, None)),
+	("saq", (None, None)),
+	("sar", (None, None)),
+	("sas", (None, None)),
+	("sat", (None, None)),
+	("sau", (None, None)),
+	("sav", (None, None)),
+	("saw", (None, None)),
+	("sax", (None, None)),
+	("say", (None, None)),
+	("saz", (None, None)),
+	("sba", (None, None)),
+	("sbb", (None, None)),
+	("sbc", (None, None)),
+	("sbd", (None, None)),
+	("sbe", (None, None)),
+	("sbf", (None, None)),
+	("sbg", (None, None)),
+	("sbh", (None, None)),
+	("sbi", (None, None)),
+	("sbj", (None, None)),
+	("sbk", (None, None)),
+	("sbl", (None, None)),
+	("sbm", (None, None)),
+	("sbn", (None, None)),
+	("sbo", (None, None)),
+	("sbp", (None, None)),
+	("sbq", (None, None)),
+	("sbr", (None, None)),
+	("sbs", (None, None)),
+	("sbt", (None, None)),
+	("sbu", (None, None)),
+	("sbv", (None, None)),
+	("sbw", (None, None)),
+	("sbx", (None, None)),
+	("sby", (None, None)),
+	("sbz", (None, None)),
+	("sc", (None, None)),
+	("sca", (Some("hle"), None)),
+	("scb", (None, None)),
+	("sce", (None, None)),
+	("scf", (None, None)),
+	("scg", (None, None)),
+	("sch", (None, None)),
+	("sci", (None, None)),
+	("sck", (None, None)),
+	("scl", (None, None)),
+	("scn", (None, None)),
+	("sco", (None, None)),
+	("scp", (None, None)),
+	("scq", (None, None)),
+	("scs", (None, None)),
+	("sct", (None, None)),
+	("scu", (None, None)),
+	("scv", (None, None)),
+	("scw", (None, None)),
+	("scx", (None, None)),
+	("sd", (None, None)),
+	("sda", (None, None)),
+	("sdb", (None, None)),
+	("sdc", (None, None)),
+	("sde", (None, None)),
+	("sdf", (None, None)),
+	("sdg", (None, None)),
+	("sdh", (None, None)),
+	("sdj", (None, None)),
+	("sdk", (None, None)),
+	("sdl", (None, None)),
+	("sdm", (None, None)),
+	("sdn", (None, None)),
+	("sdo", (None, None)),
+	("sdp", (None, None)),
+	("sdq", (None, None)),
+	("sdr", (None, None)),
+	("sds", (None, None)),
+	("sdt", (None, None)),
+	("sdu", (None, None)),
+	("sdv", (None, None)),
+	("sdx", (None, None)),
+	("sdz", (None, None)),
+	("se", (None, None)),
+	("sea", (None, None)),
+	("seb", (None, None)),
+	("sec", (None, None)),
+	("sed", (None, None)),
+	("see", (None, None)),
+	("sef", (None, None)),
+	("seg", (None, None)),
+	("seh", (None, None)),
+	("sei", (None, None)),
+	("sej", (None, None)),
+	("sek", (None, None)),
+	("sel", (None, None)),
+	("sem", (None, None)),
+	("sen", (None, None)),
+	("seo", (None, None)),
+	("sep", (None, None)),
+	("seq", (None, None)),
+	("ser", (None, None)),
+	("ses", (None, None)),
+	("set", (None, None)),
+	("seu", (None, None)),
+	("sev", (None, None)),
+	("sew", (None, None)),
+	("sey", (None, None)),
+	("sez", (None, None)),
+	("sfb", (None, None)),
+	("sfe", (None, None)),
+	("sfm", (None, None)),
+	("sfs", (None, None)),
+	("sfw", (None, None)),
+	("sg", (None, Some("Latn"))),
+	("sga", (None, None)),
+	("sgb", (None, None)),
+	("sgc", (None, None)),
+	("sgd", (None, None)),
+	("sge", (None, None)),
+	("sgg", (None, None)),
+	("sgh", (None, None)),
+	("sgi", (None, None)),
+	("sgj", (None, None)),
+	("sgk", (None, None)),
+	("sgl", (None, None)),
+	("sgm", (None, None)),
+	("sgn", (None, None)),
+	("sgo", (None, None)),
+	("sgp", (None, None)),
+	("sgr", (None, None)),
+	("sgs", (None, None)),
+	("sgt", (None, None)),
+	("sgu", (None, None)),
+	("sgw", (None, None)),
+	("sgx", (None, None)),
+	("sgy", (None, None)),
+	("sgz", (None, None)),
+	("sh", (None, None)),
+	("sha", (None, None)),
+	("shb", (None, None)),
+	("shc", (None, None)),
+	("shd", (None, None)),
+	("she", (None, None)),
+	("shg", (None, None)),
+	("shh", (None, None)),
+	("shi", (None, None)),
+	("shj", (None, None)),
+	("shk", (None, None)),
+	("shl", (None, None)),
+	("shm", (None, None)),
+	("shn", (None, None)),
+	("sho", (None, None)),
+	("shp", (None, None)),
+	("shq", (None, None)),
+	("shr", (None, None)),
+	("shs", (None, None)),
+	("sht", (None, None)),
+	("shu", (None, None)),
+	("shv", (None, None)),
+	("shw", (None, None)),
+	("shx", (None, None)),
+	("shy", (None, None)),
+	("shz", (None, None)),
+	("si", (None, Some("Sinh"))),
+	("sia", (None, None)),
+	("sib", (None, None)),
+	("sid", (None, None)),
+	("sie", (None, None)),
+	("sif", (None, None)),
+	("sig", (None, None)),
+	("sih", (None, None)),
+	("sii", (None, None)),
+	("sij", (None, None)),
+	("sik", (None, None)),
+	("sil", (None, None)),
+	("sim", (None, None)),
+	("sio", (None, None)),
+	("sip", (None, None)),
+	("siq", (None, None)),
+	("sir", (None, None)),
+	("sis", (None, None)),
+	("sit", (None, None)),
+	("siu", (None, None)),
+	("siv", (None, None)),
+	("siw", (None, None)),
+	("six", (None, None)),
+	("siy", (None, None)),
+	("siz", (None, None)),
+	("sja", (None, None)),
+	("sjb", (None, None)),
+	("sjd", (None, None)),
+	("sje", (None, None)),
+	("sjg", (None, None)),
+	("sjk", (None, None)),
+	("sjl", (None, None)),
+	("sjm", (None, None)),
+	("sjn", (None, None)),
+	("sjo", (None, None)),
+	("sjp", (None, None)),
+	("sjr", (None, None)),
+	("sjs", (None, None)),
+	("sjt", (None, None)),
+	("sju", (None, None)),
+	("sjw", (None, None)),
+	("sk", (None, Some("Latn"))),
+	("ska", (None, None)),
+	("skb", (None, None)),
+	("skc", (None, None)),
+	("skd", (None, None)),
+	("ske", (None, None)),
+	("skf", (None, None)),
+	("skg", (None, None)),
+	("skh", (None, None)),
+	("ski", (None, None)),
+	("skj", (None, None)),
+	("skk", (Some("oyb"), None)),
+	("skm", (None, None)),
+	("skn", (None, None)),
+	("sko", (None, None)),
+	("skp", (None, None)),
+	("skq", (None, None)),
+	("skr", (None, None)),
+	("sks", (None, None)),
+	("skt", (None, None)),
+	("sku", (None, None)),
+	("skv", (None, None)),
+	("skw", (None, None)),
+	("skx", (None, None)),
+	("sky", (None, None)),
+	("skz", (None, None)),
+	("sl", (None, Some("Latn"))),
+	("sla", (None, None)),
+	("slc", (None, None)),
+	("sld", (None, None)),
+	("sle", (None, None)),
+	("slf", (None, None)),
+	("slg", (None, None)),
+	("slh", (None, None)),
+	("sli", (None, None)),
+	("slj", (None, None)),
+	("sll", (None, None)),
+	("slm", (None, None)),
+	("sln", (None, None)),
+	("slp", (None, None)),
+	("slq", (None, None)),
+	("slr", (None, None)),
+	("sls", (None, None)),
+	("slt", (None, None)),
+	("slu", (None, None)),
+	("slw", (None, None)),
+	("slx", (None, None)),
+	("sly", (None, None)),
+	("slz", (None, None)),
+	("sm", (None, Some("Latn"))),
+	("sma", (None, None)),
+	("smb", (None, None)),
+	("smc", (None, None)),
+	("smd", (None, None)),
+	("smf", (None, None)),
+	("smg", (None, None)),
+	("smh", (None, None)),
+	("smi", (None, None)),
+	("smj", (None, None)),
+	("smk", (None, None)),
+	("sml", (None, None)),
+	("smm", (None, None)),
+	("smn", (None, None)),
+	("smp", (None, None)),
+	("smq", (None, None)),
+	("smr", (None, None)),
+	("sms", (None, None)),
+	("smt", (None, None)),
+	("smu", (None, None)),
+	("smv", (None, None)),
+	("smw", (None, None)),
+	("smx", (None, None)),
+	("smy", (None, None)),
+	("smz", (None, None)),
+	("sn", (None, None)),
+	("snb", (None, None)),
+	("snc", (None, None)),
+	("sne", (None, None)),
+	("snf", (None, None)),
+	("sng", (None, None)),
+	("snh", (None, None)),
+	("sni", (None, None)),
+	("snj", (None, None)),
+	("snk", (None, None)),
+	("snl", (None, None)),
+	("snm", (None, None)),
+	("snn", (None, None)),
+	("sno", (None, None)),
+	("snp", (None, None)),
+	("snq", (None, None)),
+	("snr", (None, None)),
+	("sns", (None, None)),
+	("snu", (None, None)),
+	("snv", (None, None)),
+	("snw", (None, None)),
+	("snx", (None, None)),
+	("sny", (None, None)),
+	("snz", (None, None)),
+	("so", (None, Some("Latn"))),
+	("soa", (None, None)),
+	("sob", (None, None)),
+	("soc", (None, None)),
+	("sod", (None, None)),
+	("soe", (None, None)),
+	("sog", (None, None)),
+	("soh", (None, None)),
+	("soi", (None, None)),
+	("soj", (None, None)),
+	("sok", (None, None)),
+	("sol", (None, None)),
+	("son", (None, None)),
+	("soo", (None, None)),
+	("sop", (None, None)),
+	("soq", (None, None)),
+	("sor", (None, None)),
+	("sos", (None, None)),
+	("sou", (None, None)),
+	("sov", (None, None)),
+	("sow", (None, None)),
+	("sox", (None, None)),
+	("soy", (None, None)),
+	("soz", (None, None)),
+	("spb", (None, None)),
+	("spc", (None, None)),
+	("spd", (None, None)),
+	("spe", (None, None)),
+	("spg", (None, None)),
+	("spi", (None, None)),
+	("spk", (None, None)),
+	("spl", (None, None)),
+	("spm", (None, None)),
+	("spn", (None, None)),
+	("spo", (None, None)),
+	("spp", (None, None)),
+	("spq", (None, None)),
+	("spr", (None, None)),
+	("sps", (None, None)),
+	("spt", (None, None)),
+	("spu", (None, None)),
+	("spv", (None, None)),
+	("spx", (None, None)),
+	("spy", (None, None)),
+	("sq", (None, Some("Latn"))),
+	("sqa", (None, None)),
+	("sqh", (None, None)),
+	("sqj", (None, None)),
+	("sqk", (None, None)),
+	("sqm", (None, None)),
+	("sqn", (None, None)),
+	("sqo", (None, None)),
+	("sqq", (None, None)),
+	("sqr", (None, None)),
+	("sqs", (None, None)),
+	("sqt", (None, None)),
+	("squ", (None, None)),
+	("sqx", (None, None)),
+	("sr", (None, None)),
+	("sra", (None, None)),
+	("srb", (None, None)),
+	("src", (None, None)),
+	("sre", (None, None)),
+	("srf", (None, None)),
+	("srg", (None, None)),
+	("srh", (None, None)),
+	("sri", (None, None)),
+	("srk", (None, None)),
+	("srl", (None, None)),
+	("srm", (None, None)),
+	("srn", (None, None)),
+	("sro", (None, None)),
+	("srq", (None, None)),
+	("srr", (None, None)),
+	("srs", (None, None)),
+	("srt", (None, None)),
+	("sru", (None, None)),
+	("srv", (None, None)),
+	("srw", (None, None)),
+	("srx", (None, None)),
+	("sry", (None, None)),
+	("srz", (None, None)),
+	("ss", (None, Some("Latn"))),
+	("ssa", (None, None)),
+	("ssb", (None, None)),
+	("ssc", (None, None)),
+	("ssd", (None, None)),
+	("sse", (None, None)),
+	("ssf", (None, None)),
+	("ssg", (None, None)),
+	("ssh", (None, None)),
+	("ssi", (None, None)),
+	("ssj", (None, None)),
+	("ssk", (None, None)),
+	("ssl", (None, None)),
+	("ssm", (None, None)),
+	("ssn", (None, None)),
+	("sso", (None, None)),
+	("ssp", (None, None)),
+	("ssq", (None, None)),
+	("ssr", (None, None)),
+	("sss", (None, None)),
+	("sst", (None, None)),
+	("ssu", (None, None)),
+	("ssv", (None, None)),
+	("ssx", (None, None)),
+	("ssy", (None, None)),
+	("ssz", (None, None)),
+	("st", (None, Some("Latn"))),
+	("sta", (None, None)),
+	("stb", (None, None)),
+	("std", (None, None)),
+	("ste", (None, None)),
+	("stf", (None, None)),
+	("stg", (None, None)),
+	("sth", (None, None)),
+	("sti", (None, None)),
+	("stj", (None, None)),
+	("stk", (None, None)),
+	("stl", (None, None)),
+	("stm", (None, None)),
+	("stn", (None, None)),
+	("sto", (None, None)),
+	("stp", (None, None)),
+	("stq", (None, None)),
+	("str", (None, None)),
+	("sts", (None, None)),
+	("stt", (None, None)),
+	("stu", (None, None)),
+	("stv", (None, None)),
+	("stw", (None, None)),
+	("sty", (None, None)),
+	("su", (None, None)),
+	("sua", (None, None)),
+	("sub", (None, None)),
+	("suc", (None, None)),
+	("sue", (None, None)),
+	("sug", (None, None)),
+	("sui", (None, None)),
+	("suj", (None, None)),
+	("suk", (None, None)),
+	("sul", (None, None)),
+	("sum", (None, None)),
+	("suo", (None, None)),
+	("suq", (None, None)),
+	("sur", (None, None)),
+	("sus", (None, None)),
+	("sut", (None, None)),
+	("suv", (None, None)),
+	("suw", (None, None)),
+	("sux", (None, None)),
+	("suy", (None, None)),
+	("suz", (None, None)),
+	("sv", (None, Some("Latn"))),
+	("sva", (None, None)),
+	("svb", (None, None)),
+	("svc", (None, None)),
+	("sve", (None, None)),
+	("svk", (None, None)),
+	("svm", (None, None)),
+	("svr", (None, None)),
+	("svs", (None, None)),
+	("svx", (None, None)),
+	("sw", (None, Some("Latn"))),
+	("swb", (None, None)),
+	("swc", (None, None)),
+	("swf", (None, None)),
+	("swg", (None, None)),
+	("swh", (None, None)),
+	("swi", (None, None)),
+	("swj", (None, None)),
+	("swk", (None, None)),
+	("swl", (None, None)),
+	("swm", (None, None)),
+	("swn", (None, None)),
+	("swo", (None, None)),
+	("swp", (None, None)),
+	("swq", (None, None)),
+	("swr", (None, None)),
+	("sws", (None, None)),
+	("swt", (None, None)),
+	("swu", (None, None)),
+	("swv", (None, None)),
+	("sww", (None, None)),
+	("swx", (None, None)),
+	("swy", (None, None)),
+	("sxb", (None, None)),
+	("sxc", (None, None)),
+	("sxe", (None, None)),
+	("sxg", (None, None)),
+	("sxk", (None, None)),
+	("sxl", (None, None)),
+	("sxm", (None, None)),
+	("sxn", (None, None)),
+	("sxo", (None, None)),
+	("sxr", (None, None)),
+	("sxs", (None, None)),
+	("sxu", (None, None)),
+	("sxw", (None, None)),
+	("sya", (None, None)),
+	("syb", (None, None)),
+	("syc", (None, None)),
+	("syd", (None, None)),
+	("syi", (None, None)),
+	("syk", (None, None)),
+	("syl", (None, None)),
+	("sym", (None, None)),
+	("syn", (None, None)),
+	("syo", (None, None)),
+	("syr", (None, None)),
+	("sys", (None, None)),
+	("syw", (None, None)),
+	("syx", (None, None)),
+	("syy", (None, None)),
+	("sza", (None, None)),
+	("szb", (None, None)),
+	("szc", (None, None)),
+	("szd", (None, None)),
+	("sze", (None, None)),
+	("szg", (None, None)),
+	("szl", (None, None)),
+	("szn", (None, None)),
+	("szp", (None, None)),
+	("szs", (None, None)),
+	("szv", (None, None)),
+	("szw", (None, None)),
+	("szy", (None, None)),
+	("ta", (None, Some("Taml"))),
+	("taa", (None, None)),
+	("tab", (None, None)),
+	("tac", (None, None)),
+	("tad", (None, None)),
+	("tae", (None, None)),
+	("taf", (None, None)),
+	("tag", (None, None)),
+	("tai", (None, None)),
+	("taj", (None, None)),
+	("tak", (None, None)),
+	("tal", (None, None)),
+	("tan", (None, None)),
+	("tao", (None, None)),
+	("tap", (None, None)),
+	("taq", (None, None)),
+	("tar", (None, None)),
+	("tas", (None, None)),
+	("tau", (None, None)),
+	("tav", (None, None)),
+	("taw", (None, None)),
+	("tax", (None, None)),
+	("tay", (None, None)),
+	("taz", (None, None)),
+	("tba", (None, None)),
+	("tbb", (None, None)),
+	("tbc", (None, None)),
+	("tbd", (None, None)),
+	("tbe", (None, None)),
+	("tbf", (None, None)),
+	("tbg", (None, None)),
+	("tbh", (None, None)),
+	("tbi", (None, None)),
+	("tbj", (None, None)),
+	("tbk", (None, None)),
+	("tbl", (None, None)),
+	("tbm", (None, None)),
+	("tbn", (None, None)),
+	("tbo", (None, None)),
+	("tbp", (None, None)),
+	("tbq", (None, None)),
+	("tbr", (None, None)),
+	("tbs", (None, None)),
+	("tbt", (None, None)),
+	("tbu", (None, None)),
+	("tbv", (None, None)),
+	("tbw", (None, None)),
+	("tbx", (None, None)),
+	("tby", (None, None)),
+	("tbz", (None, None)),
+	("tca", (None, None)),
+	("tcb", (None, None)),
+	("tcc", (None, None)),
+	("tcd", (None, None)),
+	("tce", (None, None)),
+	("tcf", (None, None)),
+	("tcg", (None, None)),
+	("tch", (None, None)),
+	("tci", (None, None)),
+	("tck", (None, None)),
+	("tcl", (None, None)),
+	("tcm", (None, None)),
+	("tcn", (None, None)),
+	("tco", (None, None)),
+	("tcp", (None, None)),
+	("tcq", (None, None)),
+	("tcs", (None, None)),
+	("tct", (None, None)),
+	("tcu", (None, None)),
+	("tcw", (None, None)),
+	("tcx", (None, None)),
+	("tcy", (None, None)),
+	("tcz", (None, None)),
+	("tda", (None, None)),
+	("tdb", (None, None)),
+	("tdc", (None, None)),
+	("tdd", (None, None)),
+	("tde", (None, None)),
+	("tdf", (None, None)),
+	("tdg", (None, None)),
+	("tdh", (None, None)),
+	("tdi", (None, None)),
+	("tdj", (None, None)),
+	("tdk", (None, None)),
+	("tdl", (None, None)),
+	("tdm", (None, None)),
+	("tdn", (None, None)),
+	("tdo", (None, None)),
+	("tdq", (None, None)),
+	("tdr", (None, None)),
+	("tds", (None, None)),
+	("tdt", (None, None)),
+	("tdu", (Some("dtp"), None)),
+	("tdv", (None, None)),
+	("tdx", (None, None)),
+	("tdy", (None, None)),
+	("te", (None, Some("Telu"))),
+	("tea", (None, None)),
+	("teb", (None, None)),
+	("tec", (None, None)),
+	("ted", (None, None)),
+	("tee", (None, None)),
+	("tef", (None, None)),
+	("teg", (None, None)),
+	("teh", (None, None)),
+	("tei", (None, None)),
+	("tek", (None, None)),
+	("tem", (None, Some("Latn"))),
+	("ten", (None, None)),
+	("teo", (None, None)),
+	("tep", (None, None)),
+	("teq", (None, None)),
+	("ter", (None, None)),
+	("tes", (None, None)),
+	("tet", (None, None)),
+	("teu", (None, None)),
+	("tev", (None, None)),
+	("tew", (None, None)),
+	("tex", (None, None)),
+	("tey", (None, None)),
+	("tez", (None, None)),
+	("tfi", (None, None)),
+	("tfn", (None, None)),
+	("tfo", (None, None)),
+	("tfr", (None, None)),
+	("tft", (None, None)),
+	("tg", (None, None)),
+	("tga", (None, None)),
+	("tgb", (None, None)),
+	("tgc", (None, None)),
+	("tgd", (None, None)),
+	("tge", (None, None)),
+	("tgf", (None, None)),
+	("tgg", (None, None)),
+	("tgh", (None, None)),
+	("tgi", (None, None)),
+	("tgj", (None, None)),
+	("tgn", (None, None)),
+	("tgo", (None, None)),
+	("tgp", (None, None)),
+	("tgq", (None, None)),
+	("tgr", (None, None)),
+	("tgs", (None, None)),
+	("tgt", (None, None)),
+	("tgu", (None, None)),
+	("tgv", (None, None)),
+	("tgw", (None, None)),
+	("tgx", (None, None)),
+	("tgy", (None, None)),
+	("tgz", (None, None)),
+	("th", (None, Some("Thai"))),
+	("thc", (Some("tpo"), None)),
+	("thd", (None, None)),
+	("the", (None, None)),
+	("thf", (None, None)),
+	("thh", (None, None)),
+	("thi", (None, None)),
+	("thk", (None, None)),
+	("thl", (None, None)),
+	("thm", (None, None)),
+	("thn", (None, None)),
+	("thp", (None, None)),
+	("thq", (None, None)),
+	("thr", (None, None)),
+	("ths", (None, None)),
+	("tht", (None, None)),
+	("thu", (None, None)),
+	("thv", (None, None)),
+	("thw", (Some("ola"), None)),
+	("thx", (Some("oyb"), None)),
+	("thy", (None, None)),
+	("thz", (None, None)),
+	("ti", (None, Some("Ethi"))),
+	("tia", (None, None)),
+	("tic", (None, None)),
+	("tid", (None, None)),
+	("tie", (Some("ras"), None)),
+	("tif", (None, None)),
+	("tig", (None, None)),
+	("tih", (None, None)),
+	("tii", (None, None)),
+	("tij", (None, None)),
+	("tik", (None, None)),
+	("til", (None, None)),
+	("tim", (None, None)),
+	("tin", (None, None)),
+	("tio", (None, None)),
+	("tip", (None, None)),
+	("tiq", (None, None)),
+	("tis", (None, None)),
+	("tit", (None, None)),
+	("tiu", (None, None)),
+	("tiv", (None, None)),
+	("tiw", (None, None)),
+	("tix", (None, None)),
+	("tiy", (None, None)),
+	("tiz", (None, None)),
+	("tja", (None, None)),
+	("tjg", (None, None)),
+	("tji", (None, None)),
+	("tjj", (None, None)),
+	("tjl", (None, None)),
+	("tjm", (None, None)),
+	("tjn", (None, None)),
+	("tjo", (None, None)),
+	("tjp", (None, None)),
+	("tjs", (None, None)),
+	("tju", (None, None)),
+	("tjw", (None, None)),
+	("tk", (None, None)),
+	("tka", (None, None)),
+	("tkb", (None, None)),
+	("tkd", (None, None)),
+	("tke", (None, None)),
+	("tkf", (None, None)),
+	("tkg", (None, None)),
+	("tkk", (Some("twm"), None)),
+	("tkl", (None, Some("Latn"))),
+	("tkm", (None, None)),
+	("tkn", (None, None)),
+	("tkp", (None, None)),
+	("tkq", (None, None)),
+	("tkr", (None, None)),
+	("tks", (None, None)),
+	("tkt", (None, None)),
+	("tku", (None, None)),
+	("tkv", (None, None)),
+	("tkw", (None, None)),
+	("tkx", (None, None)),
+	("tkz", (None, None)),
+	("tl", (None, Some("Latn"))),
+	("tla", (None, None)),
+	("tlb", (None, None)),
+	("tlc", (None, None)),
+	("tld", (None, None)),
+	("tlf", (None, None)),
+	("tlg", (None, None)),
+	("tlh", (None, None)),
+	("tli", (None, None)),
+	("tlj", (None, None)),
+	("tlk", (None, None)),
+	("tll", (None, None)),
+	("tlm", (None, None)),
+	("tln", (None, None)),
+	("tlo", (None, None)),
+	("tlp", (None, None)),
+	("tlq", (None, None)),
+	("tlr", (None, None)),
+	("tls", (None, None)),
+	("tlt", (None, None)),
+	("tlu", (None, None)),
+	("tlv", (None, None)),
+	("tlw", (Some("weo"), None)),
+	("tlx", (None, None)),
+	("tly", (None, None)),
+	("tma", (None, None)),
+	("tmb", (None, None)),
+	("tmc", (None, None)),
+	("tmd", (None, None)),
+	("tme", (None, None)),
+	("tmf", (None, None)),
+	("tmg", (None, None)),
+	("tmh", (None, Some("Latn"))),
+	("tmi", (None, None)),
+	("tmj", (None, None)),
+	("tmk", (None, None)),
+	("tml", (None, None)),
+	("tmm", (None, None)),
+	("tmn", (None, None)),
+	("tmo", (None, None)),
+	("tmp", (Some("tyj"), None)),
+	("tmq", (None, None)),
+	("tmr", (None, None)),
+	("tms", (None, None)),
+	("tmt", (None, None)),
+	("tmu", (None, None)),
+	("tmv", (None, None)),
+	("tmw", (None, None)),
+	("tmy", (None, None)),
+	("tmz", (None, None)),
+	("tn", (None, Some("Latn"))),
+	("tna", (None, None)),
+	("tnb", (None, None)),
+	("tnc", (None, None)),
+	("tnd", (None, None)),
+	("tne", (Some("kak"), None)),
+	("tnf", (Some("prs"), None)),
+	("tng", (None, None)),
+	("tnh", (None, None)),
+	("tni", (None, None)),
+	("tnk", (None, None)),
+	("tnl", (None, None)),
+	("tnm", (None, None)),
+	("tnn", (None, None)),
+	("tno", (None, None)),
+	("tnp", (None, None)),
+	("tnq", (None, None)),
+	("tnr", (None, None)),
+	("tns", (None, None)),
+	("tnt", (None, None)),
+	("tnu", (None, None)),
+	("tnv", (None, None)),
+	("tnw", (None, None)),
+	("tnx", (None, None)),
+	("tny", (None, None)),
+	("tnz", (None, None)),
+	("to", (None, Some("Latn"))),
+	("tob", (None, None)),
+	("toc", (None, None)),
+	("tod", (None, None)),
+	("toe", (None, None)),
+	("tof", (None, None)),
+	("tog", (None, None)),
+	("toh", (None, None)),
+	("toi", (None, None)),
+	("toj", (None, None)),
+	("tol", (None, None)),
+	("tom", (None, None)),
+	("too", (None, None)),
+	("top", (None, None)),
+	("toq", (None, None)),
+	("tor", (None, None)),
+	("tos", (None, None)),
+	("tou", (None, None)),
+	("tov", (None, None)),
+	("tow", (None, None)),
+	("tox", (None, None)),
+	("toy", (None, None)),
+	("toz", (None, None)),
+	("tpa", (None, None)),
+	("tpc", (None, None)),
+	("tpe", (None, None)),
+	("tpf", (None, None)),
+	("tpg", (None, None)),
+	("tpi", (None, Some("Latn"))),
+	("tpj", (None, None)),
+	("tpk", (None, None)),
+	("tpl", (None, None)),
+	("tpm", (None, None)),
+	("tpn", (None, None)),
+	("tpo", (None, None)),
+	("tpp", (None, None)),
+	("tpq", (None, None)),
+	("tpr", (None, None)),
+	("tpt", (None, None)),
+	("tpu", (None, None)),
+	("tpv", (None, None)),
+	("tpw", (None, None)),
+	("tpx", (None, None)),
+	("tpy", (None, None)),
+	("tpz", (None, None)),
+	("tqb", (None, None)),
+	("tql", (None, None)),
+	("tqm", (None, None)),
+	("tqn", (None, None)),
+	("tqo", (None, None)),
+	("tqp", (None, None)),
+	("tqq", (None, None)),
+	("tqr", (None, None)),
+	("tqt", (None, None)),
+	("tqu", (None, None)),
+	("tqw", (None, None)),
+	("tr", (None, Some("Latn"))),
+	("tra", (None, None)),
+	("trb", (None, None)),
+	("trc", (None, None)),
+	("trd", (None, None)),
+	("tre", (None, None)),
+	("trf", (None, None)),
+	("trg", (None, None)),
+	("trh", (None, None)),
+	("tri", (None, None)),
+	("trj", (None, None)),
+	("trk", (None, None)),
+	("trl", (None, None)),
+	("trm", (None, None)),
+	("trn", (None, None)),
+	("tro", (None, None)),
+	("trp", (None, None)),
+	("trq", (None, None)),
+	("trr", (None, None)),
+	("trs", (None, None)),
+	("trt", (None, None)),
+	("tru", (None, None)),
+	("trv", (None, None)),
+	("trw", (None, None)),
+	("trx", (None, None)),
+	("try", (None, None)),
+	("trz", (None, None)),
+	("ts", (None, Some("Latn"))),
+	("tsa", (None, None)),
+	("tsb", (None, None)),
+	("tsc", (None, None)),
+	("tsd", (None, None)),
+	("tse", (None, None)),
+	("tsf", (Some("taj"), None)),
+	("tsg", (None, None)),
+	("tsh", (None, None)),
+	("tsi", (None, None)),
+	("tsj", (None, None)),
+	("tsk", (None, None)),
+	("tsl", (None, None)),
+	("tsm", (None, None)),
+	("tsp", (None, None)),
+	("tsq", (None, None)),
+	("tsr", (None, None)),
+	("tss", (None, None)),
+	("tst", (None, None)),
+	("tsu", (None, None)),
+	("tsv", (None, None)),
+	("tsw", (None, None)),
+	("tsx", (None, None)),
+	("tsy", (None, None)),
+	("tsz", (None, None)),
+	("tt", (None, None)),
+	("tta", (None, None)),
+	("ttb", (None, None)),
+	("ttc", (None, None)),
+	("ttd", (None, None)),
+	("tte", (None, None)),
+	("ttf", (None, None)),
+	("ttg", (None, None)),
+	("tth", (None, None)),
+	("tti", (None, None)),
+	("ttj", (None, None)),
+	("ttk", (None, None)),
+	("ttl", (None, None)),
+	("ttm", (None, None)),
+	("ttn", (None, None)),
+	("tto", (None, None)),
+	("ttp", (None, None)),
+	("ttq", (None, None)),
+	("ttr", (None, None)),
+	("tts", (None, None)),
+	("ttt", (None, None)),
+	("ttu", (None, None)),
+	("ttv", (None, None)),
+	("ttw", (None, None)),
+	("tty", (None, None)),
+	("ttz", (None, None)),
+	("tua", (None, None)),
+	("tub", (None, None)),
+	("tuc", (None, None)),
+	("tud", (None, None)),
+	("tue", (None, None)),
+	("tuf", (None, None)),
+	("tug", (None, None)),
+	("tuh", (None, None)),
+	("tui", (None, None)),
+	("tuj", (None, None)),
+	("tul", (None, None)),
+	("tum", (None, None)),
+	("tun", (None, None)),
+	("tuo", (None, None)),
+	("tup", (None, None)),
+	("tuq", (None, None)),
+	("tus", (None, None)),
+	("tut", (None, None)),
+	("tuu", (None, None)),
+	("tuv", (None, None)),
+	("tuw", (None, None)),
+	("tux", (None, None)),
+	("tuy", (None, None)),
+	("tuz", (None, None)),
+	("tva", (None, None)),
+	("tvd", (None, None)),
+	("tve", (None, None)),
+	("tvk", (None, None)),
+	("tvl", (None, Some("Latn"))),
+	("tvm", (None, None)),
+	("tvn", (None, None)),
+	("tvo", (None, None)),
+	("tvs", (None, None)),
+	("tvt", (None, None)),
+	("tvu", (None, None)),
+	("tvw", (None, None)),
+	("tvx", (None, None)),
+	("tvy", (None, None)),
+	("tw", (None, None)),
+	("twa", (None, None)),
+	("twb", (None, None)),
+	("twc", (None, None)),
+	("twd", (None, None)),
+	("twe", (None, None)),
+	("twf", (None, None)),
+	("twg", (None, None)),
+	("twh", (None, None)),
+	("twl", (None, None)),
+	("twm", (None, None)),
+	("twn", (None, None)),
+	("two", (None, None)),
+	("twp", (None, None)),
+	("twq", (None, None)),
+	("twr", (None, None)),
+	("twt", (None, None)),
+	("twu", (None, None)),
+	("tww", (None, None)),
+	("twx", (None, None)),
+	("twy", (None, None)),
+	("txa", (None, None)),
+	("txb", (None, None)),
+	("txc", (None, None)),
+	("txe", (None, None)),
+	("txg", (None, None)),
+	("txh", (None, None)),
+	("txi", (None, None)),
+	("txj", (None, None)),
+	("txm", (None, None)),
+	("txn", (None, None)),
+	("txo", (None, None)),
+	("txq", (None, None)),
+	("txr", (None, None)),
+	("txs", (None, None)),
+	("txt", (None, None)),
+	("txu", (None, None)),
+	("txx", (None, None)),
+	("txy", (None, None)),
+	("ty", (None, None)),
+	("tya", (None, None)),
+	("tye", (None, None)),
+	("tyh", (None, None)),
+	("tyi", (None, None)),
+	("tyj", (None, None)),
+	("tyl", (None, None)),
+	("tyn", (None, None)),
+	("typ", (None, None)),
+	("tyr", (None, None)),
+	("tys", (None, None)),
+	("tyt", (None, None)),
+	("tyu", (None, None)),
+	("tyv", (None, None)),
+	("tyx", (None, None)),
+	("tyy", (None, None)),
+	("tyz", (None, None)),
+	("tza", (None, None)),
+	("tzh", (None, None)),
+	("tzj", (None, None)),
+	("tzl", (None, None)),
+	("tzm", (None, None)),
+	("tzn", (None, None)),
+	("tzo", (None, None)),
+	("tzx", (None, None)),
+	("uam", (None, None)),
+	("uan", (None, None)),
+	("uar", (None, None)),
+	("uba", (None, None)),
+	("ubi", (None, None)),
+	("ubl", (None, None)),
+	("ubr", (None, None)),
+	("ubu", (None, None)),
+	("uby", (None, None)),
+	("uda", (None, None)),
+	("ude", (None, None)),
+	("udg", (None, None)),
+	("udi", (None, None)),
+	("udj", (None, None)),
+	("udl", (None, None)),
+	("udm", (None, None)),
+	("udu", (None, None)),
+	("ues", (None, None)),
+	("ufi", (None, None)),
+	("ug", (None, None)),
+	("uga", (None, None)),
+	("ugb", (None, None)),
+	("uge", (None, None)),
+	("ugn", (None, None)),
+	("ugo", (None, None)),
+	("ugy", (None, None)),
+	("uha", (None, None)),
+	("uhn", (None, None)),
+	("uis", (None, None)),
+	("uiv", (None, None)),
+	("uji", (None, None)),
+	("uk", (None, Some("Cyrl"))),
+	("uka", (None, None)),
+	("ukg", (None, None)),
+	("ukh", (None, None)),
+	("uki", (None, None)),
+	("ukk", (None, None)),
+	("ukl", (None, None)),
+	("ukp", (None, None)),
+	("ukq", (None, None)),
+	("uks", (None, None)),
+	("uku", (None, None)),
+	("ukv", (None, None)),
+	("ukw", (None, None)),
+	("uky", (None, None)),
+	("ula", (None, None)),
+	("ulb", (None, None)),
+	("ulc", (None, None)),
+	("ule", (None, None)),
+	("ulf", (None, None)),
+	("uli", (None, None)),
+	("ulk", (None, None)),
+	("ull", (None, None)),
+	("ulm", (None, None)),
+	("uln", (None, None)),
+	("ulu", (None, None)),
+	("ulw", (None, None)),
+	("uma", (None, None)),
+	("umb", (None, None)),
+	("umc", (None, None)),
+	("umd", (None, None)),
+	("umg", (None, None)),
+	("umi", (None, None)),
+	("umm", (None, None)),
+	("umn", (None, None)),
+	("umo", (None, None)),
+	("ump", (None, None)),
+	("umr", (None, None)),
+	("ums", (None, None)),
+	("umu", (None, None)),
+	("una", (None, None)),
+	("und", (None, None)),
+	("une", (None, None)),
+	("ung", (None, None)),
+	("uni", (None, None)),
+	("unk", (None, None)),
+	("unm", (None, None)),
+	("unn", (None, None)),
+	("unp", (None, None)),
+	("unr", (None, None)),
+	("unu", (None, None)),
+	("unx", (None, None)),
+	("unz", (None, None)),
+	("uok", (Some("ema"), None)),
+	("upi", (None, None)),
+	("upv", (None, None)),
+	("ur", (None, Some("Arab"))),
+	("ura", (None, None)),
+	("urb", (None, None)),
+	("urc", (None, None)),
+	("ure", (None, None)),
+	("urf", (None, None)),
+	("urg", (None, None)),
+	("urh", (None, None)),
+	("uri", (None, None)),
+	("urj", (None, None)),
+	("urk", (None, None)),
+	("url", (None, None)),
+	("urm", (None, None)),
+	("urn", (None, None)),
+	("uro", (None, None)),
+	("urp", (None, None)),
+	("urr", (None, None)),
+	("urt", (None, None)),
+	("uru", (None, None)),
+	("urv", (None, None)),
+	("urw", (None, None)),
+	("urx", (None, None)),
+	("ury", (None, None)),
+	("urz", (None, None)),
+	("usa", (None, None)),
+	("ush", (None, None)),
+	("usi", (None, None)),
+	("usk", (None, None)),
+	("usp", (None, None)),
+	("uss", (None, None)),
+	("usu", (None, None)),
+	("uta", (None, None)),
+	("ute", (None, None)),
+	("uth", (None, None)),
+	("utp", (None, None)),
+	("utr", (None, None)),
+	("utu", (None, None)),
+	("uum", (None, None)),
+	("uun", (None, None)),
+	("uur", (None, None)),
+	("uuu", (None, None)),
+	("uve", (None, None)),
+	("uvh", (None, None)),
+	("uvl", (None, None)),
+	("uwa", (None, None)),
+	("uya", (None, None)),
+	("uz", (None, None)),
+	("uzn", (None, None)),
+	("uzs", (None, None)),
+	("vaa", (None, None)),
+	("vae", (None, None)),
+	("vaf", (None, None)),
+	("vag", (None, None)),
+	("vah", (None, None)),
+	("vai", (None, None)),
+	("vaj", (None, None)),
+	("val", (None, None)),
+	("vam", (None, None)),
+	("van", (None, None)),
+	("vao", (None, None)),
+	("vap", (None, None)),
+	("var", (None, None)),
+	("vas", (None, None)),
+	("vau", (None, None)),
+	("vav", (None, None)),
+	("vay", (None, None)),
+	("vbb", (None, None)),
+	("vbk", (None, None)),
+	("ve", (None, Some("Latn"))),
+	("vec", (None, None)),
+	("ved", (None, None)),
+	("vel", (None, None)),
+	("vem", (None, None)),
+	("veo", (None, None)),
+	("vep", (None, None)),
+	("ver", (None, None)),
+	("vgr", (None, None)),
+	("vgt", (None, None)),
+	("vi", (None, Some("Latn"))),
+	("vic", (None, None)),
+	("vid", (None, None)),
+	("vif", (None, None)),
+	("vig", (None, None)),
+	("vil", (None, None)),
+	("vin", (None, None)),
+	("vis", (None, None)),
+	("vit", (None, None)),
+	("viv", (None, None)),
+	("vka", (None, None)),
+	("vki", (None, None)),
+	("vkj", (None, None)),
+	("vkk", (None, None)),
+	("vkl", (None, None)),
+	("vkm", (None, None)),
+	("vkn", (None, None)),
+	("vko", (None, None)),
+	("vkp", (None, None)),
+	("vkt", (None, None)),
+	("vku", (None, None)),
+	("vkz", (None, None)),
+	("vlp", (None, None)),
+	("vls", (None, None)),
+	("vma", (None, None)),
+	("vmb", (None, None)),
+	("vmc", (None, None)),
+	("vmd", (None, None)),
+	("vme", (None, None)),
+	("vmf", (None, None)),
+	("vmg", (None, None)),
+	("vmh", (None, None)),
+	("vmi", (None, None)),
+	("vmj", (None, None)),
+	("vmk", (None, None)),
+	("vml", (None, None)),
+	("vmm", (None, None)),
+	("vmp", (None, None)),
+	("vmq", (None, None)),
+	("vmr", (None, None)),
+	("vms", (None, None)),
+	("vmu", (None, None)),
+	("vmv", (None, None)),
+	("vmw", (None, None)),
+	("vmx", (None, None)),
+	("vmy", (None, None)),
+	("vmz", (None, None)),
+	("vnk", (None, None)),
+	("vnm", (None, None)),
+	("vnp", (None, None)),
+	("vo", (None, None)),
+	("vor", (None, None)),
+	("vot", (None, None)),
+	("vra", (None, None)),
+	("vro", (None, None)),
+	("vrs", (None, None)),
+	("vrt", (None, None)),
+	("vsi", (None, None)),
+	("vsl", (None, None)),
+	("vsv", (None, None)),
+	("vto", (None, None)),
+	("vum", (None, None)),
+	("vun", (None, None)),
+	("vut", (None, None)),
+	("vwa", (None, None)),
+	("wa", (None, None)),
+	("waa", (None, None)),
+	("wab", (None, None)),
+	("wac", (None, None)),
+	("wad", (None, None)),
+	("wae", (None, None)),
+	("waf", (None, None)),
+	("wag", (None, None)),
+	("wah", (None, None)),
+	("wai", (None, None)),
+	("waj", (None, None)),
+	("wak", (None, None)),
+	("wal", (None, None)),
+	("wam", (None, None)),
+	("wan", (None, None)),
+	("wao", (None, None)),
+	("wap", (None, None)),
+	("waq", (None, None)),
+	("war", (None, None)),
+	("was", (None, None)),
+	("wat", (None, None)),
+	("wau", (None, None)),
+	("wav", (None, None)),
+	("waw", (None, None)),
+	("wax", (None, None)),
+	("way", (None, None)),
+	("waz", (None, None)),
+	("wba", (None, None)),
+	("wbb", (None, None)),
+	("wbe", (None, None)),
+	("wbf", (None, None)),
+	("wbh", (None, None)),
+	("wbi", (None, None)),
+	("wbj", (None, None)),
+	("wbk", (None, None)),
+	("wbl", (None, None)),
+	("wbm", (None, None)),
+	("wbp", (None, None)),
+	("wbq", (None, None)),
+	("wbr", (None, None)),
+	("wbs", (None, None)),
+	("wbt", (None, None)),
+	("wbv", (None, None)),
+	("wbw", (None, None)),
+	("wca", (None, None)),
+	("wci", (None, None)),
+	("wdd", (None, None)),
+	("wdg", (None, None)),
+	("wdj", (None, None)),
+	("wdk", (None, None)),
+	("wdu", (None, None)),
+	("wdy", (None, None)),
+	("wea", (None, None)),
+	("wec", (None, None)),
+	("wed", (None, None)),
+	("weg", (None, None)),
+	("weh", (None, None)),
+	("wei", (None, None)),
+	("wem", (None, None)),
+	("wen", (None, None)),
+	("weo", (None, None)),
+	("wep", (None, None)),
+	("wer", (None, None)),
+	("wes", (None, None)),
+	("wet", (None, None)),
+	("weu", (None, None)),
+	("wew", (None, None)),
+	("wfg", (None, None)),
+	("wga", (None, None)),
+	("wgb", (None, None)),
+	("wgg", (None, None)),
+	("wgi", (None, None)),
+	("wgo", (None, None)),
+	("wgu", (None, None)),
+	("wgw", (None, None)),
+	("wgy", (None, None)),
+	("wha", (None, None)),
+	("whg", (None, None)),
+	("whk", (None, None)),
+	("whu", (None, None)),
+	("wib", (None, None)),
+	("wic", (None, None)),
+	("wie", (None, None)),
+	("wif", (None, None)),
+	("wig", (None, None)),
+	("wih", (None, None)),
+	("wii", (None, None)),
+	("wij", (None, None)),
+	("wik", (None, None)),
+	("wil", (None, None)),
+	("wim", (None, None)),
+	("win", (None, None)),
+	("wir", (None, None)),
+	("wit", (None, None)),
+	("wiu", (None, None)),
+	("wiv", (None, None)),
+	("wiw", (None, None)),
+	("wiy", (None, None)),
+	("wja", (None, None)),
+	("wji", (None, None)),
+	("wka", (None, None)),
+	("wkb", (None, None)),
+	("wkd", (None, None)),
+	("wkl", (None, None)),
+	("wkr", (None, None)),
+	("wku", (None, None)),
+	("wkw", (None, None)),
+	("wky", (None, None)),
+	("wla", (None, None)),
+	("wlc", (None, None)),
+	("wle", (None, None)),
+	("wlg", (None, None)),
+	("wlh", (None, None)),
+	("wli", (None, None)),
+	("wlk", (None, None)),
+	("wll", (None, None)),
+	("wlm", (None, None)),
+	("wlo", (None, None)),
+	("wlr", (None, None)),
+	("wls", (None, None)),
+	("wlu", (None, None)),
+	("wlv", (None, None)),
+	("wlw", (None, None)),
+	("wlx", (None, None)),
+	("wly", (None, None)),
+	("wma", (None, None)),
+	("wmb", (None, None)),
+	("wmc", (None, None)),
+	("wmd", (None, None)),
+	("wme", (None, None)),
+	("wmg", (None, None)),
+	("wmh", (None, None)),
+	("wmi", (None, None)),
+	("wmm", (None, None)),
+	("wmn", (None, None)),
+	("wmo", (None, None)),
+	("wms", (None, None)),
+	("wmt", (None, None)),
+	("wmw", (None, None)),
+	("wmx", (None, None)),
+	("wnb", (None, None)),
+	("wnc", (None, None)),
+	("wnd", (None, None)),
+	("wne", (None, None)),
+	("wng", (None, None)),
+	("wni", (None, None)),
+	("wnk", (None, None)),
+	("wnm", (None, None)),
+	("wnn", (None, None)),
+	("wno", (None, None)),
+	("wnp", (None, None)),
+	("wnu", (None, None)),
+	("wnw", (None, None)),
+	("wny", (None, None)),
+	("wo", (None, None)),
+	("woa", (None, None)),
+	("wob", (None, None)),
+	("woc", (None, None)),
+	("wod", (None, None)),
+	("woe", (None, None)),
+	("wof", (None, None)),
+	("wog", (None, None)),
+	("woi", (None, None)),
+	("wok", (None, None)),
+	("wom", (None, None)),
+	("won", (None, None)),
+	("woo", (None, None)),
+	("wor", (None, None)),
+	("wos", (None, None)),
+	("wow", (None, None)),
+	("woy", (None, None)),
+	("wpc", (None, None)),
+	("wra", (None, None)),
+	("wrb", (None, None)),
+	("wrd", (None, None)),
+	("wrg", (None, None)),
+	("wrh", (None, None)),
+	("wri", (None, None)),
+	("wrk", (None, None)),
+	("wrl", (None, None)),
+	("wrm", (None, None)),
+	("wrn", (None, None)),
+	("wro", (None, None)),
+	("wrp", (None, None)),
+	("wrr", (None, None)),
+	("wrs", (None, None)),
+	("wru", (None, None)),
+	("wrv", (None, None)),
+	("wrw", (None, None)),
+	("wrx", (None, None)),
+	("wry", (None, None)),
+	("wrz", (None, None)),
+	("wsa", (None, None)),
+	("wsg", (None, None)),
+	("wsi", (None, None)),
+	("wsk", (None, None)),
+	("wsr", (None, None)),
+	("wss", (None, None)),
+	("wsu", (None, None)),
+	("wsv", (None, None)),
+	("wtf", (None, None)),
+	("wth", (None, None)),
+	("wti", (None, None)),
+	("wtk", (None, None)),
+	("wtm", (None, None)),
+	("wtw", (None, None)),
+	("wua", (None, None)),
+	("wub", (None, None)),
+	("wud", (None, None)),
+	("wuh", (None, None)),
+	("wul", (None, None)),
+	("wum", (None, None)),
+	("wun", (None, None)),
+	("wur", (None, None)),
+	("wut", (None, None)),
+	("wuu", (None, None)),
+	("wuv", (None, None)),
+	("wux", (None, None)),
+	("wuy", (None, None)),
+	("wwa", (None, None)),
+	("wwb", (None, None)),
+	("wwo", (None, None)),
+	("wwr", (None, None)),
+	("www", (None, None)),
+	("wxa", (None, None)),
+	("wxw", (None, None)),
+	("wya", (None, None)),
+	("wyb", (None, None)),
+	("wyi", (None, None)),
+	("wym", (None, None)),
+	("wyr", (None, None)),
+	("wyy", (None, None)),
+	("xaa", (None, None)),
+	("xab", (None, None)),
+	("xac", (None, None)),
+	("xad", (None, None)),
+	("xae", (None, None)),
+	("xag", (None, None)),
+	("xai", (None, None)),
+	("xaj", (None, None)),
+	("xak", (None, None)),
+	("xal", (None, None)),
+	("xam", (None, None)),
+	("xan", (None, None)),
+	("xao", (None, None)),
+	("xap", (None, None)),
+	("xaq", (None, None)),
+	("xar", (None, None)),
+	("xas", (None, None)),
+	("xat", (None, None)),
+	("xau", (None, None)),
+	("xav", (None, None)),
+	("xaw", (None, None)),
+	("xay", (None, None)),
+	("xba", (Some("cax"), None)),
+	("xbb", (None, None)),
+	("xbc", (None, None)),
+	("xbd", (None, None)),
+	("xbe", (None, None)),
+	("xbg", (None, None)),
+	("xbi", (None, None)),
+	("xbj", (None, None)),
+	("xbm", (None, None)),
+	("xbn", (None, None)),
+	("xbo", (None, None)),
+	("xbp", (None, None)),
+	("xbr", (None, None)),
+	("xbw", (None, None)),
+	("xbx", (None, None)),
+	("xby", (None, None)),
+	("xcb", (None, None)),
+	("xcc", (None, None)),
+	("xce", (None, None)),
+	("xcg", (None, None)),
+	("xch", (None, None)),
+	("xcl", (None, None)),
+	("xcm", (None, None)),
+	("xcn", (None, None)),
+	("xco", (None, None)),
+	("xcr", (None, None)),
+	("xct", (None, None)),
+	("xcu", (None, None)),
+	("xcv", (None, None)),
+	("xcw", (None, None)),
+	("xcy", (None, None)),
+	("xda", (None, None)),
+	("xdc", (None, None)),
+	("xdk", (None, None)),
+	("xdm", (None, None)),
+	("xdo", (None, None)),
+	("xdy", (None, None)),
+	("xeb", (None, None)),
+	("xed", (None, None)),
+	("xeg", (None, None)),
+	("xel", (None, None)),
+	("xem", (None, None)),
+	("xep", (None, None)),
+	("xer", (None, None)),
+	("xes", (None, None)),
+	("xet", (None, None)),
+	("xeu", (None, None)),
+	("xfa", (None, None)),
+	("xga", (None, None)),
+	("xgb", (None, None)),
+	("xgd", (None, None)),
+	("xgf", (None, None)),
+	("xgg", (None, None)),
+	("xgi", (None, None)),
+	("xgl", (None, None)),
+	("xgm", (None, None)),
+	("xgn", (None, None)),
+	("xgr", (None, None)),
+	("xgu", (None, None)),
+	("xgw", (None, None)),
+	("xh", (None, Some("Latn"))),
+	("xha", (None, None)),
+	("xhc", (None, None)),
+	("xhd", (None, None)),
+	("xhe", (None, None)),
+	("xhr", (None, None)),
+	("xht", (None, None)),
+	("xhu", (None, None)),
+	("xhv", (None, None)),
+	("xia", (Some("acn"), None)),
+	("xib", (None, None)),
+	("xii", (None, None)),
+	("xil", (None, None)),
+	("xin", (None, None)),
+	("xip", (None, None)),
+	("xir", (None, None)),
+	("xis", (None, None)),
+	("xiv", (None, None)),
+	("xiy", (None, None)),
+	("xjb", (None, None)),
+	("xjt", (None, None)),
+	("xka", (None, None)),
+	("xkb", (None, None)),
+	("xkc", (None, None)),
+	("xkd", (None, None)),
+	("xke", (None, None)),
+	("xkf", (None, None)),
+	("xkg", (None, None)),
+	("xkh", (Some("waw"), None)),
+	("xki", (None, None)),
+	("xkj", (None, None)),
+	("xkk", (None, None)),
+	("xkl", (None, None)),
+	("xkn", (None, None)),
+	("xko", (None, None)),
+	("xkp", (None, None)),
+	("xkq", (None, None)),
+	("xkr", (None, None)),
+	("xks", (None, None)),
+	("xkt", (None, None)),
+	("xku", (None, None)),
+	("xkv", (None, None)),
+	("xkw", (None, None)),
+	("xkx", (None, None)),
+	("xky", (None, None)),
+	("xkz", (None, None)),
+	("xla", (None, None)),
+	("xlb", (None, None)),
+	("xlc", (None, None)),
+	("xld", (None, None)),
+	("xle", (None, None)),
+	("xlg", (None, None)),
+	("xli", (None, None)),
+	("xln", (None, None)),
+	("xlo", (None, None)),
+	("xlp", (None, None)),
+	("xls", (None, None)),
+	("xlu", (None, None)),
+	("xly", (None, None)),
+	("xma", (None, None)),
+	("xmb", (None, None)),
+	("xmc", (None, None)),
+	("xmd", (None, None)),
+	("xme", (None, None)),
+	("xmf", (None, None)),
+	("xmg", (None, None)),
+	("xmh", (None, None)),
+	("xmj", (None, None)),
+	("xmk", (None, None)),
+	("xml", (None, None)),
+	("xmm", (None, None)),
+	("xmn", (None, None)),
+	("xmo", (None, None)),
+	("xmp", (None, None)),
+	("xmq", (None, None)),
+	("xmr", (None, None)),
+	("xms", (None, None)),
+	("xmt", (None, None)),
+	("xmu", (None, None)),
+	("xmv", (None, None)),
+	("xmw", (None, None)),
+	("xmx", (None, None)),
+	("xmy", (None, None)),
+	("xmz", (None, None)),
+	("xna", (None, None)),
+	("xnb", (None, None)),
+	("xnd", (None, None)),
+	("xng", (None, None)),
+	("xnh", (None, None)),
+	("xni", (None, None)),
+	("xnj", (None, None)),
+	("xnk", (None, None)),
+	("xnm", (None, None)),
+	("xnn", (None, None)),
+	("xno", (None, None)),
+	("xnq", (None, None)),
+	("xnr", (None, None)),
+	("xns", (None, None)),
+	("xnt", (None, None)),
+	("xnu", (None, None)),
+	("xny", (None, None)),
+	("xnz", (None, None)),
+	("xoc", (None, None)),
+	("xod", (None, None)),
+	("xog", (None, None)),
+	("xoi", (None, None)),
+	("xok", (None, None)),
+	("xom", (None, None)),
+	("xon", (None, None)),
+	("xoo", (None, None)),
+	("xop", (None, None)),
+	("xor", (None, None)),
+	("xow", (None, None)),
+	("xpa", (None, None)),
+	("xpb", (None, None)),
+	("xpc", (None, None)),
+	("xpd", (None, None)),
+	("xpe", (None, None)),
+	("xpf", (None, None)),
+	("xpg", (None, None)),
+	("xph", (None, None)),
+	("xpi", (None, None)),
+	("xpj", (None, None)),
+	("xpk", (None, None)),
+	("xpl", (None, None)),
+	("xpm", (None, None)),
+	("xpn", (None, None)),
+	("xpo", (None, None)),
+	("xpp", (None, None)),
+	("xpq", (None, None)),
+	("xpr", (None, None)),
+	("xps", (None, None)),
+	("xpt", (None, None)),
+	("xpu", (None, None)),
+	("xpv", (None, None)),
+	("xpw", (None, None)),
+	("xpx", (None, None)),
+	("xpy", (None, None)),
+	("xpz", (None, None)),
+	("xqa", (None, None)),
+	("xqt", (None, None)),
+	("xra", (None, None)),
+	("xrb", (None, None)),
+	("xrd", (None, None)),
+	("xre", (None, None)),
+	("xrg", (None, None)),
+	("xri", (None, None)),
+	("xrm", (None, None)),
+	("xrn", (None, None)),
+	("xrq", (Some("dmw"), None)),
+	("xrr", (None, None)),
+	("xrt", (None, None)),
+	("xru", (None, None)),
+	("xrw", (None, None)),
+	("xsa", (None, None)),
+	("xsb", (None, None)),
+	("xsc", (None, None)),
+	("xsd", (None, None)),
+	("xse", (None, None)),
+	("xsh", (None, None)),
+	("xsi", (None, None)),
+	("xsj", (None, None)),
+	("xsl", (None, None)),
+	("xsm", (None, None)),
+	("xsn", (None, None)),
+	("xso", (None, None)),
+	("xsp", (None, None)),
+	("xsq", (None, None)),
+	("xsr", (None, None)),
+	("xss", (None, None)),
+	("xsu", (None, None)),
+	("xsv", (None, None)),
+	("xsy", (None, None)),
+	("xta", (None, None)),
+	("xtb", (None, None)),
+	("xtc", (None, None)),
+	("xtd", (None, None)),
+	("xte", (None, None)),
+	("xtg", (None, None)),
+	("xth", (None, None)),
+	("xti", (None, None)),
+	("xtj", (None, None)),
+	("xtl", (None, None)),
+	("xtm", (None, None)),
+	("xtn", (None, None)),
+	("xto", (None, None)),
+	("xtp", (None, None)),
+	("xtq", (None, None)),
+	("xtr", (None, None)),
+	("xts", (None, None)),
+	("xtt", (None, None)),
+	("xtu", (None, None)),
+	("xtv", (None, None)),
+	("xtw", (None, None)),
+	("xty", (None, None)),
+	("xtz", (None, None)),
+	("xua", (None, None)),
+	("xub", (None, None)),
+	("xud", (None, None)),
+	("xug", (None, None)),
+	("xuj", (None, None)),
+	("xul", (None, None)),
+	("xum", (None, None)),
+	("xun", (None, None)),
+	("xuo", (None, None)),
+	("xup", (None, None)),
+	("xur", (None, None)),
+	("xut", (None, None)),
+	("xuu", (None, None)),
+	("xve", (None, None)),
+	("xvi", (None, None)),
+	("xvn", (None, None)),
+	("xvo", (None, None)),
+	("xvs", (None, None)),
+	("xwa", (None, None)),
+	("xwc", (None, None)),
+	("xwd", (None, None)),
+	("xwe", (None, None)),
+	("xwg", (None, None)),
+	("xwj", (None, None)),
+	("xwk", (None, None)),
+	("xwl", (None, None)),
+	("xwo", (None, None)),
+	("xwr", (None, None)),
+	("xwt", (None, None)),
+	("xww", (None, None)),
+	("xxb", (None, None)),
+	("xxk", (None, None)),
+	("xxm", (None, None)),
+	("xxr", (None, None)),
+	("xxt", (None, None)),
+	("xya", (None, None)),
+	("xyb", (None, None)),
+	("xyj", (None, None)),
+	("xyk", (None, None)),
+	("xyl", (None, None)),
+	("xyt", (None, None)),
+	("xyy", (None, None)),
+	("xzh", (None, None)),
+	("xzm", (None, None)),
+	("xzp", (None, None)),
+	("yaa", (None, None)),
+	("yab", (None, None)),
+	("yac", (None, None)),
+	("yad", (None, None)),
+	("yae", (None, None)),
+	("yaf", (None, None)),
+	("yag", (None, None)),
+	("yah", (None, None)),
+	("yai", (None, None)),
+	("yaj", (None, None)),
+	("yak", (None, None)),
+	("yal", (None, None)),
+	("yam", (None, None)),
+	("yan", (None, None)),
+	("yao", (None, None)),
+	("yap", (None, None)),
+	("yaq", (None, None)),
+	("yar", (None, None)),
+	("yas", (None, None)),
+	("yat", (None, None)),
+	("yau", (None, None)),
+	("yav", (None, None)),
+	("yaw", (None, None)),
+	("yax", (None, None)),
+	("yay", (None, None)),
+	("yaz", (None, None)),
+	("yba", (None, None)),
+	("ybb", (None, None)),
+	("ybd", (Some("rki"), None)),
+	("ybe", (None, None)),
+	("ybh", (None, None)),
+	("ybi", (None, None)),
+	("ybj", (None, None)),
+	("ybk", (None, None)),
+	("ybl", (None, None)),
+	("ybm", (None, None)),
+	("ybn", (None, None)),
+	("ybo", (None, None)),
+	("ybx", (None, None)),
+	("yby", (None, None)),
+	("ych", (None, None)),
+	("ycl", (None, None)),
+	("ycn", (None, None)),
+	("ycp", (None, None)),
+	("yda", (None, None)),
+	("ydd", (None, None)),
+	("yde", (None, None)),
+	("ydg", (None, None)),
+	("ydk", (None, None)),
+	("yds", (None, None)),
+	("yea", (None, None)),
+	("yec", (None, None)),
+	("yee", (None, None)),
+	("yei", (None, None)),
+	("yej", (None, None)),
+	("yel", (None, None)),
+	("yen", (None, None)),
+	("yer", (None, None)),
+	("yes", (None, None)),
+	("yet", (None, None)),
+	("yeu", (None, None)),
+	("yev", (None, None)),
+	("yey", (None, None)),
+	("yga", (None, None)),
+	("ygi", (None, None)),
+	("ygl", (None, None)),
+	("ygm", (None, None)),
+	("ygp", (None, None)),
+	("ygr", (None, None)),
+	("ygs", (None, None)),
+	("ygu", (None, None)),
+	("ygw", (None, None)),
+	("yha", (None, None)),
+	("yhd", (None, None)),
+	("yhl", (None, None)),
+	("yhs", (None, None)),
+	("yi", (None, Some("Hebr"))),
+	("yia", (None, None)),
+	("yif", (None, None)),
+	("yig", (None, None)),
+	("yih", (None, None)),
+	("yii", (None, None)),
+	("yij", (None, None)),
+	("yik", (None, None)),
+	("yil", (None, None)),
+	("yim", (None, None)),
+	("yin", (None, None)),
+	("yip", (None, None)),
+	("yiq", (None, None)),
+	("yir", (None, None)),
+	("yis", (None, None)),
+	("yit", (None, None)),
+	("yiu", (None, None)),
+	("yiv", (None, None)),
+	("yix", (None, None)),
+	("yiy", (None, None)),
+	("yiz", (None, None)),
+	("yka", (None, None)),
+	("ykg", (None, None)),
+	("yki", (None, None)),
+	("ykk", (None, None)),
+	("ykl", (None, None)),
+	("ykm", (None, None)),
+	("ykn", (None, None)),
+	("yko", (None, None)),
+	("ykr", (None, None)),
+	("ykt", (None, None)),
+	("yku", (None, None)),
+	("yky", (None, None)),
+	("yla", (None, None)),
+	("ylb", (None, None)),
+	("yle", (None, None)),
+	("ylg", (None, None)),
+	("yli", (None, None)),
+	("yll", (None, None)),
+	("ylm", (None, None)),
+	("yln", (None, None)),
+	("ylo", (None, None)),
+	("ylr", (None, None)),
+	("ylu", (None, None)),
+	("yly", (None, None)),
+	("yma", (Some("lrr"), None)),
+	("ymb", (None, None)),
+	("ymc", (None, None)),
+	("ymd", (None, None)),
+	("yme", (None, None)),
+	("ymg", (None, None)),
+	("ymh", (None, None)),
+	("ymi", (None, None)),
+	("ymk", (None, None)),
+	("yml", (None, None)),
+	("ymm", (None, None)),
+	("ymn", (None, None)),
+	("ymo", (None, None)),
+	("ymp", (None, None)),
+	("ymq", (None, None)),
+	("ymr", (None, None)),
+	("yms", (None, None)),
+	("ymt", (Some("mtm"), None)),
+	("ymx", (None, None)),
+	("ymz", (None, None)),
+	("yna", (None, None)),
+	("ynd", (None, None)),
+	("yne", (None, None)),
+	("yng", (None, None)),
+	("ynh", (None, None)),
+	("ynk", (None, None)),
+	("ynl", (None, None)),
+	("ynn", (None, None)),
+	("yno", (None, None)),
+	("ynq", (None, None)),
+	("yns", (None, None)),
+	("ynu", (None, None)),
+	("yo", (None, None)),
+	("yob", (None, None)),
+	("yog", (None, None)),
+	("yoi", (None, None)),
+	("yok", (None, None)),
+	("yol", (None, None)),
+	("yom", (None, None)),
+	("yon", (None, None)),
+	("yos", (Some("zom"), None)),
+	("yot", (None, None)),
+	("yox", (None, None)),
+	("yoy", (None, None)),
+	("ypa", (None, None)),
+	("ypb", (None, None)),
+	("ypg", (None, None)),
+	("yph", (None, None)),
+	("ypk", (None, None)),
+	("ypm", (None, None)),
+	("ypn", (None, None)),
+	("ypo", (None, None)),
+	("ypp", (None, None)),
+	("ypz", (None, None)),
+	("yra", (None, None)),
+	("yrb", (None, None)),
+	("yre", (None, None)),
+	("yri", (None, None)),
+	("yrk", (None, None)),
+	("yrl", (None, None)),
+	("yrm", (None, None)),
+	("yrn", (None, None)),
+	("yro", (None, None)),
+	("yrs", (None, None)),
+	("yrw", (None, None)),
+	("yry", (None, None)),
+	("ysc", (None, None)),
+	("ysd", (None, None)),
+	("ysg", (None, None)),
+	("ysl", (None, None)),
+	("ysm", (None, None)),
+	("ysn", (None, None)),
+	("yso", (None, None)),
+	("ysp", (None, None)),
+	("ysr", (None, None)),
+	("yss", (None, None)),
+	("ysy", (None, None)),
+	("yta", (None, None)),
+	("ytl", (None, None)),
+	("ytp", (None, None)),
+	("ytw", (None, None)),
+	("yty", (None, None)),
+	("yua", (None, None)),
+	("yub", (None, None)),
+	("yuc", (None, None)),
+	("yud", (None, None)),
+	("yue", (None, None)),
+	("yuf", (None, None)),
+	("yug", (None, None)),
+	("yui", (None, None)),
+	("yuj", (None, None)),
+	("yuk", (None, None)),
+	("yul", (None, None)),
+	("yum", (None, None)),
+	("yun", (None, None)),
+	("yup", (None, None)),
+	("yuq", (None, None)),
+	("yur", (None, None)),
+	("yut", (None, None)),
+	("yuu", (Some("yug"), None)),
+	("yuw", (None, None)),
+	("yux", (None, None)),
+	("yuy", (None, None)),
+	("yuz", (None, None)),
+	("yva", (None, None)),
+	("yvt", (None, None)),
+	("ywa", (None, None)),
+	("ywg", (None, None)),
+	("ywl", (None, None)),
+	("ywn", (None, None)),
+	("ywq", (None, None)),
+	("ywr", (None, None)),
+	("ywt", (None, None)),
+	("ywu", (None, None)),
+	("yww", (None, None)),
+	("yxa", (None, None)),
+	("yxg", (None, None)),
+	("yxl", (None, None)),
+	("yxm", (None, None)),
+	("yxu", (None, None)),
+	("yxy", (None, None)),
+	("yyr", (None, None)),
+	("yyu", (None, None)),
+	("yyz", (None, None)),
+	("yzg", (None, None)),
+	("yzk", (None, None)),
+	("za", (None, None)),
+	("zaa", (None, None)),
+	("zab", (None, None)),
+	("zac", (None, None)),
+	("zad", (None, None)),
+	("zae", (None, None)),
+	("zaf", (None, None)),
+	("zag", (None, None)),
+	("zah", (None, None)),
+	("zai", (None, None)),
+	("zaj", (None, None)),
+	("zak", (None, None)),
+	("zal", (None, None)),
+	("zam", (None, None)),
+	("zao", (None, None)),
+	("zap", (None, None)),
+	("zaq", (None, None)),
+	("zar", (None, None)),
+	("zas", (None, None)),
+	("zat", (None, None)),
+	("zau", (None, None)),
+	("zav", (None, None)),
+	("zaw", (None, None)),
+	("zax", (None, None)),
+	("zay", (None, None)),
+	("zaz", (None, None)),
+	("zba", (None, None)),
+	("zbc", (None, None)),
+	("zbe", (None, None)),
+	("zbl", (None, Some("Blis"))),
+	("zbt", (None, None)),
+	("zbu", (None, None)),
+	("zbw", (None, None)),
+	("zca", (None, None)),
+	("zch", (None, None)),
+	("zdj", (None, None)),
+	("zea", (None, None)),
+	("zeg", (None, None)),
+	("zeh", (None, None)),
+	("zen", (None, None)),
+	("zga", (None, None)),
+	("zgb", (None, None)),
+	("zgh", (None, None)),
+	("zgm", (None, None)),
+	("zgn", (None, None)),
+	("zgr", (None, None)),
+	("zh", (None, None)),
+	("zhb", (None, None)),
+	("zhd", (None, None)),
+	("zhi", (None, None)),
+	("zhn", (None, None)),
+	("zhw", (None, None)),
+	("zhx", (None, None)),
+	("zia", (None, None)),
+	("zib", (None, None)),
+	("zik", (None, None)),
+	("zil", (None, None)),
+	("zim", (None, None)),
+	("zin", (None, None)),
+	("zir", (Some("scv"), None)),
+	("ziw", (None, None)),
+	("ziz", (None, None)),
+	("zka", (None, None)),
+	("zkb", (None, None)),
+	("zkd", (None, None)),
+	("zkg", (None, None)),
+	("zkh", (None, None)),
+	("zkk", (None, None)),
+	("zkn", (None, None)),
+	("zko", (None, None)),
+	("zkp", (None, None)),
+	("zkr", (None, None)),
+	("zkt", (None, None)),
+	("zku", (None, None)),
+	("zkv", (None, None)),
+	("zkz", (None, None)),
+	("zla", (None, None)),
+	("zle", (None, None)),
+	("zlj", (None, None)),
+	("zlm", (None, None)),
+	("zln", (None, None)),
+	("zlq", (None, None)),
+	("zls", (None, None)),
+	("zlw", (None, None)),
+	("zma", (None, None)),
+	("zmb", (None, None)),
+	("zmc", (None, None)),
+	("zmd", (None, None)),
+	("zme", (None, None)),
+	("zmf", (None, None)),
+	("zmg", (None, None)),
+	("zmh", (None, None)),
+	("zmi", (None, None)),
+	("zmj", (None, None)),
+	("zmk", (None, None)),
+	("zml", (None, None)),
+	("zmm", (None, None)),
+	("zmn", (None, None)),
+	("zmo", (None, None)),
+	("zmp", (None, None)),
+	("zmq", (None, None)),
+	("zmr", (None, None)),
+	("zms", (None, None)),
+	("zmt", (None, None)),
+	("zmu", (None, None)),
+	("zmv", (None, None)),
+	("zmw", (None, None)),
+	("zmx", (None, None)),
+	("zmy", (None, None)),
+	("zmz", (None, None)),
+	("zna", (None, None)),
+	("znd", (None, None)),
+	("zne", (None, None)),
+	("zng", (None, None)),
+	("znk", (None, None)),
+	("zns", (None, None)),
+	("zoc", (None, None)),
+	("zoh", (None, None)),
+	("zom", (None, None)),
+	("zoo", (None, None)),
+	("zoq", (None, None)),
+	("zor", (None, None)),
+	("zos", (None, None)),
+	("zpa", (None, None)),
+	("zpb", (None, None)),
+	("zpc", (None, None)),
+	("zpd", (None, None)),
+	("zpe", (None, None)),
+	("zpf", (None, None)),
+	("zpg", (None, None)),
+	("zph", (None, None)),
+	("zpi", (None, None)),
+	("zpj", (None, None)),
+	("zpk", (None, None)),
+	("zpl", (None, None)),
+	("zpm", (None, None)),
+	("zpn", (None, None)),
+	("zpo", (None, None)),
+	("zpp", (None, None)),
+	("zpq", (None, None)),
+	("zpr", (None, None)),
+	("zps", (None, None)),
+	("zpt", (None, None)),
+	("zpu", (None, None)),
+	("zpv", (None, None)),
+	("zpw", (None, None)),
+	("zpx", (None, None)),
+	("zpy", (None, None)),
+	("zpz", (None, None)),
+	("zqe", (None, None)),
+	("zra", (None, None)),
+	("zrg", (None, None)),
+	("zrn", (None, None)),
+	("zro", (None, None)),
+	("zrp", (None, None)),
+	("zrs", (None, None)),
+	("zsa", (None, None)),
+	("zsk", (None, None)),
+	("zsl", (None, None)),
+	("zsm", (None, None)),
+	("zsr", (None, None)),
+	("zsu", (None, None)),
+	("zte", (None, None)),
+	("ztg", (None, None)),
+	("ztl", (None, None)),
+	("ztm", (None, None)),
+	("ztn", (None, None)),
+	("ztp", (None, None)),
+	("ztq", (None, None)),
+	("zts", (None, None)),
+	("ztt", (None, None)),
+	("ztu", (None, None)),
+	("ztx", (None, None)),
+	("zty", (None, None)),
+	("zu", (None, Some("Latn"))),
+	("zua", (None, None)),
+	("zuh", (None, None)),
+	("zum", (None, None)),
+	("zun", (None, None)),
+	("zuy", (None, None)),
+	("zwa", (None, None)),
+	("zxx", (None, None)),
+	("zyb", (None, None)),
+	("zyg", (None, None)),
+	("zyj", (None, None)),
+	("zyn", (None, None)),
+	("zyp", (None, None)),
+	("zza", (None, None)),
+	("zzj", (None, None)),
+];
@@ -0,0 +1,507 @@
+//! ISO 3166-1 alpha-2 to UN M.49 numeric region codes.
+
+/// Country codes with their numeric equivalent, sorted by alpha-2 code.
+pub(crate) static ALPHA2_TO_NUMERIC: [(&str, &str); 249] = [
+	("AD", "020"),
+	("AE", "784"),
+	("AF", "004"),
+	("AG", "028"),
+	("AI", "660"),
+	("AL", "008"),
+	("AM", "051"),
+	("AO", "024"),
+	("AQ", "010"),
+	("AR", "032"),
+	("AS", "016"),
+	("AT", "040"),
+	("AU", "036"),
+	("AW", "533"),
+	("AX", "248"),
+	("AZ", "031"),
+	("BA", "070"),
+	("BB", "052"),
+	("BD", "050"),
+	("BE", "056"),
+	("BF", "854"),
+	("BG", "100"),
+	("BH", "048"),
+	("BI", "108"),
+	("BJ", "204"),
+	("BL", "652"),
+	("BM", "060"),
+	("BN", "096"),
+	("BO", "068"),
+	("BQ", "535"),
+	("BR", "076"),
+	("BS", "044"),
+	("BT", "064"),
+	("BV", "074"),
+	("BW", "072"),
+	("BY", "112"),
+	("BZ", "084"),
+	("CA", "124"),
+	("CC", "166"),
+	("CD", "180"),
+	("CF", "140"),
+	("CG", "178"),
+	("CH", "756"),
+	("CI", "384"),
+	("CK", "184"),
+	("CL", "152"),
+	("CM", "120"),
+	("CN", "156"),
+	("CO", "170"),
+	("CR", "188"),
+	("CU", "192"),
+	("CV", "132"),
+	("CW", "531"),
+	("CX", "162"),
+	("CY", "196"),
+	("CZ", "203"),
+	("DE", "276"),
+	("DJ", "262"),
+	("DK", "208"),
+	("DM", "212"),
+	("DO", "214"),
+	("DZ", "012"),
+	("EC", "218"),
+	("EE", "233"),
+	("EG", "818"),
+	("EH", "732"),
+	("ER", "232"),
+	("ES", "724"),
+	("ET", "231"),
+	("FI", "246"),
+	("FJ", "242"),
+	("FK", "238"),
+	("FM", "583"),
+	("FO", "234"),
+	("FR", "250"),
+	("GA", "266"),
+	("GB", "826"),
+	("GD", "308"),
+	("GE", "268"),
+	("GF", "254"),
+	("GG", "831"),
+	("GH", "288"),
+	("GI", "292"),
+	("GL", "304"),
+	("GM", "270"),
+	("GN", "324"),
+	("GP", "312"),
+	("GQ", "226"),
+	("GR", "300"),
+	("GS", "239"),
+	("GT", "320"),
+	("GU", "316"),
+	("GW", "624"),
+	("GY", "328"),
+	("HK", "344"),
+	("HM", "334"),
+	("HN", "340"),
+	("HR", "191"),
+	("HT", "332"),
+	("HU", "348"),
+	("ID", "360"),
+	("IE", "372"),
+	("IL", "376"),
+	("IM", "833"),
+	("IN", "356"),
+	("IO", "086"),
+	("IQ", "368"),
+	("IR", "364"),
+	("IS", "352"),
+	("IT", "380"),
+	("JE", "832"),
+	("JM", "388"),
+	("JO", "400"),
+	("JP", "392"),
+	("KE", "404"),
+	("KG", "417"),
+	("KH", "116"),
+	("KI", "296"),
+	("KM", "174"),
+	("KN", "659"),
+	("KP", "408"),
+	("KR", "410"),
+	("KW", "414"),
+	("KY", "136"),
+	("KZ", "398"),
+	("LA", "418"),
+	("LB", "422"),
+	("LC", "662"),
+	("LI", "438"),
+	("LK", "144"),
+	("LR", "430"),
+	("LS", "426"),
+	("LT", "440"),
+	("LU", "442"),
+	("LV", "428"),
+	("LY", "434"),
+	("MA", "504"),
+	("MC", "492"),
+	("MD", "498"),
+	("ME", "499"),
+	("MF", "663"),
+	("MG", "450"),
+	("MH", "584"),
+	("MK", "807"),
+	("ML", "466"),
+	("MM", "104"),
+	("MN", "496"),
+	("MO", "446"),
+	("MP", "580"),
+	("MQ", "474"),
+	("MR", "478"),
+	("MS", "500"),
+	("MT", "470"),
+	("MU", "480"),
+	("MV", "462"),
+	("MW", "454"),
+	("MX", "484"),
+	("MY", "458"),
+	("MZ", "508"),
+	("NA", "516"),
+	("NC", "540"),
+	("NE", "562"),
+	("NF", "574"),
+	("NG", "566"),
+	("NI", "558"),
+	("NL", "528"),
+	("NO", "578"),
+	("NP", "524"),
+	("NR", "520"),
+	("NU", "570"),
+	("NZ", "554"),
+	("OM", "512"),
+	("PA", "591"),
+	("PE", "604"),
+	("PF", "258"),
+	("PG", "598"),
+	("PH", "608"),
+	("PK", "586"),
+	("PL", "616"),
+	("PM", "666"),
+	("PN", "612"),
+	("PR", "630"),
+	("PS", "275"),
+	("PT", "620"),
+	("PW", "585"),
+	("PY", "600"),
+	("QA", "634"),
+	("RE", "638"),
+	("RO", "642"),
+	("RS", "688"),
+	("RU", "643"),
+	("RW", "646"),
+	("SA", "682"),
+	("SB", "090"),
+	("SC", "690"),
+	("SD", "729"),
+	("SE", "752"),
+	("SG", "702"),
+	("SH", "654"),
+	("SI", "705"),
+	("SJ", "744"),
+	("SK", "703"),
+	("SL", "694"),
+	("SM", "674"),
+	("SN", "686"),
+	("SO", "706"),
+	("SR", "740"),
+	("SS", "728"),
+	("ST", "678"),
+	("SV", "222"),
+	("SX", "534"),
+	("SY", "760"),
+	("SZ", "748"),
+	("TC", "796"),
+	("TD", "148"),
+	("TF", "260"),
+	("TG", "768"),
+	("TH", "764"),
+	("TJ", "762"),
+	("TK", "772"),
+	("TL", "626"),
+	("TM", "795"),
+	("TN", "788"),
+	("TO", "776"),
+	("TR", "792"),
+	("TT", "780"),
+	("TV", "798"),
+	("TW", "158"),
+	("TZ", "834"),
+	("UA", "804"),
+	("UG", "800"),
+	("UM", "581"),
+	("US", "840"),
+	("UY", "858"),
+	("UZ", "860"),
+	("VA", "336"),
+	("VC", "670"),
+	("VE", "862"),
+	("VG", "092"),
+	("VI", "850"),
+	("VN", "704"),
+	("VU", "548"),
+	("WF", "876"),
+	("WS", "882"),
+	("YE", "887"),
+	("YT", "175"),
+	("ZA", "710"),
+	("ZM", "894"),
+	("ZW", "716"),
+];
+
+/// Country codes with their alpha-2 equivalent, sorted by numeric code.
+pub(crate) static NUMERIC_TO_ALPHA2: [(&str, &str); 249] = [
+	("004", "AF"),
+	("008", "AL"),
+	("010", "AQ"),
+	("012", "DZ"),
+	("016", "AS"),
+	("020", "AD"),
+	("024", "AO"),
+	("028", "AG"),
+	("031", "AZ"),
+	("032", "AR"),
+	("036", "AU"),
+	("040", "AT"),
+	("044", "BS"),
+	("048", "BH"),
+	("050", "BD"),
+	("051", "AM"),
+	("052", "BB"),
+	("056", "BE"),
+	("060", "BM"),
+	("064", "BT"),
+	("068", "BO"),
+	("070", "BA"),
+	("072", "BW"),
+	("074", "BV"),
+	("076", "BR"),
+	("084", "BZ"),
+	("086", "IO"),
+	("090", "SB"),
+	("092", "VG"),
+	("096", "BN"),
+	("100", "BG"),
+	("104", "MM"),
+	("108", "BI"),
+	("112", "BY"),
+	("116", "KH"),
+	("120", "CM"),
+	("124", "CA"),
+	("132", "CV"),
+	("136", "KY"),
+	("140", "CF"),
+	("144", "LK"),
+	("148", "TD"),
+	("152", "CL"),
+	("156", "CN"),
+	("158", "TW"),
+	("162", "CX"),
+	("166", "CC"),
+	("170", "CO"),
+	("174", "KM"),
+	("175", "YT"),
+	("178", "CG"),
+	("180", "CD"),
+	("184", "CK"),
+	("188", "CR"),
+	("191", "HR"),
+	("192", "CU"),
+	("196", "CY"),
+	("203", "CZ"),
+	("204", "BJ"),
+	("208", "DK"),
+	("212", "DM"),
+	("214", "DO"),
+	("218", "EC"),
+	("222", "SV"),
+	("226", "GQ"),
+	("231", "ET"),
+	("232", "ER"),
+	("233", "EE"),
+	("234", "FO"),
+	("238", "FK"),
+	("239", "GS"),
+	("242", "FJ"),
+	("246", "FI"),
+	("248", "AX"),
+	("250", "FR"),
+	("254", "GF"),
+	("258", "PF"),
+	("260", "TF"),
+	("262", "DJ"),
+	("266", "GA"),
+	("268", "GE"),
+	("270", "GM"),
+	("275", "PS"),
+	("276", "DE"),
+	("288", "GH"),
+	("292", "GI"),
+	("296", "KI"),
+	("300", "GR"),
+	("304", "GL"),
+	("308", "GD"),
+	("312", "GP"),
+	("316", "GU"),
+	("320", "GT"),
+	("324", "GN"),
+	("328", "GY"),
+	("332", "HT"),
+	("334", "HM"),
+	("336", "VA"),
+	("340", "HN"),
+	("344", "HK"),
+	("348", "HU"),
+	("352", "IS"),
+	("356", "IN"),
+	("360", "ID"),
+	("364", "IR"),
+	("368", "IQ"),
+	("372", "IE"),
+	("376", "IL"),
+	("380", "IT"),
+	("384", "CI"),
+	("388", "JM"),
+	("392", "JP"),
+	("398", "KZ"),
+	("400", "JO"),
+	("404", "KE"),
+	("408", "KP"),
+	("410", "KR"),
+	("414", "KW"),
+	("417", "KG"),
+	("418", "LA"),
+	("422", "LB"),
+	("426", "LS"),
+	("428", "LV"),
+	("430", "LR"),
+	("434", "LY"),
+	("438", "LI"),
+	("440", "LT"),
+	("442", "LU"),
+	("446", "MO"),
+	("450", "MG"),
+	("454", "MW"),
+	("458", "MY"),
+	("462", "MV"),
+	("466", "ML"),
+	("470", "MT"),
+	("474", "MQ"),
+	("478", "MR"),
+	("480", "MU"),
+	("484", "MX"),
+	("492", "MC"),
+	("496", "MN"),
+	("498", "MD"),
+	("499", "ME"),
+	("500", "MS"),
+	("504", "MA"),
+	("508", "MZ"),
+	("512", "OM"),
+	("516", "NA"),
+	("520", "NR"),
+	("524", "NP"),
+	("528", "NL"),
+	("531", "CW"),
+	("533", "AW"),
+	("534", "SX"),
+	("535", "BQ"),
+	("540", "NC"),
+	("548", "VU"),
+	("554", "NZ"),
+	("558", "NI"),
+	("562", "NE"),
+	("566", "NG"),
+	("570", "NU"),
+	("574", "NF"),
+	("578", "NO"),
+	("580", "MP"),
+	("581", "UM"),
+	("583", "FM"),
+	("584", "MH"),
+	("585", "PW"),
+	("586", "PK"),
+	("591", "PA"),
+	("598", "PG"),
+	("600", "PY"),
+	("604", "PE"),
+	("608", "PH"),
+	("612", "PN"),
+	("616", "PL"),
+	("620", "PT"),
+	("624", "GW"),
+	("626", "TL"),
+	("630", "PR"),
+	("634", "QA"),
+	("638", "RE"),
+	("642", "RO"),
+	("643", "RU"),
+	("646", "RW"),
+	("652", "BL"),
+	("654", "SH"),
+	("659", "KN"),
+	("660", "AI"),
+	("662", "LC"),
+	("663", "MF"),
+	("666", "PM"),
+	("670", "VC"),
+	("674", "SM"),
+	("678", "ST"),
+	("682", "SA"),
+	("686", "SN"),
+	("688", "RS"),
+	("690", "SC"),
+	("694", "SL"),
+	("702", "SG"),
+	("703", "SK"),
+	("704", "VN"),
+	("705", "SI"),
+	("706", "SO"),
+	("710", "ZA"),
+	("716", "ZW"),
+	("724", "ES"),
+	("728", "SS"),
+	("729", "SD"),
+	("732", "EH"),
+	("740", "SR"),
+	("744", "SJ"),
+	("748", "SZ"),
+	("752", "SE"),
+	("756", "CH"),
+	("760", "SY"),
+	("762", "TJ"),
+	("764", "TH"),
+	("768", "TG"),
+	("772", "TK"),
+	("776", "TO"),
+	("780", "TT"),
+	("784", "AE"),
+	("788", "TN"),
+	("792", "TR"),
+	("795", "TM"),
+	("796", "TC"),
+	("798", "TV"),
+	("800", "UG"),
+	("804", "UA"),
+	("807", "MK"),
+	("818", "EG"),
+	("826", "GB"),
+	("831", "GG"),
+	("832", "JE"),
+	("833", "IM"),
+	("834", "TZ"),
+	("840", "US"),
+	("850", "VI"),
+	("854", "BF"),
+	("858", "UY"),
+	("860", "UZ"),
+	("862", "VE"),
+	("876", "WF"),
+	("882", "WS"),
+	("887", "YE"),
+	("894", "ZM"),
+];
@@ -0,0 +1,131 @@
+//! Data extracted from the IANA Language Subtag Registry and related
+//! standards, embedded when the `registry` feature is enabled.
+use std::cmp::Ordering;
+
+use crate::utils;
+
+mod containment;
+mod extlang;
+mod language;
+mod likely;
+mod m49;
+mod region;
+mod script;
+mod variant;
+
+/// Finds the value associated to `key` in the given table, ignoring case.
+///
+/// The table must be sorted by lowercase key.
+fn lookup<'t, V>(table: &'t [(&str, V)], key: &str) -> Option<&'t V> {
+	table
+		.binary_search_by(|(k, _)| cmp_smallcase(k, key))
+		.ok()
+		.map(|i| &table[i].1)
+}
+
+/// Compares the lowercase forms of the two given strings.
+fn cmp_smallcase(a: &str, b: &str) -> Ordering {
+	a.bytes()
+		.map(utils::into_smallcase)
+		.cmp(b.bytes().map(utils::into_smallcase))
+}
+
+/// Returns the UN M.49 numeric code of the given ISO 3166-1 alpha-2 region
+/// code.
+pub(crate) fn region_alpha2_to_numeric(alpha2: &str) -> Option<&'static str> {
+	lookup(&m49::ALPHA2_TO_NUMERIC, alpha2).copied()
+}
+
+/// Returns the ISO 3166-1 alpha-2 code of the given UN M.49 numeric region
+/// code, if it designates a country.
+pub(crate) fn region_numeric_to_alpha2(numeric: &str) -> Option<&'static str> {
+	lookup(&m49::NUMERIC_TO_ALPHA2, numeric).copied()
+}
+
+/// Returns the numeric code of the UN M.49 region directly containing the
+/// given region, if any.
+pub(crate) fn region_parent(region: &str) -> Option<&'static str> {
+	lookup(&containment::REGION_PARENTS, region).copied()
+}
+
+/// Returns the `Preferred-Value` of the given region subtag, if it is
+/// deprecated.
+pub(crate) fn region_preferred_value(region: &str) -> Option<&'static str> {
+	lookup(&region::REGION_PREFERRED_VALUES, region).copied()
+}
+
+/// Returns the prefix (macrolanguage) of the given extended language subtag,
+/// if it is registered.
+pub(crate) fn extlang_prefix(extlang: &str) -> Option<&'static str> {
+	lookup(&extlang::EXTLANG_PREFIXES, extlang).copied()
+}
+
+/// `Preferred-Value` and `Suppress-Script` fields of a language subtag.
+pub(crate) type LanguageFields = (Option<&'static str>, Option<&'static str>);
+
+/// Returns the `Preferred-Value` and `Suppress-Script` fields of the given
+/// primary language subtag, if it is registered.
+pub(crate) fn language(language: &str) -> Option<LanguageFields> {
+	lookup(&language::LANGUAGES, language).copied()
+}
+
+/// Returns the script that should not be used with the given primary
+/// language (`Suppress-Script` field), if any.
+pub(crate) fn suppress_script(language: &str) -> Option<&'static str> {
+	self::language(language).and_then(|(_, suppress_script)| suppress_script)
+}
+
+/// Likely language, script and region subtags.
+pub(crate) type LikelySubtags = (&'static str, &'static str, &'static str);
+
+/// Returns the likely subtags of the given primary language, optionally
+/// in the given region.
+pub(crate) fn likely_subtags(language: &str, region: Option<&str>) -> Option<LikelySubtags> {
+	let in_region = region.and_then(|region| {
+		likely::LANGUAGE_REGIONS
+			.binary_search_by(|((l, r), _)| {
+				cmp_smallcase(l, language).then_with(|| cmp_smallcase(r, region))
+			})
+			.ok()
+			.map(|i| likely::LANGUAGE_REGIONS[i].1)
+	});
+
+	in_region.or_else(|| lookup(&likely::LANGUAGES, language).copied())
+}
+
+/// Returns the likely subtags of the given region.
+pub(crate) fn region_likely_subtags(region: &str) -> Option<LikelySubtags> {
+	lookup(&likely::REGIONS, region).copied()
+}
+
+/// Checks if the given script subtag is registered, or in the private use
+/// range `Qaaa..Qabx`.
+pub(crate) fn is_registered_script(script: &str) -> bool {
+	let lowercase = script.to_ascii_lowercase();
+	("qaaa"..="qabx").contains(&lowercase.as_str())
+		|| script::SCRIPTS
+			.binary_search_by(|s| cmp_smallcase(s, script))
+			.is_ok()
+}
+
+/// Checks if the given region subtag is registered, or in the private use
+/// ranges `QM..QZ` and `XA..XZ`.
+pub(crate) fn is_registered_region(region: &str) -> bool {
+	let lowercase = region.to_ascii_lowercase();
+	("qm"..="qz").contains(&lowercase.as_str())
+		|| ("xa"..="xz").contains(&lowercase.as_str())
+		|| region::REGIONS
+			.binary_search_by(|r| cmp_smallcase(r, region))
+			.is_ok()
+}
+
+/// Checks if the given variant subtag is registered.
+pub(crate) fn is_registered_variant(variant: &str) -> bool {
+	variant_prefixes(variant).is_some()
+}
+
+/// Returns the `Prefix` fields of the given variant subtag, if it is
+/// registered.
+pub(crate) fn variant_prefixes(variant: &str) -> Option<&'static [&'static str]> {
+	lookup(&variant::VARIANTS, variant).copied()
+}
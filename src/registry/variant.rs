@@ -0,0 +1,125 @@
+//! Registered variant subtags.
+
+/// Registered variant subtags with their `Prefix` fields, sorted by subtag.
+pub(crate) static VARIANTS: [(&str, &[&str]); 106] = [
+	("1606nict", &["frm"]),
+	("1694acad", &["fr"]),
+	("1901", &["de"]),
+	("1959acad", &["be"]),
+	(
+		"1994",
+		&[
+			"sl-rozaj",
+			"sl-rozaj-biske",
+			"sl-rozaj-njiva",
+			"sl-rozaj-osojs",
+			"sl-rozaj-solba",
+		],
+	),
+	("1996", &["de"]),
+	("abl1943", &["pt-BR"]),
+	("akuapem", &["tw"]),
+	("alalc97", &[]),
+	("aluku", &["djk"]),
+	("ao1990", &["pt", "gl"]),
+	("aranes", &["oc"]),
+	("arevela", &["hy"]),
+	("arevmda", &["hy"]),
+	("arkaika", &["eo"]),
+	("asante", &["tw"]),
+	("auvern", &["oc"]),
+	(
+		"baku1926",
+		&[
+			"az", "ba", "crh", "kk", "krc", "ky", "sah", "tk", "tt", "uz",
+		],
+	),
+	("balanka", &["blo"]),
+	("barla", &["kea"]),
+	("basiceng", &["en"]),
+	("bauddha", &["sa"]),
+	("biscayan", &["eu"]),
+	("biske", &["sl-rozaj"]),
+	("bohoric", &["sl"]),
+	("boont", &["en"]),
+	("bornholm", &["da"]),
+	("cisaup", &["oc"]),
+	("colb1945", &["pt"]),
+	("cornu", &["en"]),
+	("creiss", &["oc"]),
+	("dajnko", &["sl"]),
+	("ekavsk", &["sr", "sr-Latn", "sr-Cyrl"]),
+	("emodeng", &["en"]),
+	("fonipa", &[]),
+	("fonkirsh", &[]),
+	("fonnapa", &[]),
+	("fonupa", &[]),
+	("fonxsamp", &[]),
+	("gascon", &["oc"]),
+	("grclass", &["oc"]),
+	("grital", &["oc"]),
+	("grmistr", &["oc"]),
+	("hepburn", &["ja-Latn"]),
+	("heploc", &["ja-Latn-hepburn"]),
+	("hognorsk", &["nn"]),
+	("hsistemo", &["eo"]),
+	("ijekavsk", &["sr", "sr-Latn", "sr-Cyrl"]),
+	("itihasa", &["sa"]),
+	("ivanchov", &["bg"]),
+	("jauer", &["rm"]),
+	("jyutping", &["yue"]),
+	("kkcor", &["kw"]),
+	("kociewie", &["pl"]),
+	("kscor", &["kw"]),
+	("laukika", &["sa"]),
+	("lemosin", &["oc"]),
+	("lengadoc", &["oc"]),
+	("lipaw", &["sl-rozaj"]),
+	("luna1918", &["ru"]),
+	("metelko", &["sl"]),
+	("monoton", &["el"]),
+	("ndyuka", &["djk"]),
+	("nedis", &["sl"]),
+	("newfound", &["en-CA"]),
+	("nicard", &["oc"]),
+	("njiva", &["sl-rozaj"]),
+	("nulik", &["vo"]),
+	("osojs", &["sl-rozaj"]),
+	("oxendict", &["en"]),
+	("pahawh2", &["mww", "hnj"]),
+	("pahawh3", &["mww", "hnj"]),
+	("pahawh4", &["mww", "hnj"]),
+	("pamaka", &["djk"]),
+	("peano", &["la"]),
+	("petr1708", &["ru"]),
+	("pinyin", &["zh-Latn", "bo-Latn"]),
+	("polyton", &["el"]),
+	("provenc", &["oc"]),
+	("puter", &["rm"]),
+	("rigik", &["vo"]),
+	("rozaj", &["sl"]),
+	("rumgr", &["rm"]),
+	("scotland", &["en"]),
+	("scouse", &["en"]),
+	("simple", &[]),
+	("solba", &["sl-rozaj"]),
+	("sotav", &["kea"]),
+	("spanglis", &["en", "es"]),
+	("surmiran", &["rm"]),
+	("sursilv", &["rm"]),
+	("sutsilv", &["rm"]),
+	("tarask", &["be"]),
+	("tongyong", &["zh-Latn"]),
+	("tunumiit", &["kl"]),
+	("uccor", &["kw"]),
+	("ucrcor", &["kw"]),
+	("ulster", &["sco"]),
+	("unifon", &["en", "hup", "kyh", "tol", "yur"]),
+	("vaidika", &["sa"]),
+	("valencia", &["ca"]),
+	("vallader", &["rm"]),
+	("vecdruka", &["lv"]),
+	("vivaraup", &["oc"]),
+	("wadegile", &["zh-Latn"]),
+	("xsistemo", &["eo"]),
+];
@@ -0,0 +1,248 @@
+//! Subtag positions in language tags.
+use std::ops::Range;
+
+use crate::{LangTag, PrivateUse, TypedLangTag};
+
+/// Kind of a subtag in a language tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubtagKind {
+	/// Primary language subtag (e.g. `zh` in `zh-yue-HK`).
+	PrimaryLanguage,
+
+	/// Extended language subtag (e.g. `yue` in `zh-yue-HK`).
+	ExtendedLanguage,
+
+	/// Script subtag.
+	Script,
+
+	/// Region subtag.
+	Region,
+
+	/// Variant subtag.
+	Variant,
+
+	/// Extension singleton (e.g. `u` in `en-u-ca-gregory`).
+	Singleton,
+
+	/// Extension subtag (e.g. `ca` in `en-u-ca-gregory`).
+	Extension,
+
+	/// Private use `x` singleton.
+	PrivateUseSingleton,
+
+	/// Private use subtag.
+	PrivateUse,
+
+	/// Subtag of a grandfathered tag.
+	Grandfathered,
+}
+
+/// Subtag of a language tag, with its kind.
+///
+/// See [`LangTag::subtags`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subtag<'a> {
+	/// Primary language subtag.
+	PrimaryLanguage(&'a str),
+
+	/// Extended language subtag.
+	ExtendedLanguage(&'a str),
+
+	/// Script subtag.
+	Script(&'a str),
+
+	/// Region subtag.
+	Region(&'a str),
+
+	/// Variant subtag.
+	Variant(&'a str),
+
+	/// Extension singleton.
+	Singleton(&'a str),
+
+	/// Extension subtag.
+	Extension(&'a str),
+
+	/// Private use `x` singleton.
+	PrivateUseSingleton(&'a str),
+
+	/// Private use subtag.
+	PrivateUse(&'a str),
+
+	/// Whole grandfathered tag.
+	Grandfathered(&'a str),
+}
+
+impl<'a> Subtag<'a> {
+	fn new(kind: SubtagKind, s: &'a str) -> Self {
+		match kind {
+			SubtagKind::PrimaryLanguage => Self::PrimaryLanguage(s),
+			SubtagKind::ExtendedLanguage => Self::ExtendedLanguage(s),
+			SubtagKind::Script => Self::Script(s),
+			SubtagKind::Region => Self::Region(s),
+			SubtagKind::Variant => Self::Variant(s),
+			SubtagKind::Singleton => Self::Singleton(s),
+			SubtagKind::Extension => Self::Extension(s),
+			SubtagKind::PrivateUseSingleton => Self::PrivateUseSingleton(s),
+			SubtagKind::PrivateUse => Self::PrivateUse(s),
+			SubtagKind::Grandfathered => Self::Grandfathered(s),
+		}
+	}
+
+	/// Returns the kind of this subtag.
+	pub fn kind(&self) -> SubtagKind {
+		match self {
+			Self::PrimaryLanguage(_) => SubtagKind::PrimaryLanguage,
+			Self::ExtendedLanguage(_) => SubtagKind::ExtendedLanguage,
+			Self::Script(_) => SubtagKind::Script,
+			Self::Region(_) => SubtagKind::Region,
+			Self::Variant(_) => SubtagKind::Variant,
+			Self::Singleton(_) => SubtagKind::Singleton,
+			Self::Extension(_) => SubtagKind::Extension,
+			Self::PrivateUseSingleton(_) => SubtagKind::PrivateUseSingleton,
+			Self::PrivateUse(_) => SubtagKind::PrivateUse,
+			Self::Grandfathered(_) => SubtagKind::Grandfathered,
+		}
+	}
+
+	/// Returns the subtag as a string.
+	pub fn as_str(&self) -> &'a str {
+		match self {
+			Self::PrimaryLanguage(s)
+			| Self::ExtendedLanguage(s)
+			| Self::Script(s)
+			| Self::Region(s)
+			| Self::Variant(s)
+			| Self::Singleton(s)
+			| Self::Extension(s)
+			| Self::PrivateUseSingleton(s)
+			| Self::PrivateUse(s)
+			| Self::Grandfathered(s) => s,
+		}
+	}
+}
+
+impl LangTag {
+	/// Returns an iterator over the subtags of this tag, in order, with
+	/// their kind.
+	///
+	/// Grandfathered tags are returned as a single
+	/// [`Subtag::Grandfathered`] item.
+	///
+	/// ```
+	/// use langtag::{LangTag, Subtag};
+	///
+	/// let tag = LangTag::new("zh-yue-HK-u-co-x-foo").unwrap();
+	/// assert_eq!(
+	///   tag.subtags().collect::<Vec<_>>(),
+	///   [
+	///     Subtag::PrimaryLanguage("zh"),
+	///     Subtag::ExtendedLanguage("yue"),
+	///     Subtag::Region("HK"),
+	///     Subtag::Singleton("u"),
+	///     Subtag::Extension("co"),
+	///     Subtag::PrivateUseSingleton("x"),
+	///     Subtag::PrivateUse("foo")
+	///   ]
+	/// );
+	/// ```
+	pub fn subtags(&self) -> impl Iterator<Item = Subtag<'_>> {
+		let spans = if self.is_grandfathered() {
+			vec![(SubtagKind::Grandfathered, 0..self.0.len())]
+		} else {
+			self.subtag_spans()
+		};
+
+		spans
+			.into_iter()
+			.map(|(kind, range)| Subtag::new(kind, &self.0[range]))
+	}
+
+	/// Returns the kind and byte range of every subtag of this tag, in order.
+	///
+	/// ```
+	/// use langtag::{LangTag, SubtagKind};
+	///
+	/// let tag = LangTag::new("zh-yue-HK-x-foo").unwrap();
+	/// assert_eq!(
+	///   tag.subtag_spans(),
+	///   [
+	///     (SubtagKind::PrimaryLanguage, 0..2),
+	///     (SubtagKind::ExtendedLanguage, 3..6),
+	///     (SubtagKind::Region, 7..9),
+	///     (SubtagKind::PrivateUseSingleton, 10..11),
+	///     (SubtagKind::PrivateUse, 12..15)
+	///   ]
+	/// );
+	/// ```
+	pub fn subtag_spans(&self) -> Vec<(SubtagKind, Range<usize>)> {
+		let base = self.0.as_ptr() as usize;
+		let span = |s: &str| {
+			let start = s.as_ptr() as usize - base;
+			start..(start + s.len())
+		};
+
+		let mut spans = Vec::new();
+		let push_private_use = |private_use: &PrivateUse, spans: &mut Vec<_>| {
+			let start = span(private_use).start;
+			spans.push((SubtagKind::PrivateUseSingleton, start..(start + 1)));
+			spans.extend(
+				private_use
+					.iter()
+					.map(|s| (SubtagKind::PrivateUse, span(s))),
+			)
+		};
+
+		match self.as_typed() {
+			TypedLangTag::Normal(t) => {
+				let language = t.language();
+				spans.push((SubtagKind::PrimaryLanguage, span(language.primary())));
+				spans.extend(
+					language
+						.extension_subtags()
+						.map(|s| (SubtagKind::ExtendedLanguage, span(s))),
+				);
+				spans.extend(t.script().map(|s| (SubtagKind::Script, span(s))));
+				spans.extend(t.region().map(|r| (SubtagKind::Region, span(r))));
+				spans.extend(t.variants().iter().map(|v| (SubtagKind::Variant, span(v))));
+
+				for extension in t.extensions().iter() {
+					let start = span(extension).start;
+					spans.push((SubtagKind::Singleton, start..(start + 1)));
+					spans.extend(extension.iter().map(|s| (SubtagKind::Extension, span(s))))
+				}
+
+				if let Some(private_use) = t.private_use() {
+					push_private_use(private_use, &mut spans)
+				}
+			}
+			TypedLangTag::PrivateUse(t) => push_private_use(t, &mut spans),
+			TypedLangTag::Grandfathered(_) => spans.extend(
+				self.0
+					.split('-')
+					.map(|s| (SubtagKind::Grandfathered, span(s))),
+			),
+		}
+
+		spans
+	}
+
+	/// Returns the kind and byte range of the subtag containing the given
+	/// byte offset.
+	///
+	/// Returns `None` if the offset is on a `-` separator or past the end of
+	/// the tag.
+	///
+	/// ```
+	/// use langtag::{LangTag, SubtagKind};
+	///
+	/// let tag = LangTag::new("sr-Latn-RS").unwrap();
+	/// assert_eq!(tag.subtag_kind_at(5), Some((SubtagKind::Script, 3..7)));
+	/// assert_eq!(tag.subtag_kind_at(7), None);
+	/// ```
+	pub fn subtag_kind_at(&self, offset: usize) -> Option<(SubtagKind, Range<usize>)> {
+		self.subtag_spans()
+			.into_iter()
+			.find(|(_, range)| range.contains(&offset))
+	}
+}
@@ -0,0 +1,74 @@
+//! Lossless traversal of language tags.
+use crate::LangTag;
+
+/// Language tag token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Token<'a> {
+	/// Subtag.
+	Subtag(&'a str),
+
+	/// `-` separator.
+	Separator,
+}
+
+impl<'a> Token<'a> {
+	/// Returns the exact text of the token.
+	pub fn as_str(&self) -> &'a str {
+		match self {
+			Self::Subtag(s) => s,
+			Self::Separator => "-",
+		}
+	}
+}
+
+/// Iterator over the tokens of a language tag.
+///
+/// See [`LangTag::tokens`].
+pub struct Tokens<'a> {
+	data: &'a str,
+	offset: usize,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+	type Item = Token<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let bytes = self.data.as_bytes();
+		if self.offset < bytes.len() {
+			if bytes[self.offset] == b'-' {
+				self.offset += 1;
+				Some(Token::Separator)
+			} else {
+				let start = self.offset;
+				while self.offset < bytes.len() && bytes[self.offset] != b'-' {
+					self.offset += 1
+				}
+
+				Some(Token::Subtag(&self.data[start..self.offset]))
+			}
+		} else {
+			None
+		}
+	}
+}
+
+impl LangTag {
+	/// Returns an iterator over the subtags and separators of this tag.
+	///
+	/// Contrarily to subtag iterators, separators are preserved so that the
+	/// exact tag can be reconstructed by concatenating the tokens.
+	///
+	/// ```
+	/// use langtag::{LangTag, Token};
+	///
+	/// let tag = LangTag::new("en-US").unwrap();
+	/// let tokens: Vec<_> = tag.tokens().collect();
+	/// assert_eq!(tokens, [Token::Subtag("en"), Token::Separator, Token::Subtag("US")]);
+	/// ```
+	pub fn tokens(&self) -> Tokens<'_> {
+		Tokens {
+			data: &self.0,
+			offset: 0,
+		}
+	}
+}
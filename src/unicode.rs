@@ -0,0 +1,183 @@
+//! Conversion to Unicode locale identifiers (UTS #35).
+use crate::{
+	canonical::collapsed_language, registry, Extension, GrandfatheredLangTag, LangTag,
+	NormalLangTag, TypedLangTag, Variant,
+};
+
+impl LangTag {
+	/// Converts this tag into a canonical Unicode locale identifier, as
+	/// defined by [UTS #35], which is the form expected by ICU.
+	///
+	/// In addition to the canonical casing and extension ordering of
+	/// [`Self::write_canonical`] (including the replacement of grandfathered
+	/// tags and the collapse of extended language subtags), this:
+	/// - replaces deprecated language and region subtags by their registered
+	///   preferred value (`iw` becomes `he`, `BU` becomes `MM`);
+	/// - sorts variants alphabetically;
+	/// - sorts the keywords of `u` extensions by key and their attributes
+	///   alphabetically, and the fields of `t` extensions by key;
+	/// - removes the `true` keyword and field values (`u-ca-true` becomes
+	///   `u-ca`);
+	/// - prefixes private use tags with `und` (`x-foo` becomes `und-x-foo`),
+	///   and maps the grandfathered tags without preferred value according to
+	///   UTS #35 (`i-default` becomes `en-x-i-default`).
+	///
+	/// Aliases are taken from the IANA registry, not from the CLDR alias data
+	/// which may contain more replacements.
+	///
+	/// ```
+	/// use langtag::LangTag;
+	///
+	/// let tag = LangTag::new("EN-u-NU-thai-CA-gregory-b-bar-a-foo").unwrap();
+	/// assert_eq!(tag.to_unicode_locale_id(), "en-a-foo-b-bar-u-ca-gregory-nu-thai");
+	/// ```
+	///
+	/// [UTS #35]: <https://www.unicode.org/reports/tr35/>
+	pub fn to_unicode_locale_id(&self) -> String {
+		let mut result = String::with_capacity(self.len());
+
+		match self.as_typed() {
+			TypedLangTag::Normal(t) => write_normal(&mut result, t),
+			TypedLangTag::PrivateUse(t) => {
+				result.push_str("und-");
+				push_lowercase(&mut result, t.as_str())
+			}
+			TypedLangTag::Grandfathered(t) => {
+				let replacement = match t {
+					GrandfatheredLangTag::CelGaulish => "xtg-x-cel-gaulish",
+					GrandfatheredLangTag::IDefault => "en-x-i-default",
+					GrandfatheredLangTag::IEnochian => "und-x-i-enochian",
+					GrandfatheredLangTag::IMingo => "see-x-i-mingo",
+					GrandfatheredLangTag::ZhMin => "nan-x-zh-min",
+					other => other.preferred_value_str().unwrap(),
+				};
+
+				write_normal(&mut result, NormalLangTag::new(replacement).unwrap())
+			}
+		}
+
+		result
+	}
+}
+
+fn write_normal(result: &mut String, tag: &NormalLangTag) {
+	let language = collapsed_language(tag.language());
+	let language = if language.contains('-') {
+		language
+	} else {
+		registry::language(language)
+			.and_then(|(preferred_value, _)| preferred_value)
+			.unwrap_or(language)
+	};
+	push_lowercase(result, language);
+
+	if let Some(script) = tag.script() {
+		let (first, rest) = script.split_at(1);
+		result.push('-');
+		result.push_str(&first.to_ascii_uppercase());
+		push_lowercase(result, rest)
+	}
+
+	if let Some(region) = tag.region() {
+		let region = registry::region_preferred_value(region).unwrap_or(region);
+		result.push('-');
+		result.push_str(&region.to_ascii_uppercase())
+	}
+
+	let mut variants: Vec<_> = tag.variants().iter().map(Variant::as_str).collect();
+	variants.sort_by_cached_key(|v| v.to_ascii_lowercase());
+	variants.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+	for variant in variants {
+		result.push('-');
+		push_lowercase(result, variant)
+	}
+
+	for extension in tag.extensions().iter_sorted() {
+		result.push('-');
+		write_extension(result, extension)
+	}
+
+	if let Some(private_use) = tag.private_use() {
+		result.push('-');
+		push_lowercase(result, private_use)
+	}
+}
+
+fn write_extension(result: &mut String, extension: &Extension) {
+	let singleton = extension.singleton().unwrap().to_ascii_lowercase();
+	let subtags: Vec<_> = extension
+		.iter()
+		.map(|subtag| subtag.to_ascii_lowercase())
+		.collect();
+
+	match singleton {
+		b'u' => write_keywords(result, 'u', subtags, true, |s| s.len() == 2),
+		b't' => write_keywords(result, 't', subtags, false, |s| {
+			s.len() == 2 && s.as_bytes()[1].is_ascii_digit()
+		}),
+		_ => push_lowercase(result, extension),
+	}
+}
+
+/// Writes a `u` or `t` extension, made of a prefix (attributes or source
+/// language) followed by keywords (or fields), each composed of a key
+/// (detected with `is_key`) followed by values.
+///
+/// Keywords are sorted by key, keeping only the first occurrence of each
+/// key, and `true` values are removed. If `sort_prefix` is true, the prefix
+/// subtags are also sorted and deduplicated.
+fn write_keywords(
+	result: &mut String,
+	singleton: char,
+	subtags: Vec<String>,
+	sort_prefix: bool,
+	is_key: impl Fn(&str) -> bool,
+) {
+	let prefix_len = subtags
+		.iter()
+		.position(|s| is_key(s))
+		.unwrap_or(subtags.len());
+	let (prefix, keywords_subtags) = subtags.split_at(prefix_len);
+
+	let mut prefix = prefix.to_vec();
+	if sort_prefix {
+		prefix.sort();
+		prefix.dedup();
+	}
+
+	let mut keywords: Vec<&[String]> = Vec::new();
+	let mut start = 0;
+	for i in 1..=keywords_subtags.len() {
+		if i == keywords_subtags.len() || is_key(&keywords_subtags[i]) {
+			keywords.push(&keywords_subtags[start..i]);
+			start = i
+		}
+	}
+
+	keywords.sort_by(|a, b| a[0].cmp(&b[0]));
+	keywords.dedup_by(|a, b| a[0] == b[0]);
+
+	result.push(singleton);
+	for subtag in &prefix {
+		result.push('-');
+		result.push_str(subtag)
+	}
+
+	for keyword in keywords {
+		let values = match &keyword[1..] {
+			[value] if value == "true" => &[],
+			values => values,
+		};
+
+		result.push('-');
+		result.push_str(&keyword[0]);
+		for value in values {
+			result.push('-');
+			result.push_str(value)
+		}
+	}
+}
+
+fn push_lowercase(result: &mut String, s: &str) {
+	result.extend(s.chars().map(|c| c.to_ascii_lowercase()))
+}
@@ -0,0 +1,262 @@
+//! Validation of language tags against the registry.
+use crate::{registry, LangTag, NormalLangTag, StructureError, TypedLangTag, Variant};
+
+/// Validation issue of a well-formed language tag.
+///
+/// See [`LangTag::validate_all`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+	/// The primary language subtag is not registered.
+	#[error("unregistered language `{0}`")]
+	UnregisteredLanguage(String),
+
+	/// The extended language subtag is not registered.
+	#[error("unregistered extended language `{0}`")]
+	UnregisteredExtendedLanguage(String),
+
+	/// The extended language subtag is registered with another prefix.
+	#[error("extended language `{extlang}` requires the `{prefix}` prefix")]
+	InvalidExtendedLanguagePrefix {
+		/// Extended language subtag.
+		extlang: String,
+
+		/// Registered prefix.
+		prefix: &'static str,
+	},
+
+	/// The script subtag is not registered.
+	#[error("unregistered script `{0}`")]
+	UnregisteredScript(String),
+
+	/// The region subtag is not registered.
+	#[error("unregistered region `{0}`")]
+	UnregisteredRegion(String),
+
+	/// The variant subtag is not registered.
+	#[error("unregistered variant `{0}`")]
+	UnregisteredVariant(String),
+
+	/// The tag does not start with any of the registered prefixes of the
+	/// variant subtag.
+	#[error("variant `{0}` used without any of its registered prefixes")]
+	InvalidVariantPrefix(String),
+
+	/// The subtag (or grandfathered tag) is deprecated.
+	#[error("deprecated `{subtag}`")]
+	Deprecated {
+		/// Deprecated subtag, or grandfathered tag.
+		subtag: String,
+
+		/// Registered replacement, if any.
+		preferred_value: Option<&'static str>,
+	},
+
+	/// Structural rule violation (duplicate variant or singleton).
+	#[error(transparent)]
+	Structure(StructureError),
+}
+
+impl ValidationError {
+	/// Checks if this issue is only a warning, that does not make the tag
+	/// invalid for [`LangTag::check_valid`].
+	///
+	/// Unregistered variants and deprecated subtags are warnings.
+	pub fn is_warning(&self) -> bool {
+		matches!(self, Self::UnregisteredVariant(_) | Self::Deprecated { .. })
+	}
+}
+
+impl LangTag {
+	/// Validates this tag against the registry, returning the first issue
+	/// that is not a warning (see [`ValidationError::is_warning`]).
+	///
+	/// Unlike [`Self::validate`], which only checks the syntax, this checks
+	/// the subtags against the registry like [`Self::validate_all`].
+	///
+	/// ```
+	/// use langtag::{LangTag, ValidationError};
+	///
+	/// assert!(LangTag::new("en-shadok").unwrap().check_valid().is_ok());
+	/// assert_eq!(
+	///   LangTag::new("en-Abcd-shadok").unwrap().check_valid(),
+	///   Err(ValidationError::UnregisteredScript("Abcd".to_owned()))
+	/// );
+	/// ```
+	pub fn check_valid(&self) -> Result<(), ValidationError> {
+		match self.validate_all().into_iter().find(|e| !e.is_warning()) {
+			Some(e) => Err(e),
+			None => Ok(()),
+		}
+	}
+
+	/// Validates this tag against the registry, returning every issue found.
+	///
+	/// The following issues are reported, ordered by position in the tag:
+	/// - unregistered language, extended language, script, region and variant
+	///   subtags (private use ranges such as `qaa..qtz` are accepted);
+	/// - extended language subtags used with the wrong primary language;
+	/// - variant subtags used without any of their registered prefixes;
+	/// - deprecated subtags having a `Preferred-Value`, and deprecated
+	///   grandfathered tags;
+	/// - duplicate variants and extension singletons.
+	///
+	/// Returns an empty list if the tag is valid.
+	///
+	/// ```
+	/// use langtag::{LangTag, ValidationError};
+	///
+	/// assert!(LangTag::new("sl-rozaj-biske").unwrap().validate_all().is_empty());
+	/// assert_eq!(
+	///   LangTag::new("en-Abcd-shadok").unwrap().validate_all(),
+	///   [
+	///     ValidationError::UnregisteredScript("Abcd".to_owned()),
+	///     ValidationError::UnregisteredVariant("shadok".to_owned())
+	///   ]
+	/// );
+	/// ```
+	pub fn validate_all(&self) -> Vec<ValidationError> {
+		match self.as_typed() {
+			TypedLangTag::Normal(t) => validate_normal(t),
+			TypedLangTag::PrivateUse(_) => Vec::new(),
+			TypedLangTag::Grandfathered(g) => {
+				if g.is_deprecated() {
+					vec![ValidationError::Deprecated {
+						subtag: g.as_str().to_owned(),
+						preferred_value: g.preferred_value_str(),
+					}]
+				} else {
+					Vec::new()
+				}
+			}
+		}
+	}
+}
+
+impl NormalLangTag {
+	/// Checks that every registered variant of this tag follows one of its
+	/// registered prefixes (see [`Variant::valid_prefixes`]).
+	///
+	/// Unregistered variants, and variants registered without prefix, are
+	/// accepted.
+	///
+	/// ```
+	/// use langtag::NormalLangTag;
+	///
+	/// assert!(NormalLangTag::new("sl-rozaj-biske").unwrap().variants_have_valid_prefixes());
+	/// assert!(!NormalLangTag::new("de-nedis").unwrap().variants_have_valid_prefixes());
+	/// ```
+	pub fn variants_have_valid_prefixes(&self) -> bool {
+		self.variants()
+			.iter()
+			.all(|variant| has_valid_prefix(self, variant))
+	}
+}
+
+fn validate_normal(tag: &NormalLangTag) -> Vec<ValidationError> {
+	let mut errors = Vec::new();
+
+	let language = tag.language();
+	let primary = language.primary();
+	match primary.registry_fields() {
+		Some((preferred_value @ Some(_), _)) => errors.push(ValidationError::Deprecated {
+			subtag: primary.to_string(),
+			preferred_value,
+		}),
+		Some(_) => (),
+		None => errors.push(ValidationError::UnregisteredLanguage(primary.to_string())),
+	}
+
+	for extlang in language.extension_subtags() {
+		match registry::extlang_prefix(extlang) {
+			Some(prefix) => {
+				if *primary != *prefix {
+					errors.push(ValidationError::InvalidExtendedLanguagePrefix {
+						extlang: extlang.to_string(),
+						prefix,
+					})
+				}
+			}
+			None => errors.push(ValidationError::UnregisteredExtendedLanguage(
+				extlang.to_string(),
+			)),
+		}
+	}
+
+	if let Some(script) = tag.script() {
+		if !script.is_registered() {
+			errors.push(ValidationError::UnregisteredScript(script.to_string()))
+		}
+	}
+
+	if let Some(region) = tag.region() {
+		if !region.is_registered() {
+			errors.push(ValidationError::UnregisteredRegion(region.to_string()))
+		} else if let Some(preferred_value) = registry::region_preferred_value(region) {
+			errors.push(ValidationError::Deprecated {
+				subtag: region.to_string(),
+				preferred_value: Some(preferred_value),
+			})
+		}
+	}
+
+	let variants = tag.variants();
+	for (i, variant) in variants.iter().enumerate() {
+		if variants.iter().take(i).any(|v| v == variant) {
+			errors.push(ValidationError::Structure(
+				StructureError::DuplicateVariant(variant.to_owned()),
+			))
+		}
+
+		if !variant.is_registered() {
+			errors.push(ValidationError::UnregisteredVariant(variant.to_string()))
+		} else if !has_valid_prefix(tag, variant) {
+			errors.push(ValidationError::InvalidVariantPrefix(variant.to_string()))
+		}
+	}
+
+	let extensions = tag.extensions();
+	for (i, extension) in extensions.iter().enumerate() {
+		let singleton = extension.singleton();
+		if extensions
+			.iter()
+			.take(i)
+			.any(|e| e.singleton().eq_ignore_case(singleton))
+		{
+			errors.push(ValidationError::Structure(
+				StructureError::DuplicateSingleton(singleton),
+			))
+		}
+	}
+
+	errors
+}
+
+/// Checks that the given variant of `tag` follows one of its registered
+/// prefixes, if it has any.
+fn has_valid_prefix(tag: &NormalLangTag, variant: &Variant) -> bool {
+	let prefixes = variant.valid_prefixes();
+	prefixes.is_empty() || prefixes.iter().any(|p| has_prefix(tag, variant, p))
+}
+
+/// Checks that `tag` matches the given registered prefix of one of its
+/// variants.
+///
+/// Each component of the prefix is compared to the same component of the
+/// tag: the language subtags must be equal, and the script, region and
+/// variants of the prefix (if any) must be those of the tag. Variants of the
+/// prefix must precede `variant`.
+fn has_prefix(tag: &NormalLangTag, variant: &Variant, prefix: &str) -> bool {
+	let Ok(prefix) = NormalLangTag::new(prefix) else {
+		return false;
+	};
+
+	let mut preceding = tag
+		.variants()
+		.iter()
+		.take_while(|v| !std::ptr::eq(*v, variant));
+
+	prefix.language() == tag.language()
+		&& prefix.script().is_none_or(|s| tag.script() == Some(s))
+		&& prefix.region().is_none_or(|r| tag.region() == Some(r))
+		&& prefix.variants().iter().all(|p| preceding.any(|v| v == p))
+}
@@ -0,0 +1,105 @@
+use langtag::accept_language::{
+	negotiate, parse, parse_with, LanguageRange, MalformedQuality, Options,
+};
+use langtag::LangTagBuf;
+
+fn qualities(header: &str, options: Options) -> Vec<(String, f32)> {
+	parse_with(header, options)
+		.map(|e| {
+			let range = match e.range {
+				LanguageRange::Tag(tag) => tag.into_string(),
+				LanguageRange::Wildcard => "*".to_string(),
+			};
+
+			(range, e.quality)
+		})
+		.collect()
+}
+
+#[test]
+pub fn accept_language_quality() {
+	let drop = Options::default();
+	assert_eq!(
+		qualities("en-US, fr;q=0.8, de;Q=0.5 , *;q=0", drop),
+		[
+			("en-US".to_string(), 1.0),
+			("fr".to_string(), 0.8),
+			("de".to_string(), 0.5),
+			("*".to_string(), 0.0)
+		]
+	);
+	assert_eq!(qualities("en;q=", drop), [("en".to_string(), 1.0)]);
+	assert_eq!(qualities("en;q=0.", drop), [("en".to_string(), 0.0)]);
+	assert_eq!(qualities("en;q=0.125", drop), [("en".to_string(), 0.125)]);
+	assert_eq!(qualities("en;q=1.000", drop), [("en".to_string(), 1.0)]);
+	assert_eq!(qualities("en;q=abc, fr", drop), [("fr".to_string(), 1.0)]);
+	for q in [
+		"NaN", "inf", "1.5", "1.001", "-2", "-0", "+0.5", "2", "1e-1", "0.1234", ".5", "00.5",
+	] {
+		assert_eq!(qualities(&format!("en;q={q}"), drop), []);
+	}
+
+	let mut default = Options::default();
+	default.malformed_quality = MalformedQuality::Default;
+	assert_eq!(
+		qualities("en;q=abc, fr;q=0.5, de;q=1.5", default),
+		[
+			("en".to_string(), 1.0),
+			("fr".to_string(), 0.5),
+			("de".to_string(), 1.0)
+		]
+	);
+}
+
+#[test]
+pub fn accept_language_malformed_tags() {
+	assert_eq!(
+		qualities("en_US, , fr;q=0.5, 12", Options::default()),
+		[("fr".to_string(), 0.5)]
+	);
+}
+
+#[test]
+pub fn accept_language_sorted() {
+	let sorted: Vec<_> = parse("de;q=0.5, *;q=0.1, en-US, fr;q=0.8, en;q=0.8, es;q=x")
+		.map(|e| match e.range {
+			LanguageRange::Tag(tag) => tag.into_string(),
+			LanguageRange::Wildcard => "*".to_string(),
+		})
+		.collect();
+	assert_eq!(sorted, ["en-US", "fr", "en", "de", "*"]);
+}
+
+#[test]
+pub fn accept_language_negotiate() {
+	let available: Vec<LangTagBuf> = ["en-US", "fr", "zh-Hant"]
+		.into_iter()
+		.map(|t| t.parse().unwrap())
+		.collect();
+
+	assert_eq!(negotiate("fr-CH, en-US", &available).unwrap(), "fr");
+	assert_eq!(
+		negotiate("fr;q=0.8, en-US;q=0.8", &available).unwrap(),
+		"fr"
+	);
+	assert_eq!(
+		negotiate("fr;q=0.5, en-us-x-foo", &available).unwrap(),
+		"en-US"
+	);
+	assert_eq!(negotiate("zh-Hant-TW", &available).unwrap(), "zh-Hant");
+	assert_eq!(
+		negotiate("fr;q=0, es, *;q=0.1", &available).unwrap(),
+		"en-US"
+	);
+	assert_eq!(negotiate("fr;q=0, es", &available), None);
+	assert_eq!(negotiate("fr;q=0, *", &available).unwrap(), "en-US");
+	assert_eq!(
+		negotiate("en;q=0, fr;q=0, *", &available).unwrap(),
+		"zh-Hant"
+	);
+	assert_eq!(negotiate("en-US, en;q=0", &available), None);
+	assert_eq!(negotiate("fr-CH, fr;q=0", &available), None);
+	assert_eq!(negotiate("fr, fr-CA;q=0", &available).unwrap(), "fr");
+	assert_eq!(negotiate("", &available), None);
+	assert_eq!(negotiate("*", &[]), None);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "arbitrary")]
+mod common;
+
+use arbitrary::{Arbitrary, Unstructured};
+use langtag::{LangTag, LangTagBuf, NormalLangTag, NormalLangTagBuf};
+
+use common::bytes;
+
+#[test]
+pub fn arbitrary_lang_tags_are_valid() {
+	for seed in 0..10_000 {
+		let data = bytes(seed, 256);
+		let mut u = Unstructured::new(&data);
+
+		let tag = LangTagBuf::arbitrary(&mut u).unwrap();
+		assert!(LangTag::new(tag.as_str()).is_ok(), "{tag}");
+
+		let normal = NormalLangTagBuf::arbitrary(&mut u).unwrap();
+		assert!(NormalLangTag::new(normal.as_str()).is_ok(), "{normal}");
+	}
+}
+
+#[test]
+pub fn arbitrary_components_are_valid() {
+	use langtag::{ExtensionBuf, LanguageBuf, PrivateUseBuf, RegionBuf, ScriptBuf, VariantBuf};
+
+	for seed in 0..1_000 {
+		let data = bytes(seed, 256);
+		let mut u = Unstructured::new(&data);
+		LanguageBuf::arbitrary(&mut u).unwrap();
+		ScriptBuf::arbitrary(&mut u).unwrap();
+		RegionBuf::arbitrary(&mut u).unwrap();
+		VariantBuf::arbitrary(&mut u).unwrap();
+		ExtensionBuf::arbitrary(&mut u).unwrap();
+		PrivateUseBuf::arbitrary(&mut u).unwrap();
+	}
+}
@@ -0,0 +1,303 @@
+use langtag::{
+	canonicalize_all, canonicalize_and_dedup, Canonical, CanonicalizeOptions, LangTag, LangTagBuf,
+};
+use std::{
+	collections::{hash_map::DefaultHasher, HashSet},
+	hash::Hasher,
+};
+
+fn canonical_hash(tag: &str) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	LangTag::new(tag).unwrap().hash_canonical_into(&mut hasher);
+	hasher.finish()
+}
+
+#[test]
+pub fn hash_canonical_into() {
+	assert_eq!(
+		canonical_hash("en-b-ccc-a-bbb"),
+		canonical_hash("EN-a-bbb-b-ccc")
+	);
+	assert_eq!(canonical_hash("zh-hant-tw"), canonical_hash("ZH-Hant-tw"));
+	assert_eq!(canonical_hash("I-KLINGON"), canonical_hash("i-klingon"));
+	assert_ne!(
+		canonical_hash("en-a-bbb-b-ccc"),
+		canonical_hash("en-a-ccc-b-bbb")
+	);
+	assert_ne!(canonical_hash("en-US"), canonical_hash("en-GB"));
+
+	// The tag does not run into the data hashed after it.
+	let composite_hash = |tag: &str, data: &[u8]| {
+		let mut hasher = DefaultHasher::new();
+		LangTag::new(tag).unwrap().hash_canonical_into(&mut hasher);
+		hasher.write(data);
+		hasher.finish()
+	};
+	assert_ne!(composite_hash("en", b"-US"), composite_hash("en-US", b""));
+}
+
+#[test]
+pub fn canonical_wrapper() {
+	#[derive(PartialEq, Eq, Hash)]
+	struct Key<'a> {
+		tag: Canonical<&'a LangTag>,
+		id: u32,
+	}
+
+	let mut set = HashSet::new();
+	set.insert(Key {
+		tag: Canonical(LangTag::new("fr-b-bbb-a-aaa-x-foo").unwrap()),
+		id: 1,
+	});
+
+	assert!(set.contains(&Key {
+		tag: Canonical(LangTag::new("FR-a-AAA-b-bbb-x-FOO").unwrap()),
+		id: 1
+	}));
+	assert!(!set.contains(&Key {
+		tag: Canonical(LangTag::new("fr-a-aaa-b-bbb-x-foo").unwrap()),
+		id: 2
+	}));
+}
+
+#[test]
+pub fn write_canonical() {
+	let canonical = |s: &str| {
+		let mut result = String::new();
+		LangTag::new(s)
+			.unwrap()
+			.write_canonical(&mut result)
+			.unwrap();
+		result
+	};
+
+	assert_eq!(canonical("DE-aRaB-LY"), "de-Arab-LY");
+	assert_eq!(canonical("en-US-x-TWAIN"), "en-US-x-twain");
+	assert_eq!(canonical("X-Foo"), "x-foo");
+	assert_eq!(canonical("I-DEFAULT"), "i-default");
+	assert_eq!(canonical("fr-b-bbb-A-AAA-x-b-c"), "fr-a-aaa-b-bbb-x-b-c");
+}
+
+#[test]
+pub fn canonicalize_into() {
+	let mut s = String::with_capacity(64);
+	s.push_str("und");
+	let mut out = langtag::LangTagBuf::new(s).unwrap();
+	let capacity = out.heap_size();
+
+	LangTag::new("DE-aRaB-LY")
+		.unwrap()
+		.canonicalize_into(&mut out);
+	assert_eq!(out.as_str(), "de-Arab-LY");
+	LangTag::new("x-FOO").unwrap().canonicalize_into(&mut out);
+	assert_eq!(out.as_str(), "x-foo");
+	assert!(out.is_private_use());
+	assert_eq!(out.heap_size(), capacity);
+}
+
+#[test]
+pub fn canonicalize_with() {
+	let canonical = |s: &str, options| LangTag::new(s).unwrap().canonicalize_with(options);
+	let default = CanonicalizeOptions::default();
+	let none = CanonicalizeOptions::NONE;
+
+	let tag = "DE-aRaB-LY-b-CCC-a-bbb-x-Foo-bar";
+	assert_eq!(canonical(tag, default), "de-Arab-LY-a-bbb-b-ccc-x-foo-bar");
+	assert_eq!(canonical(tag, none).as_str(), tag);
+	assert_eq!(
+		canonical(
+			tag,
+			CanonicalizeOptions {
+				sort_extensions: true,
+				..none
+			}
+		)
+		.as_str(),
+		"DE-aRaB-LY-a-bbb-b-CCC-x-Foo-bar"
+	);
+	assert_eq!(
+		canonical(
+			tag,
+			CanonicalizeOptions {
+				sort_private_use: true,
+				..default
+			}
+		)
+		.as_str(),
+		"de-Arab-LY-a-bbb-b-ccc-x-bar-foo"
+	);
+	assert_eq!(canonical("X-Foo", default).as_str(), "x-foo");
+	assert_eq!(canonical("X-Foo", none).as_str(), "X-Foo");
+}
+
+#[test]
+pub fn cache_key() {
+	let key = |s: &str| LangTag::new(s).unwrap().cache_key();
+
+	assert_eq!(key("en"), "en");
+	assert_eq!(key("DE-aRaB-LY"), "de-arab-ly");
+	assert_eq!(key("zh-CMN-Hant"), "zh-cmn-hant");
+	assert_eq!(key("fr-b-BBB-A-aaa-x-Foo-bar"), "fr-a-aaa-b-bbb-x-foo-bar");
+	assert_eq!(key("X-Foo"), "x-foo");
+	assert_eq!(key("I-Klingon"), "i-klingon");
+	assert_eq!(key("en-b-ccc-a-bbb"), key("EN-a-bbb-b-ccc"));
+}
+
+#[test]
+pub fn canonicalize_batch() {
+	let tags: Vec<_> = [
+		"fr-b-bbb-a-aaa",
+		"EN-us",
+		"FR-a-aaa-b-BBB",
+		"x-foo",
+		"en-US",
+	]
+	.into_iter()
+	.map(|t| LangTag::new(t).unwrap())
+	.collect();
+
+	assert_eq!(
+		canonicalize_all(tags.iter().copied(), CanonicalizeOptions::default()),
+		[
+			"fr-a-aaa-b-bbb",
+			"en-US",
+			"fr-a-aaa-b-bbb",
+			"x-foo",
+			"en-US"
+		]
+	);
+	assert_eq!(
+		canonicalize_and_dedup(tags.iter().copied(), CanonicalizeOptions::default()),
+		["fr-a-aaa-b-bbb", "en-US", "x-foo"]
+	);
+}
+
+#[test]
+pub fn store() {
+	let store = |s: &str| LangTag::new(s).unwrap().store().into_string();
+
+	assert_eq!(store("en"), "en");
+	assert_eq!(store("DE-aRaB-LY"), "de-arab-ly");
+	assert_eq!(store("zh-CMN-Hant"), "zh-cmn-hant");
+	assert_eq!(store("sgn-BE-FR"), "sgn-be-fr");
+	assert_eq!(
+		store("fr-b-BBB-A-aaa-x-Foo-bar"),
+		"fr-a-aaa-b-bbb-x-foo-bar"
+	);
+	assert_eq!(store("X-Foo"), "x-foo");
+	assert_eq!(store("I-Klingon"), "i-klingon");
+}
+
+#[test]
+pub fn to_canonical() {
+	let canonical = |s: &str| LangTag::new(s).unwrap().to_canonical().into_string();
+
+	assert_eq!(canonical("DE-aRaB-LY"), "de-Arab-LY");
+	assert_eq!(canonical("ES-419"), "es-419");
+	assert_eq!(
+		canonical("sr-latn-rs-B-Foo-A-bar-X-Priv"),
+		"sr-Latn-RS-b-foo-a-bar-x-priv"
+	);
+	assert_eq!(canonical("EN-gb-OED"), "en-GB-oed");
+	assert_eq!(canonical("X-Foo"), "x-foo");
+
+	let tag = langtag::LangTagBuf::new("zh-HANT-tw".to_owned()).unwrap();
+	assert_eq!(tag.canonical().as_str(), "zh-Hant-TW");
+	assert_eq!(tag.canonical(), tag);
+}
+
+#[test]
+pub fn canonical_sort_extensions() {
+	let sort = |tag: &str| {
+		let mut tag: LangTagBuf = tag.parse().unwrap();
+		tag.sort_extensions();
+		tag
+	};
+
+	assert_eq!(sort("fr-b-ext3-a-ext1"), "fr-a-ext1-b-ext3");
+	assert_eq!(
+		sort("en-US-z-zzz-B-bbb-ccc-1-one-x-z-a"),
+		"en-US-1-one-B-bbb-ccc-z-zzz-x-z-a"
+	);
+	assert_eq!(sort("fr-a-ext1-b-ext3"), "fr-a-ext1-b-ext3");
+	assert_eq!(sort("fr-x-b-a"), "fr-x-b-a");
+	assert_eq!(sort("i-klingon"), "i-klingon");
+	assert!(LangTag::new(&sort("de-u-co-phonebk-t-en-a-bbb")).is_ok());
+}
+
+#[test]
+pub fn is_canonical() {
+	let canonical = |s: &str| LangTag::new(s).unwrap().is_canonical();
+
+	assert!(canonical("en"));
+	assert!(canonical("de-Arab-LY"));
+	assert!(canonical("sr-Latn-RS-a-bar-b-foo-x-priv"));
+	assert!(canonical("en-GB-oed"));
+	assert!(canonical("i-default"));
+	assert!(canonical("x-foo"));
+
+	assert!(!canonical("EN"));
+	assert!(!canonical("de-ARAB-LY"));
+	assert!(!canonical("de-Arab-ly"));
+	assert!(!canonical("sr-Latn-RS-b-foo-a-bar"));
+	assert!(!canonical("sr-Latn-RS-a-BAR"));
+	assert!(!canonical("EN-gb-OED"));
+	assert!(!canonical("X-Foo"));
+
+	for s in ["DE-aRaB-LY", "sr-latn-rs-B-Foo-A-bar-X-Priv", "I-Klingon"] {
+		let tag = LangTag::new(s).unwrap();
+		let mut canonical = tag.to_canonical();
+		canonical.sort_extensions();
+		assert!(canonical.is_canonical());
+	}
+}
+
+#[test]
+pub fn canonical_cmp() {
+	use std::cmp::Ordering;
+
+	let cmp = |a: &str, b: &str| {
+		LangTag::new(a)
+			.unwrap()
+			.canonical_cmp(LangTag::new(b).unwrap())
+	};
+
+	assert_eq!(cmp("en-a-bbb-b-ccc", "en-b-ccc-a-bbb"), Ordering::Equal);
+	assert_eq!(
+		cmp("EN-b-CCC-a-bbb-x-foo", "en-a-bbb-b-ccc-x-FOO"),
+		Ordering::Equal
+	);
+	assert_eq!(cmp("en-b-ccc-a-bbb", "en-a-ccc"), Ordering::Less);
+	assert_eq!(cmp("en-z-zzz-a-aaa", "en-b-bbb"), Ordering::Less);
+	assert_eq!(cmp("en-US", "en"), Ordering::Greater);
+	assert_eq!(cmp("en-x-b-a", "en-x-a-b"), Ordering::Greater);
+	assert_eq!(cmp("i-klingon", "I-KLINGON"), Ordering::Equal);
+
+	let tags = [
+		"en-b-ccc-a-bbb",
+		"EN-a-bbb-b-ccc",
+		"fr-x-foo",
+		"en-a-ccc",
+		"de-CH-1996",
+	];
+	for a in tags {
+		for b in tags {
+			let (a, b) = (LangTag::new(a).unwrap(), LangTag::new(b).unwrap());
+			assert_eq!(a.canonical_cmp(b).is_eq(), a.canonical_eq(b));
+			assert_eq!(a.canonical_cmp(b), b.canonical_cmp(a).reverse());
+		}
+	}
+
+	let mut sorted = tags.map(|t| LangTag::new(t).unwrap());
+	sorted.sort_by(|a, b| a.canonical_cmp(b));
+	assert_eq!(
+		sorted,
+		[
+			"de-CH-1996",
+			"en-b-ccc-a-bbb",
+			"EN-a-bbb-b-ccc",
+			"en-a-ccc",
+			"fr-x-foo"
+		]
+	);
+}
@@ -0,0 +1,15 @@
+//! Fixtures shared by the integration tests.
+
+/// Deterministic pseudo-random bytes (xorshift), used to drive
+/// `arbitrary::Unstructured`.
+pub fn bytes(seed: u64, len: usize) -> Vec<u8> {
+	let mut state = seed.wrapping_mul(0x9E3779B97F4A7C15) | 1;
+	(0..len)
+		.map(|_| {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state as u8
+		})
+		.collect()
+}
@@ -1,4 +1,4 @@
-use langtag::LangTag;
+use langtag::{LangTag, NormalLangTagBuf};
 use std::convert::TryInto;
 
 #[test]
@@ -29,57 +29,223 @@ pub fn extensions_iter_extension() {
 	assert_eq!(it.next(), None);
 }
 
-// #[test]
-// pub fn extensions_mut_insert() {
-// 	let mut tag = LangTag::new("fr-a-ext1-b-ext2").unwrap();
-// 	tag.extensions_mut()
-// 		.insert('a'.try_into().unwrap(), "ext3".try_into().unwrap());
-// 	tag.extensions_mut()
-// 		.insert('c'.try_into().unwrap(), "ext4".try_into().unwrap());
-// 	tag.extensions_mut()
-// 		.insert('b'.try_into().unwrap(), "ext5".try_into().unwrap());
-// 	tag.extensions_mut()
-// 		.insert('c'.try_into().unwrap(), "ext6".try_into().unwrap());
-// 	assert_eq!(tag, "fr-a-ext1-ext3-b-ext2-ext5-c-ext4-ext6")
-// }
-
-// #[test]
-// pub fn extensions_mut_remove() {
-// 	let mut tag =
-// 		LangTag::new("fr-a-ext1-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8")
-// 			.unwrap();
-// 	tag.extensions_mut().remove('a'.try_into().unwrap());
-// 	assert_eq!(tag, "fr-b-ext3-ext4-c-ext7")
-// }
-
-// #[test]
-// pub fn extensions_mut_remove_subtag() {
-// 	let mut tag =
-// 		LangTag::new("fr-a-ext1-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9")
-// 			.unwrap();
-// 	let a: langtag::Singleton = 'a'.try_into().unwrap();
-// 	let b: langtag::Singleton = 'b'.try_into().unwrap();
-// 	let c: langtag::Singleton = 'c'.try_into().unwrap();
-// 	tag.extensions_mut().remove_subtag(a, "ext1");
-// 	assert_eq!(
-// 		tag,
-// 		"fr-a-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9"
-// 	);
-// 	tag.extensions_mut().remove_subtag(a, "ext2");
-// 	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9");
-// 	tag.extensions_mut().remove_subtag(a, "ext8");
-// 	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext9");
-// 	tag.extensions_mut().remove_subtag(a, "ext9");
-// 	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7");
-// 	tag.extensions_mut().remove_subtag(a, "ext5");
-// 	assert_eq!(tag, "fr-b-ext3-ext4-a-ext6-c-ext7");
-// 	tag.extensions_mut().remove_subtag(a, "ext6");
-// 	assert_eq!(tag, "fr-b-ext3-ext4-c-ext7");
-
-// 	tag.extensions_mut().remove_subtag(c, "ext7");
-// 	assert_eq!(tag, "fr-b-ext3-ext4");
-// 	tag.extensions_mut().remove_subtag(b, "ext3");
-// 	assert_eq!(tag, "fr-b-ext4");
-// 	tag.extensions_mut().remove_subtag(b, "ext4");
-// 	assert_eq!(tag, "fr");
-// }
+#[test]
+pub fn extensions_canonically_ordered() {
+	let tag = LangTag::new("en-a-xx-b-yy").unwrap();
+	assert!(tag.extensions().is_canonically_ordered());
+	let tag = LangTag::new("en-b-yy-a-xx").unwrap();
+	assert!(!tag.extensions().is_canonically_ordered());
+	let tag = LangTag::new("en-1-zz-A-xx-b-yy-x-priv").unwrap();
+	assert!(tag.extensions().is_canonically_ordered());
+	let tag = LangTag::new("en-a-xx-A-yy").unwrap();
+	assert!(!tag.extensions().is_canonically_ordered());
+	let tag = LangTag::new("en-u-ca-gregory").unwrap();
+	assert!(tag.extensions().is_canonically_ordered());
+	let tag = LangTag::new("en").unwrap();
+	assert!(tag.extensions().is_canonically_ordered());
+}
+
+#[test]
+pub fn extensions_sorted() {
+	let tag = LangTag::new("fr-c-ext5-A-ext1-b-ext3-a-ext2-x-priv").unwrap();
+	let mut it = tag.extensions_sorted();
+	assert_eq!(it.next().unwrap(), "A-ext1");
+	assert_eq!(it.next().unwrap(), "a-ext2");
+	assert_eq!(it.next().unwrap(), "b-ext3");
+	assert_eq!(it.next().unwrap(), "c-ext5");
+	assert_eq!(it.next(), None);
+
+	let tag = LangTag::new("x-foo").unwrap();
+	assert_eq!(tag.extensions_sorted().next(), None);
+}
+
+#[test]
+pub fn extensions_without_singleton() {
+	let a = 'a'.try_into().unwrap();
+	let tag = LangTag::new("fr-a-ext1-ext2-b-ext3").unwrap();
+	assert_eq!(tag.without_singleton(a), "fr-b-ext3");
+	let tag = LangTag::new("fr-b-ext3-A-ext1-x-priv").unwrap();
+	assert_eq!(tag.without_singleton(a), "fr-b-ext3-x-priv");
+	let tag = LangTag::new("fr-a-ext1").unwrap();
+	assert_eq!(tag.without_singleton(a), "fr");
+	let tag = LangTag::new("fr-b-ext3-x-a-foo").unwrap();
+	assert_eq!(tag.without_singleton(a), "fr-b-ext3-x-a-foo");
+	let tag = LangTag::new("i-klingon").unwrap();
+	assert_eq!(tag.without_singleton(a), "i-klingon");
+}
+
+#[test]
+pub fn extensions_subtag_values() {
+	let a = 'a'.try_into().unwrap();
+	let tag = LangTag::new("fr-A-ext1-EXT2-b-ext3-x-a-priv").unwrap();
+	assert!(tag.extension_subtag_values(a).eq(["ext1", "EXT2"]));
+	let b = 'B'.try_into().unwrap();
+	assert!(tag.extension_subtag_values(b).eq(["ext3"]));
+	let c = 'c'.try_into().unwrap();
+	assert_eq!(tag.extension_subtag_values(c).next(), None);
+	let tag = LangTag::new("x-a-priv").unwrap();
+	assert_eq!(tag.extension_subtag_values(a).next(), None);
+}
+
+#[test]
+pub fn extensions_mut_insert() {
+	let mut tag = NormalLangTagBuf::new("fr-a-ext1-b-ext2".to_owned()).unwrap();
+	tag.extensions_mut()
+		.insert('a'.try_into().unwrap(), "ext3".try_into().unwrap());
+	tag.extensions_mut()
+		.insert('c'.try_into().unwrap(), "ext4".try_into().unwrap());
+	tag.extensions_mut()
+		.insert('b'.try_into().unwrap(), "ext5".try_into().unwrap());
+	tag.extensions_mut()
+		.insert('c'.try_into().unwrap(), "ext6".try_into().unwrap());
+	assert_eq!(tag, "fr-a-ext1-ext3-b-ext2-ext5-c-ext4-ext6")
+}
+
+#[test]
+pub fn extensions_mut_remove() {
+	let mut tag =
+		NormalLangTagBuf::new("fr-a-ext1-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8".to_owned())
+			.unwrap();
+	tag.extensions_mut().remove('a'.try_into().unwrap());
+	assert_eq!(tag, "fr-b-ext3-ext4-c-ext7")
+}
+
+#[test]
+pub fn extensions_mut_remove_subtag() {
+	let mut tag = NormalLangTagBuf::new(
+		"fr-a-ext1-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9".to_owned(),
+	)
+	.unwrap();
+	let a: langtag::Singleton = 'a'.try_into().unwrap();
+	let b: langtag::Singleton = 'b'.try_into().unwrap();
+	let c: langtag::Singleton = 'c'.try_into().unwrap();
+	tag.extensions_mut().remove_subtag(a, "ext1");
+	assert_eq!(
+		tag,
+		"fr-a-ext2-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9"
+	);
+	tag.extensions_mut().remove_subtag(a, "ext2");
+	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext8-ext9");
+	tag.extensions_mut().remove_subtag(a, "ext8");
+	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7-a-ext9");
+	tag.extensions_mut().remove_subtag(a, "ext9");
+	assert_eq!(tag, "fr-b-ext3-ext4-a-ext5-a-ext6-c-ext7");
+	tag.extensions_mut().remove_subtag(a, "ext5");
+	assert_eq!(tag, "fr-b-ext3-ext4-a-ext6-c-ext7");
+	tag.extensions_mut().remove_subtag(a, "ext6");
+	assert_eq!(tag, "fr-b-ext3-ext4-c-ext7");
+
+	tag.extensions_mut().remove_subtag(c, "ext7");
+	assert_eq!(tag, "fr-b-ext3-ext4");
+	tag.extensions_mut().remove_subtag(b, "ext3");
+	assert_eq!(tag, "fr-b-ext4");
+	tag.extensions_mut().remove_subtag(b, "ext4");
+	assert_eq!(tag, "fr");
+}
+
+#[test]
+pub fn extensions_mut_private_use() {
+	let a: langtag::Singleton = 'a'.try_into().unwrap();
+	let mut tag = NormalLangTagBuf::new("fr-FR-x-a-priv".to_owned()).unwrap();
+	tag.extensions_mut().insert(a, "ext1".try_into().unwrap());
+	assert_eq!(tag, "fr-FR-a-ext1-x-a-priv");
+	assert_eq!(tag.private_use().unwrap(), "x-a-priv");
+	assert!(tag.extensions_mut().remove_subtag(a, "EXT1"));
+	assert_eq!(tag, "fr-FR-x-a-priv");
+	assert_eq!(tag.private_use().unwrap(), "x-a-priv");
+	assert!(!tag.extensions_mut().remove(a));
+}
+
+#[test]
+pub fn extensions_count() {
+	let tag = LangTag::new("fr-a-ext1-ext2-b-ext3-x-foo").unwrap();
+	assert_eq!(tag.extensions().count(), 2);
+	assert_eq!(tag.extensions().subtag_count(), 3);
+
+	let tag = LangTag::new("fr-x-foo").unwrap();
+	assert_eq!(tag.extensions().count(), 0);
+	assert_eq!(tag.extensions().subtag_count(), 0);
+}
+
+#[test]
+pub fn extensions_contains_singleton() {
+	use langtag::Singleton;
+
+	let tag = LangTag::new("fr-a-ext1-U-ext2-x-b").unwrap();
+	assert!(tag
+		.extensions()
+		.contains_singleton(Singleton::new(b'a').unwrap()));
+	assert!(tag
+		.extensions()
+		.contains_singleton(Singleton::new(b'u').unwrap()));
+	assert!(!tag
+		.extensions()
+		.contains_singleton(Singleton::new(b'b').unwrap()));
+}
+
+#[test]
+pub fn extensions_iter_rev() {
+	let tag = LangTag::new("fr-a-ext1-ext2-b-ext3").unwrap();
+	let extensions: Vec<_> = tag.extensions().iter().rev().map(|e| e.as_str()).collect();
+	assert_eq!(extensions, ["b-ext3", "a-ext1-ext2"]);
+
+	let mut it = tag.extensions().iter();
+	assert_eq!(it.len(), 2);
+	assert_eq!(it.next_back().unwrap(), "b-ext3");
+	assert_eq!(it.len(), 1);
+	assert_eq!(it.next().unwrap(), "a-ext1-ext2");
+	assert_eq!(it.next(), None);
+	assert_eq!(it.next_back(), None);
+
+	let mut it = tag.extensions().iter();
+	assert_eq!(it.next().unwrap(), "a-ext1-ext2");
+	assert_eq!(it.next_back().unwrap(), "b-ext3");
+	assert_eq!(it.next_back(), None);
+	assert_eq!(it.next(), None);
+
+	let extension = tag.extensions().iter().next().unwrap();
+	let mut it = extension.iter();
+	assert_eq!(it.next_back().unwrap(), "ext2");
+	assert_eq!(it.next().unwrap(), "ext1");
+	assert_eq!(it.next_back(), None);
+	assert_eq!(it.next(), None);
+}
+
+#[test]
+pub fn singleton_all() {
+	use langtag::Singleton;
+
+	let all: Vec<Singleton> = Singleton::all().collect();
+	assert_eq!(all.len(), 35);
+	assert_eq!(all[0], '0');
+	assert_eq!(all[10], 'a');
+	assert_eq!(*all.last().unwrap(), 'z');
+	assert!(all.windows(2).all(|w| w[0] < w[1]));
+	assert!(all.iter().all(|s| *s != 'x'));
+}
+
+#[test]
+pub fn extensions_iter_subtags() {
+	let tag = LangTag::new("fr-a-ext1-ext2-b-ext3-x-foo").unwrap();
+	let subtags: Vec<_> = tag
+		.extensions()
+		.iter_subtags()
+		.map(|(singleton, subtag)| (singleton.to_string(), subtag.as_str()))
+		.collect();
+	assert_eq!(
+		subtags,
+		[
+			("a".to_owned(), "ext1"),
+			("a".to_owned(), "ext2"),
+			("b".to_owned(), "ext3")
+		]
+	);
+	assert_eq!(
+		LangTag::new("fr")
+			.unwrap()
+			.extensions()
+			.iter_subtags()
+			.count(),
+		0
+	);
+}
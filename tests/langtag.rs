@@ -1,4 +1,4 @@
-use langtag::NormalLangTag;
+use langtag::{NormalLangTag, NormalLangTagBuf};
 
 #[test]
 pub fn langtag_script_region() {
@@ -8,20 +8,96 @@ pub fn langtag_script_region() {
 	assert_eq!(tag.region().unwrap(), "bz");
 }
 
-// #[test]
-// pub fn langtag_set_script() {
-// 	let mut tag = NormalLangTag::new("fr-abc-bz-e-foo").unwrap();
-// 	tag.set_script(Some("nice".try_into().unwrap()));
-// 	assert_eq!(tag, "fr-abc-nice-bz-e-foo");
-// 	assert_eq!(tag.script().unwrap(), "nice");
-// 	assert_eq!(tag.region().unwrap(), "bz");
-// }
-
-// #[test]
-// pub fn langtag_set_region() {
-// 	let mut tag = NormalLangTag::new("fr-abc-bz-e-foo").unwrap();
-// 	tag.set_region(Some("no".try_into().unwrap()));
-// 	assert_eq!(tag, "fr-abc-no-e-foo");
-// 	assert_eq!(tag.region().unwrap(), "no");
-// 	assert_eq!(tag.extensions(), "e-foo");
-// }
+#[test]
+pub fn langtag_set_script() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-bz-e-foo".to_owned()).unwrap();
+	tag.set_script(Some("nice".try_into().unwrap()));
+	assert_eq!(tag, "fr-abc-nice-bz-e-foo");
+	assert_eq!(tag.script().unwrap(), "nice");
+	assert_eq!(tag.region().unwrap(), "bz");
+}
+
+#[test]
+pub fn langtag_set_region() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-bz-e-foo".to_owned()).unwrap();
+	tag.set_region(Some("no".try_into().unwrap()));
+	assert_eq!(tag, "fr-abc-no-e-foo");
+	assert_eq!(tag.region().unwrap(), "no");
+	assert_eq!(tag.extensions(), "e-foo");
+}
+
+#[test]
+pub fn langtag_unset_script_region() {
+	let mut tag = NormalLangTagBuf::new("sr-Latn-RS-x-foo".to_owned()).unwrap();
+	tag.set_region(None);
+	assert_eq!(tag, "sr-Latn-x-foo");
+	tag.set_script(None);
+	assert_eq!(tag, "sr-x-foo");
+	tag.set_script(None);
+	assert_eq!(tag, "sr-x-foo");
+	tag.set_region(Some("ME".try_into().unwrap()));
+	assert_eq!(tag, "sr-ME-x-foo");
+	tag.set_script(Some("Cyrl".try_into().unwrap()));
+	assert_eq!(tag, "sr-Cyrl-ME-x-foo");
+	tag.set_region(Some("RS".try_into().unwrap()));
+	assert_eq!(tag, "sr-Cyrl-RS-x-foo");
+	assert_eq!(tag.private_use().unwrap(), "x-foo");
+}
+
+#[test]
+pub fn langtag_eq_ignoring_extensions() {
+	let eq = |a: &str, b: &str| {
+		NormalLangTag::new(a)
+			.unwrap()
+			.eq_ignoring_extensions(NormalLangTag::new(b).unwrap())
+	};
+
+	assert!(eq("en-US-a-foo", "en-US-b-bar"));
+	assert!(eq("en-US-a-foo", "EN-us-x-baz"));
+	assert!(eq("en-US", "en-US-a-foo-x-bar"));
+	assert!(eq("de-CH-1996-a-foo", "de-ch-1996"));
+	assert!(!eq("en-US-a-foo", "en-GB-a-foo"));
+	assert!(!eq("de-CH-1996", "de-CH"));
+}
+
+#[test]
+pub fn langtag_eq_ignoring_private_use() {
+	let eq = |a: &str, b: &str| {
+		NormalLangTag::new(a)
+			.unwrap()
+			.eq_ignoring_private_use(NormalLangTag::new(b).unwrap())
+	};
+
+	assert!(eq("en-US-x-foo", "en-US"));
+	assert!(eq("en-US-a-foo-x-foo", "EN-us-A-FOO-x-bar"));
+	assert!(!eq("en-US-a-foo", "en-US-b-bar"));
+	assert!(!eq("en-US-x-foo", "en-GB-x-foo"));
+}
+
+#[test]
+pub fn langtag_parsed() {
+	for s in [
+		"fr",
+		"fr-abc-nice-bz-e-foo",
+		"zh-cmn-Hans-CN",
+		"de-CH-1901-1996",
+		"en-US-a-bbb-x-a-ccc",
+		"qaa-Qaaa-QM-x-southern",
+		"sl-rozaj-biske-1994",
+		"en-x-US",
+	] {
+		let tag = NormalLangTag::new(s).unwrap();
+		let parsed = tag.parsed();
+		assert_eq!(parsed.language(), tag.language());
+		assert_eq!(parsed.script(), tag.script());
+		assert_eq!(parsed.region(), tag.region());
+		assert_eq!(parsed.variants(), tag.variants());
+		assert_eq!(parsed.extensions(), tag.extensions());
+		assert_eq!(parsed.private_use(), tag.private_use());
+		assert!(parsed.private_use_subtags().eq(tag.private_use_subtags()));
+
+		let owned = NormalLangTagBuf::new(s.to_owned()).unwrap().into_parsed();
+		assert_eq!(owned.variants(), tag.variants());
+		assert_eq!(owned.into_inner(), s);
+	}
+}
@@ -1,4 +1,4 @@
-use langtag::{LangTag, NormalLangTag};
+use langtag::{LangTag, NormalLangTag, NormalLangTagBuf};
 
 #[test]
 pub fn language_eq() {
@@ -12,23 +12,85 @@ pub fn language_primary_eq() {
 	assert_eq!(tag.language().primary(), "fr");
 }
 
-// #[test]
-// pub fn language_primary_set() {
-// 	let mut tag = NormalLangTag::new("fr-abc-def-ghi-bz").unwrap();
-// 	tag.language_mut().set_primary("foo".try_into().unwrap());
-// 	assert_eq!(tag, "foo-abc-def-ghi-bz");
-// 	assert_eq!(tag.language().extension().unwrap(), "abc-def-ghi");
-// 	assert_eq!(tag.region().unwrap(), "bz");
-// }
+#[test]
+pub fn language_primary_new() {
+	use langtag::PrimaryLanguage;
+
+	assert!(PrimaryLanguage::new("en").is_ok());
+	assert!(PrimaryLanguage::new("fr").is_ok());
+	assert!(PrimaryLanguage::new("fra").is_ok());
+	assert!(PrimaryLanguage::new("abcd").is_ok());
+	assert!(PrimaryLanguage::new("abcde").is_ok());
+	assert!(PrimaryLanguage::new("foobar").is_ok());
+	assert!(PrimaryLanguage::new("abcdefgh").is_ok());
+	assert!(PrimaryLanguage::new("fr1").is_err());
+	assert!(PrimaryLanguage::new("f").is_err());
+	assert!(PrimaryLanguage::new("fr-CA").is_err());
+	assert!(PrimaryLanguage::new("foobarbaz").is_err());
+}
+
+#[test]
+pub fn language_primary_kind() {
+	use langtag::{PrimaryLanguage, PrimaryLanguageKind};
+
+	let kind = |s: &str| PrimaryLanguage::new(s).unwrap().kind();
+	assert_eq!(kind("EN"), PrimaryLanguageKind::TwoLetter);
+	assert_eq!(kind("ast"), PrimaryLanguageKind::ThreeLetter);
+	assert_eq!(kind("qaa"), PrimaryLanguageKind::ThreeLetter);
+	assert_eq!(kind("abcd"), PrimaryLanguageKind::Reserved);
+	assert_eq!(kind("dinka"), PrimaryLanguageKind::Registered);
+	assert_eq!(kind("abcdefgh"), PrimaryLanguageKind::Registered);
+
+	assert!(kind("zh").allows_extlang());
+	assert!(!kind("abcd").allows_extlang());
+
+	let tag = NormalLangTag::new("zh-yue-HK").unwrap();
+	assert_eq!(
+		tag.language().primary().kind(),
+		PrimaryLanguageKind::TwoLetter
+	);
+}
+
+#[test]
+pub fn language_primary_is_one_of() {
+	use langtag::PrimaryLanguage;
+
+	let en = PrimaryLanguage::new("en").unwrap();
+	let fr = PrimaryLanguage::new("FR").unwrap();
+	let supported = [en, fr];
+
+	assert!(LangTag::new("fr-CA").unwrap().primary_is_one_of(&supported));
+	assert!(LangTag::new("EN").unwrap().primary_is_one_of(&supported));
+	assert!(!LangTag::new("de-CH").unwrap().primary_is_one_of(&supported));
+	assert!(!LangTag::new("x-en").unwrap().primary_is_one_of(&supported));
+	assert!(!LangTag::new("i-klingon")
+		.unwrap()
+		.primary_is_one_of(&supported));
+	assert!(LangTag::new("art-lojban")
+		.unwrap()
+		.primary_is_one_of_str(&["art"]));
+	assert!(LangTag::new("zh-yue-HK")
+		.unwrap()
+		.primary_is_one_of_str(&["ZH"]));
+}
 
-// #[test]
-// pub fn language_primary_set_long() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-abc-def-ghi-bz").unwrap();
-// 	tag.language_mut().set_primary("foobar".try_into().unwrap());
-// 	assert_eq!(tag, "foobar-bz");
-// 	assert_eq!(tag.language().extension(), None);
-// 	assert_eq!(tag.region().unwrap(), "bz");
-// }
+#[test]
+pub fn language_primary_set() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-def-ghi-bz".to_owned()).unwrap();
+	tag.language_mut().set_primary("foo".try_into().unwrap());
+	assert_eq!(tag, "foo-abc-def-ghi-bz");
+	assert_eq!(tag.language().extension().unwrap(), "abc-def-ghi");
+	assert_eq!(tag.region().unwrap(), "bz");
+}
+
+#[test]
+pub fn language_primary_set_long() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-def-ghi-bz".to_owned()).unwrap();
+	tag.language_mut().set_primary("foobar".try_into().unwrap());
+	assert_eq!(tag, "foobar-bz");
+	assert_eq!(tag.language().extension(), None);
+	assert_eq!(tag.region().unwrap(), "bz");
+}
 
 #[test]
 pub fn language_ext_eq() {
@@ -36,27 +98,143 @@ pub fn language_ext_eq() {
 	assert_eq!(tag.language().extension().unwrap(), "abc-def-ghi");
 }
 
-// #[test]
-// pub fn language_ext_mut_insert() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-abc-bz").unwrap();
-// 	assert_eq!(
-// 		tag.language_mut()
-// 			.extension_mut()
-// 			.unwrap()
-// 			.insert("def".try_into().unwrap()),
-// 		true
-// 	);
-// 	assert_eq!(tag, "fr-abc-def-bz");
-// 	assert_eq!(tag.region().unwrap(), "bz");
-// }
-
-// #[test]
-// pub fn language_ext_mut_remove() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-abc-def-ghi-bz").unwrap();
-// 	assert_eq!(
-// 		tag.language_mut().extension_mut().unwrap().remove("def"),
-// 		true
-// 	);
-// 	assert_eq!(tag, "fr-abc-ghi-bz");
-// 	assert_eq!(tag.region().unwrap(), "bz");
-// }
+#[test]
+pub fn language_ext_mut_insert() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-bz".to_owned()).unwrap();
+	assert!(tag
+		.language_mut()
+		.extension_mut()
+		.unwrap()
+		.insert("def".try_into().unwrap()));
+	assert_eq!(tag, "fr-abc-def-bz");
+	assert_eq!(tag.region().unwrap(), "bz");
+
+	let mut language = tag.language_mut();
+	let mut ext = language.extension_mut().unwrap();
+	assert!(!ext.insert("DEF".try_into().unwrap()));
+	assert!(ext.insert("ghi".try_into().unwrap()));
+	assert!(!ext.insert("jkl".try_into().unwrap()));
+	assert_eq!(tag, "fr-abc-def-ghi-bz");
+
+	let mut tag = NormalLangTagBuf::new("foobar-bz".to_owned()).unwrap();
+	assert!(tag.language_mut().extension_mut().is_none());
+}
+
+#[test]
+pub fn language_ext_mut_remove() {
+	let mut tag = NormalLangTagBuf::new("fr-abc-def-ghi-bz".to_owned()).unwrap();
+	assert!(tag.language_mut().extension_mut().unwrap().remove("DEF"));
+	assert_eq!(tag, "fr-abc-ghi-bz");
+	assert!(!tag.language_mut().extension_mut().unwrap().remove("def"));
+	assert_eq!(tag.region().unwrap(), "bz");
+}
+
+#[test]
+pub fn language_ext_iter_rev() {
+	let tag = NormalLangTag::new("fr-abc-def-ghi-bz").unwrap();
+	assert_eq!(tag.language().extension_subtags().len(), 3);
+	let extlangs: Vec<_> = tag.language().extension_subtags().rev().collect();
+	assert_eq!(extlangs, ["ghi", "def", "abc"]);
+}
+
+#[test]
+pub fn language_extended_primary() {
+	use langtag::{GrandfatheredLangTag, Language};
+
+	let language = Language::new("zh-cmn").unwrap();
+	assert!(language.is_extlang_form());
+	assert_eq!(language.extended_primary().unwrap(), "cmn");
+
+	let language = Language::new("zh").unwrap();
+	assert!(!language.is_extlang_form());
+	assert_eq!(language.extended_primary(), None);
+
+	let language = Language::new("abcde").unwrap();
+	assert_eq!(language.extended_primary(), None);
+
+	let language = GrandfatheredLangTag::ZhMinNan.extlang_form().unwrap();
+	assert_eq!(language.primary(), "zh");
+	assert_eq!(language.extended_primary().unwrap(), "nan");
+	assert_eq!(
+		GrandfatheredLangTag::SgnBeFr.extlang_form().unwrap(),
+		"sgn-sfb"
+	);
+	assert_eq!(
+		GrandfatheredLangTag::SgnBeNl.extlang_form().unwrap(),
+		"sgn-vgt"
+	);
+	assert_eq!(
+		GrandfatheredLangTag::SgnChDe.extlang_form().unwrap(),
+		"sgn-sgg"
+	);
+	assert_eq!(GrandfatheredLangTag::ZhMin.extlang_form(), None);
+	assert_eq!(GrandfatheredLangTag::IKlingon.extlang_form(), None);
+}
+
+#[test]
+pub fn language_primary_and_extlangs_str() {
+	let tag = LangTag::new("fr-abc-def-ghi-bz").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (Some("fr"), "abc-def-ghi"));
+	let tag = LangTag::new("zh-yue-Hant-HK").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (Some("zh"), "yue"));
+	let tag = LangTag::new("en-Latn-US").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (Some("en"), ""));
+	let tag = LangTag::new("fr").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (Some("fr"), ""));
+	let tag = LangTag::new("x-fr-CH").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (None, ""));
+	let tag = LangTag::new("i-klingon").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (None, ""));
+	let tag = LangTag::new("zh-min-nan").unwrap();
+	assert_eq!(tag.primary_and_extlangs_str(), (Some("zh"), ""));
+}
+
+#[test]
+pub fn language_similarity() {
+	let similarity = |a: &str, b: &str| {
+		LangTag::new(a)
+			.unwrap()
+			.language_similarity(LangTag::new(b).unwrap())
+	};
+
+	assert_eq!(similarity("zh-yue", "ZH-Yue-HK"), 1.0);
+	assert_eq!(similarity("zh-yue", "zh"), 0.5);
+	assert_eq!(similarity("zh-yue", "fr"), 0.0);
+	assert_eq!(similarity("en-US", "en-GB"), 1.0);
+	assert_eq!(similarity("ar-aao-abh", "ar-aao"), 0.75);
+	assert_eq!(similarity("x-foo", "x-foo"), 0.0);
+}
+
+#[test]
+pub fn language_with() {
+	use langtag::Language;
+
+	let with = |tag: &str, language: &str| {
+		LangTag::new(tag)
+			.unwrap()
+			.with_language(Language::new(language).unwrap())
+	};
+
+	assert_eq!(with("fr-abc-def-ghi-bz", "foo"), "foo-bz");
+	assert_eq!(with("fr-bz", "zh-yue"), "zh-yue-bz");
+	assert_eq!(with("en", "foobar"), "foobar");
+	assert_eq!(with("en-Latn-US-x-foo", "fr"), "fr-Latn-US-x-foo");
+	assert_eq!(with("x-foo", "fr"), "fr-x-foo");
+	assert_eq!(with("i-klingon", "tlh"), "tlh");
+}
+
+#[test]
+pub fn language_primary_tag() {
+	let primary = |s: &str| NormalLangTag::new(s).unwrap().primary_tag();
+	assert_eq!(primary("en-Latn-US-x-foo"), "en");
+	assert_eq!(primary("zh-yue-Hant-HK"), "zh-yue");
+	assert_eq!(primary("DINKA"), "DINKA");
+
+	let range = |s: &str| LangTag::new(s).unwrap().language_range();
+	assert_eq!(range("fr-CA-u-ca-gregory").unwrap(), "fr");
+	assert_eq!(range("zh-min-nan").unwrap(), "zh");
+	assert_eq!(range("no-bok").unwrap(), "no");
+	assert_eq!(range("x-foo"), None);
+	assert_eq!(range("en-GB-oed"), None);
+	assert!(range("sgn-BE-FR").is_none());
+}
@@ -393,3 +393,741 @@ fn test_random_bad_tags() {
 		);
 	}
 }
+
+#[test]
+fn test_into_lang_tag_buf() {
+	use langtag::{IntoLangTagBuf, LangTagBuf};
+
+	let tag = LangTag::new("fr-FR").unwrap();
+	let owned: LangTagBuf = tag.into();
+	assert_eq!(tag.try_into_lang_tag_buf().unwrap(), owned);
+	assert_eq!(owned.clone().try_into_lang_tag_buf().unwrap(), owned);
+	assert_eq!("fr-FR".try_into_lang_tag_buf().unwrap(), owned);
+	assert_eq!("fr-FR".to_string().try_into_lang_tag_buf().unwrap(), owned);
+	assert!("fr_FR".try_into_lang_tag_buf().is_err());
+	assert!("fr_FR".to_string().try_into_lang_tag_buf().is_err());
+}
+
+#[test]
+fn test_extension_split_offset() {
+	let offset = |s: &str| LangTag::new(s).unwrap().extension_split_offset();
+	assert_eq!(offset("en"), 2);
+	assert_eq!(offset("de-CH-1996"), 10);
+	assert_eq!(offset("de-CH-1996-a-ext"), 11);
+	assert_eq!(offset("fr-x-foo"), 3);
+	assert_eq!(offset("x-foo"), 0);
+	assert_eq!(offset("i-klingon"), 9);
+}
+
+#[test]
+fn test_from_components() {
+	use langtag::{Extensions, LangTagBuf, Language, PrivateUse, Region, Script, Variants};
+
+	let tag = LangTagBuf::from_components(
+		Language::new("zh-yue").unwrap(),
+		Some(Script::new("Hant").unwrap()),
+		Some(Region::new("HK").unwrap()),
+		Variants::new("").unwrap(),
+		Extensions::new("u-co-pinyin").unwrap(),
+		Some(PrivateUse::new("x-foo").unwrap()),
+	)
+	.unwrap();
+	assert_eq!(tag, "zh-yue-Hant-HK-u-co-pinyin-x-foo");
+
+	let unchecked =
+		unsafe { LangTagBuf::from_components_unchecked("de", None, Some("CH"), "1996", "", None) };
+	assert_eq!(unchecked, "de-CH-1996");
+	assert_eq!(unchecked.as_normal().unwrap().variants(), "1996");
+}
+
+#[test]
+fn test_components_display() {
+	use langtag::{Extensions, Region, Variants};
+
+	let tag = LangTag::new("fr-Latn-FR").unwrap();
+	let components = tag.components_display().unwrap();
+	assert!(components.variants.is_empty());
+	assert!(components.extensions.is_empty());
+	assert_eq!(components.to_string(), "fr-Latn-FR");
+
+	let mut components = LangTag::new("de-CH-x-foo")
+		.unwrap()
+		.components_display()
+		.unwrap();
+	components.region = Some(Region::new("AT").unwrap());
+	components.variants = Variants::new("1996").unwrap();
+	components.extensions = Extensions::new("u-co-phonebk").unwrap();
+	assert_eq!(components.to_string(), "de-AT-1996-u-co-phonebk-x-foo");
+	assert_eq!(components.build().unwrap(), "de-AT-1996-u-co-phonebk-x-foo");
+
+	components.variants = Variants::new("1996-1996").unwrap();
+	assert!(components.build().is_err());
+	components.variants = Variants::new("").unwrap();
+	components.extensions = Extensions::new("a-foo-A-bar").unwrap();
+	assert!(components.build().is_err());
+
+	for tag in ["zh-hakka", "i-klingon", "x-foo"] {
+		assert!(LangTag::new(tag).unwrap().components_display().is_none())
+	}
+}
+
+#[test]
+fn test_eq_exact() {
+	let a = LangTag::new("en-US").unwrap();
+	let b = LangTag::new("en-us").unwrap();
+	assert_eq!(a, b);
+	assert!(!a.eq_exact(b));
+	assert!(a.eq_exact(LangTag::new("en-US").unwrap()));
+}
+
+#[test]
+fn test_cmp_exact() {
+	use langtag::Exact;
+	use std::{cmp::Ordering, collections::BTreeSet};
+
+	let a = LangTag::new("en-US").unwrap();
+	let b = LangTag::new("en-us").unwrap();
+	assert_eq!(a.cmp(b), Ordering::Equal);
+	assert_eq!(a.cmp_exact(b), Ordering::Less);
+	assert_eq!(b.cmp_exact(a), Ordering::Greater);
+	assert_eq!(a.cmp_exact(a), Ordering::Equal);
+
+	let set: BTreeSet<_> = [a, b, a].into_iter().map(Exact).collect();
+	assert_eq!(set.len(), 2);
+	assert_ne!(Exact(a), Exact(b));
+	assert_eq!(Exact(a.to_owned()), Exact(a.to_owned()));
+}
+
+#[test]
+fn test_parse_list() {
+	let tags: Vec<_> = LangTag::parse_list(" en-US ,fr,,\tde-CH-1996 ,", ',')
+		.map(Result::unwrap)
+		.collect();
+	assert_eq!(tags, ["en-US", "fr", "de-CH-1996"]);
+
+	let tags: Vec<_> = LangTag::parse_list("en\nfr_FR\n\n", '\n').collect();
+	assert_eq!(tags.len(), 2);
+	assert!(tags[0].is_ok());
+	assert_eq!(tags[1].as_ref().unwrap_err().0, "fr_FR");
+}
+
+#[test]
+fn test_parse_all() {
+	let inputs = [
+		"en-US".to_owned(),
+		"fr_FR".to_owned(),
+		"i-klingon".to_owned(),
+	];
+	let tags: Vec<_> = LangTag::parse_all(inputs.iter().map(String::as_str)).collect();
+	assert_eq!(tags.len(), 3);
+	assert_eq!(*tags[0].as_ref().unwrap(), "en-US");
+	assert_eq!(tags[1].as_ref().unwrap_err().0, "fr_FR");
+	assert!(tags[2].as_ref().unwrap().is_grandfathered());
+}
+
+#[test]
+fn test_is_single_subtag() {
+	let single = |s: &str| LangTag::new(s).unwrap().is_single_subtag();
+	assert!(single("en"));
+	assert!(single("ZH"));
+	assert!(single("deu"));
+	assert!(!single("en-US"));
+	assert!(!single("x-foo"));
+	assert!(!single("i-klingon"));
+}
+
+#[test]
+fn test_total_subtag_count() {
+	let count = |s: &str| LangTag::new(s).unwrap().total_subtag_count();
+	assert_eq!(count("en"), 1);
+	assert_eq!(count("en-Latn-US-x-a-b"), 6);
+	assert_eq!(count("de-CH-1996-a-ext"), 5);
+	assert_eq!(count("x-foo"), 2);
+	assert_eq!(count("zh-min-nan"), 3);
+}
+
+#[test]
+fn test_shared_prefix_subtags() {
+	let shared = |a: &str, b: &str| {
+		LangTag::new(a)
+			.unwrap()
+			.shared_prefix_subtags(LangTag::new(b).unwrap())
+	};
+	assert_eq!(shared("en-US", "en-GB"), 1);
+	assert_eq!(shared("en-Latn-US", "EN-latn-GB"), 2);
+	assert_eq!(shared("en-US", "en-US"), 2);
+	assert_eq!(shared("en", "en-US"), 1);
+	assert_eq!(shared("en-US", "eng-US"), 0);
+	assert_eq!(shared("fr-x-foo", "fr-x-bar"), 2);
+}
+
+#[test]
+fn test_validate_structure() {
+	use langtag::{Singleton, StructureError};
+
+	let validate = |s: &str| LangTag::new(s).unwrap().validate_structure();
+	assert!(validate("de-CH-1901-1996-a-aaa-b-bbb").is_ok());
+	assert!(validate("x-foo-foo").is_ok());
+	assert_eq!(
+		validate("en-a-aaa-b-bbb-A-ccc"),
+		Err(StructureError::DuplicateSingleton(
+			Singleton::new(b'A').unwrap()
+		))
+	);
+	assert!(matches!(
+		validate("de-1996-1901-1996"),
+		Err(StructureError::DuplicateVariant(v)) if v == "1996"
+	));
+}
+
+#[test]
+fn test_heap_size() {
+	use langtag::LangTagBuf;
+
+	let tag = LangTagBuf::new("en-US".to_string()).unwrap();
+	assert!(tag.heap_size() >= 5);
+	assert_eq!(std::mem::size_of::<&LangTag>(), std::mem::size_of::<&str>());
+
+	let mut s = String::with_capacity(64);
+	s.push_str("fr");
+	assert_eq!(LangTagBuf::new(s).unwrap().heap_size(), 64);
+}
+
+#[test]
+fn test_ascii_bytes() {
+	for tag in ["en-US", "x-foo", "i-klingon", "zh-yue-Hant-HK-u-co-pinyin"] {
+		let bytes = LangTag::new(tag).unwrap().ascii_bytes();
+		assert!(bytes.is_ascii());
+		assert_eq!(bytes, tag.as_bytes());
+	}
+}
+
+#[test]
+fn test_diff() {
+	let diff = |a: &str, b: &str| {
+		let a = LangTag::new(a).unwrap();
+		let b = LangTag::new(b).unwrap();
+		(a.diff(b).is_empty(), a.diff(b).to_string())
+	};
+
+	assert_eq!(diff("en-US", "EN-us"), (true, "no difference".to_string()));
+	assert_eq!(
+		diff("en-Latn-US", "fr-GB-x-foo"),
+		(
+			false,
+			"language: en vs fr, script: Latn vs none, region: US vs GB, private use: none vs x-foo"
+				.to_string()
+		)
+	);
+	assert_eq!(
+		diff("de-CH-1996", "de-CH"),
+		(false, "variants: 1996 vs none".to_string())
+	);
+
+	let a = LangTag::new("en-a-aaa").unwrap();
+	let b = LangTag::new("en-b-bbb").unwrap();
+	let extensions = a.diff(b).extensions.unwrap();
+	assert_eq!(extensions.left, "a-aaa");
+	assert_eq!(extensions.right, "b-bbb");
+}
+
+#[test]
+fn test_tokens() {
+	use langtag::Token;
+
+	for tag in [
+		"en",
+		"zh-yue-Hant-HK-u-co-pinyin-x-Foo",
+		"i-klingon",
+		"x-a-b",
+	] {
+		let tokens: Vec<_> = LangTag::new(tag).unwrap().tokens().collect();
+		assert_eq!(tokens.len(), tag.split('-').count() * 2 - 1);
+		assert_eq!(tokens.iter().map(Token::as_str).collect::<String>(), tag);
+	}
+}
+
+#[test]
+fn test_looks_grandfathered() {
+	for tag in [
+		"i-klingon",
+		"I-KLINGON",
+		"zh-Min-Nan",
+		"EN-gb-OED",
+		"art-LOJBAN",
+	] {
+		assert!(LangTag::looks_grandfathered(tag));
+		assert!(LangTag::new(tag).unwrap().is_grandfathered())
+	}
+
+	for tag in ["en", "i-notexist", "x-klingon", "i-klingon-x", ""] {
+		assert!(!LangTag::looks_grandfathered(tag))
+	}
+}
+
+#[test]
+fn test_grandfathered_kind() {
+	use langtag::{Kind, GRANDFATHERED};
+
+	for g in &GRANDFATHERED {
+		let tag = LangTag::new(g.as_str()).unwrap();
+		assert_eq!(tag.kind(), Kind::Grandfathered);
+		assert!(tag.as_typed().as_grandfathered() == Some(*g));
+	}
+
+	for s in ["EN-gb-OED", "sgn-be-fr", "Zh-Min-Nan"] {
+		assert_eq!(LangTag::new(s).unwrap().kind(), Kind::Grandfathered);
+	}
+
+	// Regular grandfathered tags keep their normal interpretation.
+	let tag = LangTag::new("zh-min-nan").unwrap();
+	assert!(!tag.is_normal());
+	assert!(tag.is_grandfathered());
+	assert_eq!(tag.as_normal().unwrap().language(), "zh-min-nan");
+
+	assert_eq!(LangTag::new("zh-min-nan-TW").unwrap().kind(), Kind::Normal);
+	assert_eq!(LangTag::new("x-klingon").unwrap().kind(), Kind::PrivateUse);
+}
+
+#[test]
+fn test_from_subtags() {
+	use langtag::LangTagBuf;
+
+	assert_eq!(
+		LangTagBuf::from_subtags(["zh", "Hant", "HK", "u", "co", "pinyin"]).unwrap(),
+		"zh-Hant-HK-u-co-pinyin"
+	);
+	assert_eq!(
+		LangTagBuf::from_subtags(vec!["x".to_owned(), "foo".to_owned()]).unwrap(),
+		"x-foo"
+	);
+	assert_eq!(
+		LangTagBuf::from_subtags(["i", "klingon"]).unwrap(),
+		"i-klingon"
+	);
+
+	let index = |subtags: &[&str]| LangTagBuf::from_subtags(subtags).unwrap_err().index;
+	assert_eq!(index(&["en", "US", "Latn"]), 2);
+	assert_eq!(index(&["en", "a"]), 1);
+	assert_eq!(index(&["en", "", "US"]), 1);
+	assert_eq!(index(&["en", "toolongsubtag"]), 1);
+	assert_eq!(index(&["en", "U$"]), 1);
+	assert_eq!(index(&["en", "U$", "Latn"]), 1);
+	assert_eq!(index(&["a", "US"]), 0);
+	assert_eq!(index(&[]), 0);
+}
+
+#[test]
+fn test_with_normal_mut() {
+	use langtag::{LangTagBuf, NormalLangTagBuf};
+
+	let mut tag = LangTagBuf::new("en-US".to_owned()).unwrap();
+	let region = tag.with_normal_mut(|normal| {
+		let region = normal.region().unwrap().to_owned();
+		*normal = NormalLangTagBuf::new("en-GB-x-foo".to_owned()).unwrap();
+		region
+	});
+	assert_eq!(region.unwrap(), "US");
+	assert_eq!(tag, "en-GB-x-foo");
+	assert_eq!(tag.private_use().unwrap(), "x-foo");
+
+	for tag in ["x-foo", "i-klingon", "zh-hakka"] {
+		let mut t = LangTagBuf::new(tag.to_owned()).unwrap();
+		assert!(t.with_normal_mut(|_| ()).is_none());
+		assert_eq!(t, tag)
+	}
+
+	let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+		tag.with_normal_mut(|normal| {
+			normal.set_region(None);
+			panic!()
+		})
+	}));
+	assert!(result.is_err());
+	assert_eq!(tag, "en-x-foo");
+	assert!(tag.is_normal());
+}
+
+#[test]
+fn test_from_html_lang() {
+	assert_eq!(LangTag::from_html_lang("fr").unwrap(), "fr");
+	assert_eq!(
+		LangTag::from_html_lang("\t zh-HANT-tw ").unwrap().as_str(),
+		"zh-HANT-tw"
+	);
+	assert_eq!(LangTag::from_html_lang("UND").unwrap(), "und");
+	assert_eq!(LangTag::from_html_lang("x-foo").unwrap(), "x-foo");
+	assert!(LangTag::from_html_lang("").is_none());
+	assert!(LangTag::from_html_lang(" \n").is_none());
+	assert!(LangTag::from_html_lang("en US").is_none());
+	assert!(LangTag::from_html_lang("en--US").is_none());
+}
+
+#[test]
+fn test_from_str() {
+	use langtag::{
+		ExtensionSubtagBuf, LangTagBuf, LanguageBuf, NormalLangTagBuf, RegionBuf, ScriptBuf,
+		VariantBuf,
+	};
+
+	fn owned_error<T: std::str::FromStr>(s: &str) -> T::Err
+	where
+		T::Err: std::error::Error + 'static,
+	{
+		s.parse::<T>().err().unwrap()
+	}
+
+	let tag: LangTagBuf = "fr-FR".parse().unwrap();
+	assert_eq!(tag, "fr-FR");
+	assert_eq!(owned_error::<LangTagBuf>("fr--FR").0, "fr--FR");
+
+	assert!("zh-Hant".parse::<NormalLangTagBuf>().is_ok());
+	assert!("i-klingon".parse::<NormalLangTagBuf>().is_err());
+	assert!("zh-yue".parse::<LanguageBuf>().is_ok());
+	assert!("Latn".parse::<ScriptBuf>().is_ok());
+	assert!("419".parse::<RegionBuf>().is_ok());
+	assert!("fonipa".parse::<VariantBuf>().is_ok());
+	assert!("pinyin".parse::<ExtensionSubtagBuf>().is_ok());
+	assert_eq!(owned_error::<RegionBuf>("USA").0, "USA");
+}
+
+#[test]
+fn test_subtag_kind_at() {
+	use langtag::SubtagKind;
+
+	let tag = LangTag::new("en-Latn-US-1996-u-ca-gregory-x-foo").unwrap();
+	let kind = |offset| tag.subtag_kind_at(offset).map(|(kind, _)| kind);
+	assert_eq!(kind(0), Some(SubtagKind::PrimaryLanguage));
+	assert_eq!(kind(2), None);
+	assert_eq!(kind(3), Some(SubtagKind::Script));
+	assert_eq!(kind(9), Some(SubtagKind::Region));
+	assert_eq!(kind(11), Some(SubtagKind::Variant));
+	assert_eq!(kind(16), Some(SubtagKind::Singleton));
+	assert_eq!(
+		tag.subtag_kind_at(22),
+		Some((SubtagKind::Extension, 21..28))
+	);
+	assert_eq!(kind(29), Some(SubtagKind::PrivateUseSingleton));
+	assert_eq!(
+		tag.subtag_kind_at(33),
+		Some((SubtagKind::PrivateUse, 31..34))
+	);
+	assert_eq!(kind(34), None);
+
+	let tag = LangTag::new("i-klingon").unwrap();
+	assert_eq!(
+		tag.subtag_kind_at(4),
+		Some((SubtagKind::Grandfathered, 2..9))
+	);
+
+	let tag = LangTag::new("x-a-b").unwrap();
+	assert_eq!(
+		tag.subtag_kind_at(0),
+		Some((SubtagKind::PrivateUseSingleton, 0..1))
+	);
+	assert_eq!(tag.subtag_kind_at(4), Some((SubtagKind::PrivateUse, 4..5)));
+}
+
+#[test]
+fn test_subtags() {
+	use langtag::{Subtag, SubtagKind};
+
+	let tag = LangTag::new("en-Latn-US-1996-u-ca-gregory-x-foo").unwrap();
+	let subtags: Vec<_> = tag.subtags().collect();
+	assert_eq!(
+		subtags,
+		[
+			Subtag::PrimaryLanguage("en"),
+			Subtag::Script("Latn"),
+			Subtag::Region("US"),
+			Subtag::Variant("1996"),
+			Subtag::Singleton("u"),
+			Subtag::Extension("ca"),
+			Subtag::Extension("gregory"),
+			Subtag::PrivateUseSingleton("x"),
+			Subtag::PrivateUse("foo")
+		]
+	);
+	assert!(subtags
+		.iter()
+		.zip(tag.subtag_spans())
+		.all(|(s, (kind, range))| s.kind() == kind && s.as_str() == &tag[range]));
+
+	let tag = LangTag::new("zh-min-nan").unwrap();
+	assert_eq!(
+		tag.subtags().collect::<Vec<_>>(),
+		[Subtag::Grandfathered("zh-min-nan")]
+	);
+
+	let tag = LangTag::new("x-a-b").unwrap();
+	assert_eq!(
+		tag.subtags().map(|s| s.kind()).collect::<Vec<_>>(),
+		[
+			SubtagKind::PrivateUseSingleton,
+			SubtagKind::PrivateUse,
+			SubtagKind::PrivateUse
+		]
+	);
+}
+
+#[test]
+fn test_builder() {
+	use langtag::{
+		BuildError, ExtendedLangTag, ExtensionSubtag, LangTagBuilder, PrimaryLanguage,
+		PrivateUseSubtag, Script, Singleton, Variant,
+	};
+
+	let zh = PrimaryLanguage::new("zh").unwrap();
+	let yue = ExtendedLangTag::new("yue").unwrap();
+	let hant = Script::new("Hant").unwrap();
+	let variant = Variant::new("1996").unwrap();
+	let a = Singleton::new(b'a').unwrap();
+	let big_a = Singleton::new(b'A').unwrap();
+	let b = Singleton::new(b'b').unwrap();
+	let foo = ExtensionSubtag::new("foo").unwrap();
+	let bar = ExtensionSubtag::new("bar").unwrap();
+	let priv1 = PrivateUseSubtag::new("p").unwrap();
+
+	let tag = LangTagBuilder::new()
+		.push_private_use(priv1)
+		.push_extension(b, foo)
+		.push_extension(a, foo)
+		.push_extension(big_a, bar)
+		.push_variant(variant)
+		.script(Some(hant))
+		.extended_language(yue)
+		.primary_language(zh)
+		.push_private_use(priv1)
+		.build()
+		.unwrap();
+	assert_eq!(tag.as_str(), "zh-yue-Hant-1996-b-foo-a-foo-bar-x-p-p");
+
+	let tag = LangTagBuilder::new()
+		.push_private_use(priv1)
+		.build()
+		.unwrap();
+	assert_eq!(tag, "x-p");
+	assert!(tag.is_private_use());
+
+	assert!(matches!(
+		LangTagBuilder::new().script(Some(hant)).build(),
+		Err(BuildError::MissingPrimaryLanguage)
+	));
+	assert!(matches!(
+		LangTagBuilder::new().build(),
+		Err(BuildError::MissingPrimaryLanguage)
+	));
+	assert!(matches!(
+		LangTagBuilder::new()
+			.primary_language(PrimaryLanguage::new("abcde").unwrap())
+			.extended_language(yue)
+			.build(),
+		Err(BuildError::UnexpectedExtendedLanguage)
+	));
+	assert!(matches!(
+		LangTagBuilder::new()
+			.primary_language(zh)
+			.extended_language(yue)
+			.extended_language(yue)
+			.extended_language(yue)
+			.extended_language(yue)
+			.build(),
+		Err(BuildError::TooManyExtendedLanguages)
+	));
+}
+
+#[test]
+fn test_hash_eq_consistency() {
+	use langtag::{Canonical, LangTagBuf, Language, Script};
+	use std::{
+		collections::{hash_map::DefaultHasher, HashMap},
+		hash::{Hash, Hasher},
+	};
+
+	fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		t.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	let pairs = [
+		("en-US", "EN-us"),
+		("zh-Hant-TW-x-Foo", "ZH-hant-tw-X-FOO"),
+		("i-klingon", "I-KLINGON"),
+		("x-Private", "X-private"),
+	];
+
+	for (a, b) in pairs {
+		let (a_ref, b_ref) = (LangTag::new(a).unwrap(), LangTag::new(b).unwrap());
+		let (a_buf, b_buf) = (a_ref.to_owned(), b_ref.to_owned());
+		assert_eq!(a_ref, b_ref);
+		assert_eq!(hash(a_ref), hash(b_ref));
+		assert_eq!(a_buf, b_buf);
+		assert_eq!(hash(&a_buf), hash(&b_buf));
+		assert_eq!(hash(&a_buf), hash(a_ref));
+	}
+
+	assert_eq!(
+		hash(Language::new("ZH").unwrap()),
+		hash(Language::new("zh").unwrap())
+	);
+	assert_eq!(
+		hash(Script::new("HANT").unwrap()),
+		hash(Script::new("hant").unwrap())
+	);
+
+	// Equality is lexical: reordered extensions are different tags...
+	let a = LangTag::new("en-a-foo-b-bar").unwrap();
+	let b = LangTag::new("en-b-bar-a-foo").unwrap();
+	assert_ne!(a, b);
+
+	// ...unless compared by canonical form.
+	assert_eq!(Canonical(a), Canonical(b));
+	assert_eq!(hash(&Canonical(a)), hash(&Canonical(b)));
+
+	// Owned keys can be looked up with borrowed tags of any case.
+	let mut map = HashMap::new();
+	map.insert(LangTagBuf::new("en-US".to_owned()).unwrap(), 1);
+	assert_eq!(map.get(LangTag::new("EN-US").unwrap()), Some(&1));
+}
+
+#[test]
+fn test_error_position() {
+	use langtag::SubtagKind;
+
+	let error = |tag: &str| {
+		let e = LangTag::new(tag).unwrap_err();
+		(e.position(), e.expected())
+	};
+
+	assert_eq!(error(""), (0, SubtagKind::PrimaryLanguage));
+	assert_eq!(error("e"), (0, SubtagKind::PrimaryLanguage));
+	assert_eq!(error("en--US"), (3, SubtagKind::ExtendedLanguage));
+	assert_eq!(error("en-US-"), (6, SubtagKind::Variant));
+	assert_eq!(error("en-Latn-Latn"), (8, SubtagKind::Region));
+	assert_eq!(error("en-US-foo!"), (6, SubtagKind::Variant));
+	assert_eq!(error("zh-yue-abc-def-ghi"), (15, SubtagKind::Script));
+	assert_eq!(error("english-abc"), (8, SubtagKind::Script));
+	assert_eq!(error("en-a"), (4, SubtagKind::Extension));
+	assert_eq!(error("en-a-b-foo"), (5, SubtagKind::Extension));
+	assert_eq!(error("en-a-foo-verylongsubtag"), (9, SubtagKind::Extension));
+	assert_eq!(error("en-x"), (4, SubtagKind::PrivateUse));
+	assert_eq!(error("x"), (1, SubtagKind::PrivateUse));
+	assert_eq!(error("en-x-foo-b@r"), (9, SubtagKind::PrivateUse));
+}
+
+#[test]
+fn test_error_kind() {
+	use langtag::InvalidLangTagKind;
+
+	let kind = |tag: &str| LangTag::new(tag).unwrap_err().kind();
+
+	assert_eq!(kind(""), InvalidLangTagKind::Empty);
+	assert_eq!(kind("-"), InvalidLangTagKind::MalformedSubtag);
+	assert_eq!(kind("en--US"), InvalidLangTagKind::MalformedSubtag);
+	assert_eq!(kind("en-US-"), InvalidLangTagKind::MalformedSubtag);
+	assert_eq!(
+		kind("en-verylongsubtag"),
+		InvalidLangTagKind::MalformedSubtag
+	);
+	assert_eq!(kind("en_US"), InvalidLangTagKind::MalformedSubtag);
+	assert_eq!(kind("e"), InvalidLangTagKind::UnexpectedSubtag);
+	assert_eq!(kind("en-US-foo"), InvalidLangTagKind::UnexpectedSubtag);
+	assert_eq!(kind("en-a-b-foo"), InvalidLangTagKind::UnexpectedSubtag);
+	assert_eq!(kind("en-a"), InvalidLangTagKind::Incomplete);
+	assert_eq!(kind("x"), InvalidLangTagKind::Incomplete);
+
+	let error = LangTag::new("en-US-foo").unwrap_err();
+	assert_eq!(error.0, "en-US-foo");
+}
+
+#[test]
+fn test_try_from() {
+	use langtag::{
+		InvalidLangTag, InvalidSingleton, LangTagBuf, NormalLangTag, Region, RegionBuf, Script,
+		ScriptBuf, Singleton, Variant, VariantBuf,
+	};
+
+	fn parse<'a, T: TryFrom<&'a str>>(s: &'a str) -> Option<T> {
+		s.try_into().ok()
+	}
+
+	let tag: &LangTag = "en-US".try_into().unwrap();
+	assert_eq!(tag, "en-US");
+	let tag: LangTagBuf = "en-US".to_owned().try_into().unwrap();
+	assert_eq!(tag, "en-US");
+	let error: InvalidLangTag<String> = LangTagBuf::try_from("en--US".to_owned()).unwrap_err();
+	assert_eq!(error.0, "en--US");
+
+	assert!(<&NormalLangTag>::try_from("fr-CA").is_ok());
+	assert!(<&Script>::try_from("Latn").is_ok());
+	assert!(<&Script>::try_from("Lat").is_err());
+	assert!(ScriptBuf::try_from("Latn".to_owned()).is_ok());
+	assert!(<&Region>::try_from("419").is_ok());
+	assert!(RegionBuf::try_from("U".to_owned()).is_err());
+	assert!(<&Variant>::try_from("1996").is_ok());
+	assert!(VariantBuf::try_from("rozaj".to_owned()).is_ok());
+
+	assert_eq!(parse::<Singleton>("u").unwrap(), 'u');
+	assert!(parse::<Singleton>("x").is_none());
+	assert!(parse::<Singleton>("ab").is_none());
+	assert!(Singleton::try_from("7".to_owned()).is_ok());
+	let error: InvalidSingleton<String> = Singleton::try_from("x".to_owned()).unwrap_err();
+	assert_eq!(error.0, "x");
+}
+
+#[test]
+fn test_owned_borrowed() {
+	use langtag::{
+		LangTagBuf, Language, LanguageBuf, NormalLangTag, NormalLangTagBuf, Region, RegionBuf,
+		Script, ScriptBuf,
+	};
+	use std::{borrow::Borrow, collections::HashSet, ops::Deref};
+
+	fn primary(tag: &LangTag) -> Option<&str> {
+		tag.language().map(|l| l.primary().as_str())
+	}
+
+	fn check<
+		B: ?Sized + PartialEq + std::fmt::Debug,
+		T: Deref<Target = B> + AsRef<B> + Borrow<B>,
+	>(
+		owned: &T,
+	) {
+		assert_eq!(owned.deref(), owned.as_ref());
+		assert_eq!(owned.as_ref(), owned.borrow());
+	}
+
+	let tag = LangTagBuf::new("en-US".to_owned()).unwrap();
+	assert_eq!(primary(&tag), Some("en"));
+	check::<LangTag, _>(&tag);
+	check::<NormalLangTag, _>(&NormalLangTagBuf::new("en-US".to_owned()).unwrap());
+	check::<Language, _>(&LanguageBuf::new("zh-yue".to_owned()).unwrap());
+	check::<Script, _>(&ScriptBuf::new("Latn".to_owned()).unwrap());
+	check::<Region, _>(&RegionBuf::new("US".to_owned()).unwrap());
+
+	let set: HashSet<LangTagBuf> = [tag].into_iter().collect();
+	assert!(set.contains(LangTag::new("en-US").unwrap()));
+	assert!(set.contains(LangTag::new("EN-us").unwrap()));
+}
+
+#[test]
+fn test_as_str() {
+	use langtag::{LangTagBuf, Singleton};
+
+	let tag = LangTag::new("en-Latn-US").unwrap();
+	assert_eq!(tag.as_str(), "en-Latn-US");
+	assert_eq!(tag.as_bytes(), b"en-Latn-US");
+
+	let owned = LangTagBuf::new("EN-latn-us".to_owned()).unwrap();
+	assert_eq!(owned.as_str(), "EN-latn-us");
+	assert_eq!(owned.as_bytes(), b"EN-latn-us");
+	assert_eq!(owned.as_lang_tag().as_str(), "EN-latn-us");
+
+	assert_eq!(tag.script().unwrap().as_str(), "Latn");
+	assert_eq!(tag.region().unwrap().as_bytes(), b"US");
+
+	let singleton = Singleton::new(b'U').unwrap();
+	assert_eq!(singleton.as_str(), "U");
+	assert_eq!(singleton.as_bytes(), b"U");
+}
@@ -0,0 +1,101 @@
+use langtag::{LangTag, LangTagBuf};
+
+#[test]
+pub fn matching_basic() {
+	let matches = |tag: &str, range: &str| LangTag::new(tag).unwrap().matches_basic(range);
+
+	assert!(matches("de-CH-1996", "de-CH"));
+	assert!(matches("de-CH-1996", "DE-ch-1996"));
+	assert!(matches("de-CH-1996", "de"));
+	assert!(!matches("de-CH-1996", "de-C"));
+	assert!(!matches("de-CH", "de-CH-1996"));
+	assert!(!matches("de", "d"));
+	assert!(matches("en-US", "en-*"));
+	assert!(matches("en", "en-*"));
+	assert!(!matches("fr", "en-*"));
+	assert!(matches("i-klingon", "*"));
+	assert!(matches("x-foo", "*"));
+	assert!(!matches("en", ""));
+}
+
+#[test]
+pub fn matching_extended() {
+	let matches = |tag: &str, range: &str| LangTag::new(tag).unwrap().matches_extended(range);
+
+	assert!(matches("de-DE", "de-*-DE"));
+	assert!(matches("de-de", "de-*-DE"));
+	assert!(matches("de-Latn-DE", "de-*-DE"));
+	assert!(matches("de-Latf-DE", "de-*-DE"));
+	assert!(matches("de-DE-x-goethe", "de-*-DE"));
+	assert!(matches("de-Latn-DE-1996", "de-*-DE"));
+	assert!(matches("de-Deva-DE", "de-*-DE"));
+	assert!(matches("de-DE-1996", "de-*-DE"));
+	assert!(!matches("de", "de-*-DE"));
+	assert!(!matches("de-x-DE", "de-*-DE"));
+	assert!(!matches("de-Deva", "de-*-DE"));
+	assert!(!matches("fr-DE", "de-*-DE"));
+
+	assert!(matches("de-Latn-DE", "*-DE"));
+	assert!(matches("de-Latn-DE", "de-DE"));
+	assert!(matches("i-klingon", "*"));
+	assert!(!matches("en", ""));
+}
+
+#[test]
+pub fn matching_lookup() {
+	let available: Vec<LangTagBuf> = ["en", "zh-Hant", "fr-CA", "de-DE-u-co-phonebk", "i-klingon"]
+		.into_iter()
+		.map(|t| t.parse().unwrap())
+		.collect();
+	let lookup = |range: &str| langtag::lookup(range, &available).map(LangTagBuf::as_str);
+
+	assert_eq!(lookup("zh-Hant-CN-x-private1-private2"), Some("zh-Hant"));
+	assert_eq!(lookup("ZH-hant"), Some("zh-Hant"));
+	assert_eq!(lookup("en-Latn-US"), Some("en"));
+	assert_eq!(lookup("fr-CA-*"), Some("fr-CA"));
+	assert_eq!(
+		lookup("de-DE-u-co-phonebk-x-foo"),
+		Some("de-DE-u-co-phonebk")
+	);
+	assert_eq!(lookup("i-klingon"), Some("i-klingon"));
+	assert_eq!(lookup("fr-FR"), None);
+	assert_eq!(lookup("zh"), None);
+	assert_eq!(lookup("x-en"), None);
+	assert_eq!(lookup("*"), None);
+}
+
+#[test]
+pub fn matching_fallbacks() {
+	let fallbacks = |tag: &'static str| {
+		LangTag::new(tag)
+			.unwrap()
+			.fallbacks()
+			.map(LangTag::as_str)
+			.collect::<Vec<_>>()
+	};
+
+	assert_eq!(
+		fallbacks("en-Latn-US-x-foo"),
+		["en-Latn-US-x-foo", "en-Latn-US", "en-Latn", "en"]
+	);
+	assert_eq!(
+		fallbacks("de-DE-a-bbb-ccc-x-foo-bar"),
+		[
+			"de-DE-a-bbb-ccc-x-foo-bar",
+			"de-DE-a-bbb-ccc-x-foo",
+			"de-DE-a-bbb-ccc",
+			"de-DE-a-bbb",
+			"de-DE",
+			"de"
+		]
+	);
+	assert_eq!(fallbacks("zh-yue-HK"), ["zh-yue-HK", "zh-yue", "zh"]);
+	assert_eq!(fallbacks("fr"), ["fr"]);
+	assert_eq!(fallbacks("x-foo-bar"), ["x-foo-bar"]);
+	assert_eq!(fallbacks("i-klingon"), ["i-klingon"]);
+	assert_eq!(fallbacks("zh-min-nan"), ["zh-min-nan"]);
+	assert_eq!(
+		fallbacks("zh-min-nan-x-foo"),
+		["zh-min-nan-x-foo", "zh-min-nan", "zh-min", "zh"]
+	);
+}
@@ -1,4 +1,4 @@
-use langtag::LangTag;
+use langtag::{LangTag, PrivateUse};
 
 #[test]
 pub fn privateuse_eq() {
@@ -22,6 +22,81 @@ pub fn privateuse_iter() {
 	assert_eq!(it.next(), None);
 }
 
+#[test]
+pub fn privateuse_iter_rev() {
+	let tag = LangTag::new("fr-x-ext1-ext2-ext3").unwrap();
+	let mut it = tag.private_use_subtags();
+	assert_eq!(it.len(), 3);
+	assert_eq!(it.next_back().unwrap(), "ext3");
+	assert_eq!(it.next().unwrap(), "ext1");
+	assert_eq!(it.len(), 1);
+	assert_eq!(it.next_back().unwrap(), "ext2");
+	assert_eq!(it.next(), None);
+	assert_eq!(it.next_back(), None);
+}
+
+#[test]
+pub fn privateuse_sorted() {
+	let sorted = |s: &str| LangTag::new(s).unwrap().with_sorted_private_use();
+	assert_eq!(
+		sorted("fr-a-zzz-x-ext3-EXT1-ext2"),
+		"fr-a-zzz-x-EXT1-ext2-ext3"
+	);
+	assert_eq!(sorted("x-b-a"), "x-a-b");
+	assert_eq!(sorted("fr-FR"), "fr-FR");
+	assert_eq!(sorted("i-klingon"), "i-klingon");
+}
+
+#[test]
+pub fn privateuse_take() {
+	let take = |s: &str| LangTag::new(s).unwrap().take_private_use();
+
+	let (tag, private) = take("fr-a-zzz-x-ext1-EXT2");
+	assert_eq!(tag, "fr-a-zzz");
+	assert_eq!(private, ["ext1", "EXT2"]);
+	assert!(LangTag::new(&tag).is_ok());
+
+	let (tag, private) = take("x-foo-bar");
+	assert_eq!(tag, "und");
+	assert_eq!(private, ["foo", "bar"]);
+
+	let (tag, private) = take("fr-FR");
+	assert_eq!(tag, "fr-FR");
+	assert!(private.is_empty());
+
+	let (tag, private) = take("i-klingon");
+	assert_eq!(tag, "i-klingon");
+	assert!(private.is_empty());
+}
+
+#[test]
+pub fn privateuse_len() {
+	let tag = LangTag::new("fr-a-zzz-x-ext1-ext2-ext3").unwrap();
+	assert_eq!(tag.private_use().unwrap().len(), 3);
+	assert_eq!(PrivateUse::new("x-foo").unwrap().len(), 1);
+}
+
+#[test]
+pub fn privateuse_slices() {
+	let private_use = PrivateUse::new("x-ext1-ext2-ext3").unwrap();
+	assert_eq!(private_use.subtags_str(), "ext1-ext2-ext3");
+	assert_eq!(private_use.first(), "ext1");
+	assert_eq!(private_use.last(), "ext3");
+
+	let private_use = PrivateUse::new("X-foo").unwrap();
+	assert_eq!(private_use.subtags_str(), "foo");
+	assert_eq!(private_use.first(), "foo");
+	assert_eq!(private_use.last(), "foo");
+}
+
+#[test]
+pub fn privateuse_contains() {
+	let private_use = PrivateUse::new("x-Foo-bar").unwrap();
+	assert!(private_use.contains("foo".try_into().unwrap()));
+	assert!(private_use.contains("BAR".try_into().unwrap()));
+	assert!(!private_use.contains("x".try_into().unwrap()));
+}
+
 // #[test]
 // pub fn privateuse_insert1() {
 // 	let mut tag = NormalLangTag::new("fr").unwrap();
@@ -66,3 +141,18 @@ pub fn privateuse_iter() {
 // 	pu.remove("ext1");
 // 	assert_eq!(tag, "fr");
 // }
+
+#[test]
+pub fn private_use_after_extensions() {
+	use langtag::NormalLangTag;
+
+	let tag = NormalLangTag::new("en-a-foo-x-bar").unwrap();
+	assert_eq!(tag.extensions(), "a-foo");
+	assert_eq!(tag.private_use().unwrap(), "x-bar");
+
+	let tag = NormalLangTag::new("de-CH-1996-a-foo-b-bar-x-baz").unwrap();
+	assert_eq!(tag.private_use().unwrap(), "x-baz");
+
+	let tag = NormalLangTag::new("en-a-foo").unwrap();
+	assert!(tag.private_use().is_none());
+}
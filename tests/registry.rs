@@ -0,0 +1,523 @@
+#![cfg(feature = "registry")]
+use langtag::Region;
+
+#[test]
+pub fn region_to_numeric() {
+	let us = Region::new("US").unwrap();
+	assert_eq!(us.to_numeric().unwrap(), "840");
+	let fr = Region::new("fr").unwrap();
+	assert_eq!(fr.to_numeric().unwrap(), "250");
+	let latam = Region::new("419").unwrap();
+	assert_eq!(latam.to_numeric().unwrap(), "419");
+	let eu = Region::new("EU").unwrap();
+	assert_eq!(eu.to_numeric(), None);
+}
+
+#[test]
+pub fn region_to_alpha2() {
+	let us = Region::new("840").unwrap();
+	assert_eq!(us.to_alpha2().unwrap(), "US");
+	let fr = Region::new("250").unwrap();
+	assert_eq!(fr.to_alpha2().unwrap(), "FR");
+	let latam = Region::new("419").unwrap();
+	assert_eq!(latam.to_alpha2(), None);
+	let de = Region::new("DE").unwrap();
+	assert_eq!(de.to_alpha2().unwrap(), "DE");
+}
+
+#[test]
+pub fn region_containing_regions() {
+	let regions = |s: &str| Region::new(s).unwrap().containing_regions();
+	assert_eq!(regions("FR"), ["155", "150", "001"]);
+	assert_eq!(regions("250"), ["155", "150", "001"]);
+	assert_eq!(regions("br"), ["005", "419", "019", "001"]);
+	assert_eq!(regions("JE"), ["830", "154", "150", "001"]);
+	assert_eq!(regions("150"), ["001"]);
+	assert!(regions("001").is_empty());
+	assert!(regions("EU").is_empty());
+	assert!(regions("QO").is_empty());
+}
+
+#[test]
+pub fn variant_is_registered() {
+	use langtag::Variant;
+
+	assert!(Variant::new("1996").unwrap().is_registered());
+	assert!(Variant::new("Rozaj").unwrap().is_registered());
+	assert!(Variant::new("fonipa").unwrap().is_registered());
+	assert!(!Variant::new("shadok").unwrap().is_registered());
+	assert!(!Variant::new("1234").unwrap().is_registered());
+}
+
+#[test]
+pub fn preferred_str() {
+	use langtag::LangTag;
+
+	assert_eq!(LangTag::new("i-klingon").unwrap().preferred_str(), "tlh");
+	assert_eq!(LangTag::new("ZH-min-NAN").unwrap().preferred_str(), "nan");
+	assert_eq!(
+		LangTag::new("i-default").unwrap().preferred_str(),
+		"i-default"
+	);
+	assert_eq!(LangTag::new("fr-FR").unwrap().preferred_str(), "fr-FR");
+}
+
+#[test]
+pub fn extlang_expand_collapse() {
+	use langtag::LangTag;
+
+	let tag = LangTag::new("cmn-Hant").unwrap();
+	assert_eq!(tag.expand_extlang(), "zh-cmn-Hant");
+	assert_eq!(tag.collapse_extlang(), "cmn-Hant");
+
+	let tag = LangTag::new("zh-yue-HK").unwrap();
+	assert_eq!(tag.collapse_extlang(), "yue-HK");
+	assert_eq!(tag.expand_extlang(), "zh-yue-HK");
+
+	let tag = LangTag::new("fr-FR").unwrap();
+	assert_eq!(tag.expand_extlang(), "fr-FR");
+	assert_eq!(tag.collapse_extlang(), "fr-FR");
+
+	let tag = LangTag::new("ar-cmn").unwrap();
+	assert_eq!(tag.collapse_extlang(), "ar-cmn");
+
+	let tag = LangTag::new("i-klingon").unwrap();
+	assert_eq!(tag.expand_extlang(), "i-klingon");
+}
+
+#[test]
+pub fn grandfathered_deprecation() {
+	use langtag::GrandfatheredLangTag;
+
+	let klingon = GrandfatheredLangTag::new("i-klingon").unwrap();
+	assert!(klingon.is_deprecated());
+	assert_eq!(klingon.deprecated_on(), Some("2004-02-24"));
+
+	let bok = GrandfatheredLangTag::new("no-bok").unwrap();
+	assert_eq!(bok.deprecated_on(), Some("2000-02-18"));
+
+	let default = GrandfatheredLangTag::new("i-default").unwrap();
+	assert!(!default.is_deprecated());
+	assert_eq!(default.deprecated_on(), None);
+}
+
+#[test]
+pub fn grandfathered_preferred_value() {
+	use langtag::{GrandfatheredLangTag, GRANDFATHERED};
+
+	let preferred = |s: &str| GrandfatheredLangTag::new(s).unwrap().preferred_value();
+	assert_eq!(preferred("i-klingon").unwrap(), "tlh");
+	assert_eq!(preferred("zh-min-nan").unwrap(), "nan");
+	assert_eq!(preferred("ART-LOJBAN").unwrap(), "jbo");
+	assert_eq!(preferred("en-GB-oed").unwrap(), "en-GB-oxendict");
+	assert_eq!(preferred("i-default"), None);
+	assert_eq!(preferred("zh-min"), None);
+
+	for tag in &GRANDFATHERED {
+		if let Some(value) = tag.preferred_value() {
+			assert!(value.is_normal());
+		}
+	}
+}
+
+#[test]
+pub fn plausible_combination() {
+	use langtag::LangTag;
+
+	let plausible = |s: &str| LangTag::new(s).unwrap().is_plausible_combination();
+	assert!(plausible("pt-BR"));
+	assert!(plausible("fr-CA"));
+	assert!(plausible("en-GB"));
+	assert!(plausible("de-CH"));
+	assert!(plausible("zh-Hant-TW"));
+	assert!(plausible("ja"));
+	assert!(plausible("ja-Latn-BR"));
+	assert!(plausible("qaa-BR"));
+	assert!(plausible("x-foo"));
+	for tag in [
+		"en-IN", "en-HK", "en-PK", "en-IL", "en-AE", "es-US", "hi-IN", "bn-IN", "ta-LK", "ar-IL",
+		"fr-MA", "pt-MO", "zh-SG", "ko-JP",
+	] {
+		assert!(plausible(tag), "{tag}");
+	}
+	assert!(!plausible("ja-BR"));
+	assert!(!plausible("ru-JP"));
+	assert!(!plausible("th-DE"));
+}
+
+#[test]
+pub fn equivalents() {
+	use langtag::LangTag;
+
+	let equivalents = |s: &str| LangTag::new(s).unwrap().equivalents();
+	assert_eq!(equivalents("en-Latn-US"), ["en-Latn-US", "en-US"]);
+	assert_eq!(equivalents("zh-cmn-Hans"), ["zh-cmn-Hans", "cmn-Hans"]);
+	assert_eq!(equivalents("hak"), ["hak", "i-hak", "zh-hakka", "zh-hak"]);
+	assert_eq!(equivalents("x-foo"), ["x-foo"]);
+	assert_eq!(equivalents("i-default"), ["i-default"]);
+}
+
+#[test]
+pub fn write_canonical() {
+	use langtag::LangTag;
+
+	let canonical = |s: &str| {
+		let mut result = String::new();
+		LangTag::new(s)
+			.unwrap()
+			.write_canonical(&mut result)
+			.unwrap();
+		result
+	};
+
+	assert_eq!(canonical("ZH-cmn-hant"), "cmn-Hant");
+	assert_eq!(canonical("i-klingon"), "tlh");
+	assert_eq!(canonical("en-gb-OED"), "en-GB-oxendict");
+	assert_eq!(canonical("I-default"), "i-default");
+}
+
+#[test]
+pub fn suppress_script() {
+	use langtag::{NormalLangTag, PrimaryLanguage, Script};
+
+	let en = PrimaryLanguage::new("en").unwrap();
+	assert!(Script::new("Latn").unwrap().is_suppress_script_for(en));
+	assert!(Script::new("LATN").unwrap().is_suppress_script_for(en));
+	assert!(!Script::new("Cyrl").unwrap().is_suppress_script_for(en));
+	assert!(!Script::new("Latn")
+		.unwrap()
+		.is_suppress_script_for(PrimaryLanguage::new("sr").unwrap()));
+
+	let without = |s: &str| NormalLangTag::new(s).unwrap().without_suppressed_script();
+	assert_eq!(without("en-Latn-US"), "en-US");
+	assert_eq!(without("EN-latn"), "EN");
+	assert_eq!(without("ru-Cyrl-x-foo"), "ru-x-foo");
+	assert_eq!(without("en-Cyrl-US"), "en-Cyrl-US");
+	assert_eq!(without("zh-Hant"), "zh-Hant");
+	assert_eq!(without("en-US"), "en-US");
+}
+
+#[test]
+pub fn unicode_locale_id() {
+	use langtag::LangTag;
+
+	let unicode = |s: &str| LangTag::new(s).unwrap().to_unicode_locale_id();
+	assert_eq!(unicode("EN-latn-us"), "en-Latn-US");
+	assert_eq!(unicode("sl-rozaj-biske-1994"), "sl-1994-biske-rozaj");
+	assert_eq!(unicode("iw-IL"), "he-IL");
+	assert_eq!(unicode("de-DD"), "de-DE");
+	assert_eq!(unicode("zh-cmn-Hans"), "cmn-Hans");
+	assert_eq!(unicode("en-u-ca-true"), "en-u-ca");
+	assert_eq!(
+		unicode("en-u-foo-bar-nu-thai-ca-buddhist"),
+		"en-u-bar-foo-ca-buddhist-nu-thai"
+	);
+	assert_eq!(
+		unicode("en-t-ES-M0-names-H0-hybrid"),
+		"en-t-es-h0-hybrid-m0-names"
+	);
+	assert_eq!(unicode("x-Foo"), "und-x-foo");
+	assert_eq!(unicode("i-default"), "en-x-i-default");
+	assert_eq!(unicode("i-klingon"), "tlh");
+	assert_eq!(unicode("en-GB-oed"), "en-GB-oxendict");
+}
+
+#[test]
+pub fn canonical_region() {
+	use langtag::LangTag;
+
+	let canonical = |s: &str| LangTag::new(s).unwrap().with_canonical_region();
+	assert_eq!(canonical("my-BU"), "my-MM");
+	assert_eq!(canonical("de-dd-1996-x-foo"), "de-DE-1996-x-foo");
+	assert_eq!(canonical("fr-ZR"), "fr-CD");
+	assert_eq!(canonical("sr-YU"), "sr-YU");
+	assert_eq!(canonical("en-GB"), "en-GB");
+	assert_eq!(canonical("en"), "en");
+
+	// Folded into the default canonicalization.
+	let tag = LangTag::new("my-bu").unwrap();
+	assert_eq!(
+		tag.canonicalize_with(langtag::CanonicalizeOptions::default()),
+		"my-MM"
+	);
+}
+
+#[test]
+pub fn canonical_language() {
+	use langtag::LangTag;
+
+	let canonical = |s: &str| LangTag::new(s).unwrap().with_canonical_language();
+	assert_eq!(canonical("iw-IL"), "he-IL");
+	assert_eq!(canonical("in"), "id");
+	assert_eq!(canonical("MO-Latn"), "ro-Latn");
+	assert_eq!(canonical("en-US"), "en-US");
+	assert_eq!(canonical("x-iw"), "x-iw");
+}
+
+#[test]
+pub fn canonicalize_with() {
+	use langtag::{CanonicalizeOptions, LangTag};
+
+	let canonical = |s: &str, options| LangTag::new(s).unwrap().canonicalize_with(options);
+	let default = CanonicalizeOptions::default();
+
+	assert_eq!(canonical("ZH-cmn-hans-bu", default).as_str(), "cmn-Hans-MM");
+	assert_eq!(canonical("I-KLINGON", default).as_str(), "tlh");
+	assert_eq!(canonical("I-DEFAULT", default).as_str(), "i-default");
+	assert_eq!(canonical("en-latn-us", default).as_str(), "en-Latn-US");
+	assert_eq!(canonical("IW-il", default).as_str(), "he-IL");
+	assert_eq!(canonical("in-x-foo", default).as_str(), "id-x-foo");
+	assert_eq!(
+		canonical(
+			"iw-IL",
+			CanonicalizeOptions {
+				canonical_language: false,
+				..default
+			}
+		)
+		.as_str(),
+		"iw-IL"
+	);
+	assert_eq!(
+		canonical(
+			"en-latn-us",
+			CanonicalizeOptions {
+				remove_suppressed_script: true,
+				..default
+			}
+		)
+		.as_str(),
+		"en-US"
+	);
+	assert_eq!(
+		canonical(
+			"zh-cmn-BU",
+			CanonicalizeOptions {
+				collapse_extlang: false,
+				canonical_region: false,
+				..default
+			}
+		)
+		.as_str(),
+		"zh-cmn-BU"
+	);
+	assert_eq!(
+		canonical(
+			"i-klingon",
+			CanonicalizeOptions {
+				replace_grandfathered: false,
+				..default
+			}
+		)
+		.as_str(),
+		"i-klingon"
+	);
+}
+
+#[test]
+pub fn equivalent_to_grandfathered() {
+	use langtag::{GrandfatheredLangTag, LangTag};
+
+	let tag = |s: &'static str| LangTag::new(s).unwrap();
+	assert!(tag("tlh").is_equivalent_to_grandfathered(GrandfatheredLangTag::IKlingon));
+	assert!(tag("I-Klingon").is_equivalent_to_grandfathered(GrandfatheredLangTag::IKlingon));
+	assert!(tag("en-gb-OXENDICT").is_equivalent_to_grandfathered(GrandfatheredLangTag::EnGbOed));
+	assert!(tag("en-GB-oed").is_equivalent_to_grandfathered(GrandfatheredLangTag::EnGbOed));
+	assert!(!tag("en-GB").is_equivalent_to_grandfathered(GrandfatheredLangTag::EnGbOed));
+	assert!(!tag("en").is_equivalent_to_grandfathered(GrandfatheredLangTag::IDefault));
+}
+
+#[test]
+pub fn remove_redundant() {
+	use langtag::LangTag;
+
+	let minimal = |s: &str| LangTag::new(s).unwrap().remove_redundant().into_string();
+	assert_eq!(minimal("zh-cmn-Hans"), "cmn-Hans");
+	assert_eq!(minimal("en-Latn-US"), "en-US");
+	assert_eq!(minimal("EN-latn"), "EN");
+	assert_eq!(minimal("sgn-ase-Latn"), "ase-Latn");
+	assert_eq!(minimal("sr-Latn-RS"), "sr-Latn-RS");
+	assert_eq!(minimal("x-foo"), "x-foo");
+	assert_eq!(minimal("i-klingon"), "i-klingon");
+}
+
+#[test]
+pub fn validate_all() {
+	use langtag::{LangTag, StructureError, ValidationError};
+
+	let validate = |s: &str| LangTag::new(s).unwrap().validate_all();
+
+	for tag in [
+		"en",
+		"zh-yue-Hant-HK",
+		"de-CH-1901",
+		"sl-IT-rozaj-biske-1994",
+		"qaa-Qaab-QM",
+		"en-a-foo-b-bar-x-a-a",
+		"x-whatever",
+		"i-default",
+	] {
+		assert!(validate(tag).is_empty(), "{tag}")
+	}
+
+	// A region equal to the required language does not satisfy the prefix.
+	for (tag, variant) in [
+		("en-DE-1901", "1901"),
+		("zh-TW-akuapem", "akuapem"),
+		("en-FR-1694acad", "1694acad"),
+		("es-CA-valencia", "valencia"),
+		("en-RU-petr1708", "petr1708"),
+		("sl-biske-rozaj", "biske"),
+	] {
+		assert_eq!(
+			validate(tag),
+			[ValidationError::InvalidVariantPrefix(variant.to_owned())],
+			"{tag}"
+		)
+	}
+
+	assert_eq!(
+		validate("zz-yue-Abcd-BU-shadok-1901-1901-a-foo-A-bar"),
+		[
+			ValidationError::UnregisteredLanguage("zz".to_owned()),
+			ValidationError::InvalidExtendedLanguagePrefix {
+				extlang: "yue".to_owned(),
+				prefix: "zh"
+			},
+			ValidationError::UnregisteredScript("Abcd".to_owned()),
+			ValidationError::Deprecated {
+				subtag: "BU".to_owned(),
+				preferred_value: Some("MM")
+			},
+			ValidationError::UnregisteredVariant("shadok".to_owned()),
+			ValidationError::InvalidVariantPrefix("1901".to_owned()),
+			ValidationError::Structure(StructureError::DuplicateVariant("1901".parse().unwrap())),
+			ValidationError::InvalidVariantPrefix("1901".to_owned()),
+			ValidationError::Structure(StructureError::DuplicateSingleton('A'.try_into().unwrap())),
+		]
+	);
+
+	assert_eq!(
+		validate("iw-AB"),
+		[
+			ValidationError::Deprecated {
+				subtag: "iw".to_owned(),
+				preferred_value: Some("he")
+			},
+			ValidationError::UnregisteredRegion("AB".to_owned()),
+		]
+	);
+	assert_eq!(
+		validate("i-klingon"),
+		[ValidationError::Deprecated {
+			subtag: "i-klingon".to_owned(),
+			preferred_value: Some("tlh")
+		}]
+	);
+}
+
+#[test]
+pub fn check_valid() {
+	use langtag::{LangTag, ValidationError};
+
+	let validate = |s: &str| LangTag::new(s).unwrap().check_valid();
+
+	assert_eq!(validate("en-US"), Ok(()));
+	assert_eq!(validate("en-shadok"), Ok(()));
+	assert_eq!(validate("iw-IL"), Ok(()));
+	assert_eq!(
+		validate("zz-Abcd-shadok"),
+		Err(ValidationError::UnregisteredLanguage("zz".to_owned()))
+	);
+	assert_eq!(
+		validate("en-BU-shadok-1901"),
+		Err(ValidationError::InvalidVariantPrefix("1901".to_owned()))
+	);
+}
+
+#[test]
+pub fn subtag_registration() {
+	use langtag::{Language, PrimaryLanguage, Region, Script};
+
+	assert!(PrimaryLanguage::new("EN").unwrap().is_registered());
+	assert!(PrimaryLanguage::new("qtz").unwrap().is_registered());
+	assert!(!PrimaryLanguage::new("ax").unwrap().is_registered());
+
+	assert!(Language::new("zh-yue").unwrap().is_registered());
+	assert!(!Language::new("fr-yue").unwrap().is_registered());
+	assert!(!Language::new("zh-abc").unwrap().is_registered());
+	assert!(!Language::new("ax").unwrap().is_registered());
+
+	assert!(Script::new("latn").unwrap().is_registered());
+	assert!(Script::new("Qaab").unwrap().is_registered());
+	assert!(!Script::new("Abcd").unwrap().is_registered());
+
+	assert!(Region::new("tz").unwrap().is_registered());
+	assert!(Region::new("419").unwrap().is_registered());
+	assert!(Region::new("XB").unwrap().is_registered());
+	assert!(!Region::new("QL").unwrap().is_registered());
+}
+
+#[test]
+pub fn variant_prefixes() {
+	use langtag::{NormalLangTag, Variant};
+
+	assert_eq!(Variant::new("nedis").unwrap().valid_prefixes(), ["sl"]);
+	assert!(Variant::new("shadok").unwrap().valid_prefixes().is_empty());
+
+	let valid = |s: &str| {
+		NormalLangTag::new(s)
+			.unwrap()
+			.variants_have_valid_prefixes()
+	};
+	assert!(valid("sl-nedis"));
+	assert!(valid("SL-Latn-IT-nedis"));
+	assert!(valid("sl-rozaj-biske"));
+	assert!(valid("en-shadok"));
+	assert!(valid("en-US"));
+	assert!(!valid("de-nedis"));
+	assert!(!valid("sl-biske"));
+	assert!(!valid("sl-biske-rozaj"));
+	assert!(!valid("en-DE-1901"));
+	assert!(!valid("zh-TW-akuapem"));
+	assert!(!valid("en-SL-nedis"));
+	assert!(valid("de-DE-1901"));
+	assert!(valid("ca-ES-valencia"));
+}
+
+#[test]
+pub fn canonical_forms_agree() {
+	use langtag::{CanonicalizeOptions, LangTag, LangTagBuf};
+
+	let mut out = LangTagBuf::new("und".to_owned()).unwrap();
+	for (tag, expected) in [
+		("iw-IL", "he-IL"),
+		("my-BU", "my-MM"),
+		("zh-yue-HK", "yue-HK"),
+		("i-klingon", "tlh"),
+	] {
+		let tag = LangTag::new(tag).unwrap();
+		assert_eq!(
+			tag.canonicalize_with(CanonicalizeOptions::default()),
+			expected
+		);
+
+		let mut s = String::new();
+		tag.write_canonical(&mut s).unwrap();
+		assert_eq!(s, expected);
+
+		tag.canonicalize_into(&mut out);
+		assert_eq!(out, expected);
+
+		#[cfg(feature = "serde")]
+		{
+			let json = format!("\"{}\"", tag.as_str());
+			let canonical: langtag::Canonical<LangTagBuf> = serde_json::from_str(&json).unwrap();
+			assert_eq!(canonical.0, expected);
+			assert_eq!(
+				serde_json::to_string(&langtag::Canonical(tag)).unwrap(),
+				format!("\"{expected}\"")
+			);
+		}
+	}
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+use langtag::{Canonical, LangTagBuf};
+use std::collections::HashMap;
+
+#[test]
+pub fn serde_canonical() {
+	let tag: Canonical<LangTagBuf> = serde_json::from_str("\"EN-latn-us-b-CCC-a-bbb\"").unwrap();
+	assert_eq!(tag.0.as_str(), "en-Latn-US-a-bbb-b-ccc");
+
+	let tag: LangTagBuf = "fr-ca".parse().unwrap();
+	assert_eq!(serde_json::to_string(&Canonical(tag)).unwrap(), "\"fr-CA\"");
+
+	let map: HashMap<Canonical<LangTagBuf>, u32> =
+		serde_json::from_str("{\"en-us\": 1, \"en-US\": 2}").unwrap();
+	assert_eq!(map.len(), 1);
+
+	assert!(serde_json::from_str::<Canonical<LangTagBuf>>("\"en--us\"").is_err());
+}
+
+#[test]
+pub fn serde_grandfathered() {
+	use langtag::{GrandfatheredLangTag, GRANDFATHERED};
+
+	for tag in GRANDFATHERED {
+		let json = serde_json::to_string(&tag).unwrap();
+		assert_eq!(json, format!("\"{tag}\""));
+		assert!(serde_json::from_str::<GrandfatheredLangTag>(&json).unwrap() == tag);
+	}
+
+	assert!(
+		serde_json::from_str::<GrandfatheredLangTag>("\"I-KLINGON\"").unwrap()
+			== GrandfatheredLangTag::IKlingon
+	);
+	assert!(serde_json::from_str::<GrandfatheredLangTag>("\"en-US\"").is_err());
+}
+
+#[test]
+pub fn serde_singleton() {
+	use langtag::Singleton;
+
+	let u = Singleton::new(b'u').unwrap();
+	assert_eq!(serde_json::to_string(&u).unwrap(), "\"u\"");
+	assert_eq!(serde_json::from_str::<Singleton>("\"u\"").unwrap(), u);
+	assert_eq!(serde_json::from_str::<Singleton>("\"7\"").unwrap(), '7');
+	assert!(serde_json::from_str::<Singleton>("\"x\"").is_err());
+	assert!(serde_json::from_str::<Singleton>("\"X\"").is_err());
+	assert!(serde_json::from_str::<Singleton>("\"-\"").is_err());
+	assert!(serde_json::from_str::<Singleton>("\"ab\"").is_err());
+}
@@ -0,0 +1,98 @@
+//! Differential test against `unic-langid`, on the subset of language tags
+//! both crates support: language, script, region and variants.
+//!
+//! Known divergences, excluded from the comparison:
+//! - `unic-langid` has no extended language subtags (`zh-yue`);
+//! - `unic-langid` rejects 4-letter primary language subtags, reserved by
+//!   RFC 5646 but well-formed;
+//! - `unic-langid` sorts and deduplicates variants, so variants are compared
+//!   as sorted sets;
+//! - extensions, private use and grandfathered tags are out of scope.
+//!
+//! The generated tags are compared after [`LangTag::to_canonical`], which
+//! only normalizes the case of subtags. Should it start replacing subtags
+//! (e.g. deprecated languages or regions, see `canonicalize_with`), the
+//! comparison would have to account for it.
+//!
+//! The generator relies on the `arbitrary` feature, so this test only runs
+//! with `cargo test --features arbitrary` (or `--all-features`).
+#![cfg(feature = "arbitrary")]
+mod common;
+
+use arbitrary::{Arbitrary, Unstructured};
+use langtag::{LangTag, NormalLangTagBuf};
+use unic_langid::LanguageIdentifier;
+
+use common::bytes;
+
+/// Returns the language, script, region and variants part of the given tag,
+/// if it is in the subset supported by `unic-langid`.
+fn common_subset(tag: &NormalLangTagBuf) -> Option<String> {
+	let language = tag.language();
+	if language.extension().is_some() || language.primary().len() == 4 {
+		return None;
+	}
+
+	let mut result = language.primary().to_string();
+	for subtag in tag
+		.script()
+		.map(|s| s.as_str())
+		.into_iter()
+		.chain(tag.region().map(|r| r.as_str()))
+		.chain(tag.variants().iter().map(|v| v.as_str()))
+	{
+		result.push('-');
+		result.push_str(subtag)
+	}
+
+	Some(result)
+}
+
+#[test]
+pub fn unic_langid_agrees() {
+	let mut compared = 0;
+
+	for seed in 0..10_000 {
+		let data = bytes(seed, 256);
+		let mut u = Unstructured::new(&data);
+		let Some(subset) = common_subset(&NormalLangTagBuf::arbitrary(&mut u).unwrap()) else {
+			continue;
+		};
+
+		let tag = LangTag::new(&subset).unwrap().to_canonical();
+		let id: LanguageIdentifier = subset
+			.parse()
+			.unwrap_or_else(|e| panic!("`{subset}` rejected by unic-langid: {e}"));
+
+		assert_eq!(
+			tag.language().unwrap().primary().as_str(),
+			id.language.as_str(),
+			"{subset}"
+		);
+		assert_eq!(
+			tag.script().map(|s| s.as_str()),
+			id.script.as_ref().map(|s| s.as_str()),
+			"{subset}"
+		);
+		assert_eq!(
+			tag.region().map(|r| r.as_str()),
+			id.region.as_ref().map(|r| r.as_str()),
+			"{subset}"
+		);
+
+		let mut variants: Vec<_> = tag.variants().iter().map(|v| v.as_str()).collect();
+		variants.sort_unstable();
+		variants.dedup();
+		assert!(
+			variants
+				.iter()
+				.copied()
+				.eq(id.variants().map(|v| v.as_str())),
+			"{subset}"
+		);
+
+		compared += 1
+	}
+
+	assert!(compared > 1_000)
+}
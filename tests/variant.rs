@@ -1,4 +1,4 @@
-use langtag::LangTag;
+use langtag::{LangTag, NormalLangTagBuf};
 
 #[test]
 pub fn variants_eq() {
@@ -29,56 +29,131 @@ pub fn variants_last() {
 	assert_eq!(tag.variants().last().unwrap(), "4242");
 }
 
-// #[test]
-// pub fn variants_mut_first() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-azert-0foo-barbz-4242-e-ext").unwrap();
-// 	assert_eq!(tag.variants_mut().first().unwrap(), "azert");
-// }
-
-// #[test]
-// pub fn variants_mut_last() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-azert-0foo-barbz-4242-e-ext").unwrap();
-// 	assert_eq!(tag.variants_mut().last().unwrap(), "4242");
-// }
-
-// #[test]
-// pub fn variants_push() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-azert-0foo-barbz-e-ext").unwrap();
-// 	tag.variants_mut().push("4242".try_into().unwrap());
-// 	let mut it = tag.variants().iter();
-// 	assert_eq!(it.next().unwrap(), "azert");
-// 	assert_eq!(it.next().unwrap(), "0foo");
-// 	assert_eq!(it.next().unwrap(), "barbz");
-// 	assert_eq!(it.next().unwrap(), "4242");
-// 	assert_eq!(it.next(), None);
-// }
-
-// #[test]
-// pub fn variants_pop() {
-// 	let mut tag = langtag::LangTag::parse_copy("fr-azert-0foo-barbz-4242-e-ext").unwrap();
-// 	assert_eq!(tag.variants_mut().last().unwrap(), "4242");
-// 	tag.variants_mut().pop();
-// 	assert_eq!(tag.variants_mut().last().unwrap(), "barbz");
-// 	let mut it = tag.variants().iter();
-// 	assert_eq!(it.next().unwrap(), "azert");
-// 	assert_eq!(it.next().unwrap(), "0foo");
-// 	assert_eq!(it.next().unwrap(), "barbz");
-// 	assert_eq!(it.next(), None);
-
-// 	tag.variants_mut().pop();
-// 	assert_eq!(tag.variants_mut().last().unwrap(), "0foo");
-// 	let mut it = tag.variants().iter();
-// 	assert_eq!(it.next().unwrap(), "azert");
-// 	assert_eq!(it.next().unwrap(), "0foo");
-// 	assert_eq!(it.next(), None);
-
-// 	tag.variants_mut().pop();
-// 	let mut it = tag.variants().iter();
-// 	assert_eq!(it.next().unwrap(), "azert");
-// 	assert_eq!(it.next(), None);
-
-// 	tag.variants_mut().pop();
-// 	assert_eq!(tag.variants_mut().last(), None);
-// 	let mut it = tag.variants().iter();
-// 	assert_eq!(it.next(), None);
-// }
+#[test]
+pub fn variants_eq_ignore() {
+	let a = LangTag::new("de-CH-1996").unwrap();
+	let b = LangTag::new("DE-ch").unwrap();
+	assert!(a.eq_ignore_variants(b));
+	assert!(b.eq_ignore_variants(a));
+	let a = LangTag::new("sl-rozaj-biske-u-co-phonebk-x-foo").unwrap();
+	let b = LangTag::new("sl-nedis-u-co-phonebk-x-foo").unwrap();
+	assert!(a.eq_ignore_variants(b));
+	let b = LangTag::new("sl-nedis-u-co-phonebk").unwrap();
+	assert!(!a.eq_ignore_variants(b));
+	let a = LangTag::new("de-CH-1996").unwrap();
+	let b = LangTag::new("de-AT-1996").unwrap();
+	assert!(!a.eq_ignore_variants(b));
+	let a = LangTag::new("i-klingon").unwrap();
+	assert!(a.eq_ignore_variants(a));
+
+	// Regular grandfathered tags are not normal tags with variants.
+	for (a, b) in [("zh-hakka", "zh"), ("art-lojban", "art")] {
+		let a = LangTag::new(a).unwrap();
+		let b = LangTag::new(b).unwrap();
+		assert!(!a.eq_ignore_variants(b));
+		assert!(!b.eq_ignore_variants(a));
+	}
+}
+
+#[test]
+pub fn variants_mut_first() {
+	let mut tag = NormalLangTagBuf::new("fr-azert-0foo-barbz-4242-e-ext".to_owned()).unwrap();
+	assert_eq!(tag.variants_mut().first().unwrap(), "azert");
+}
+
+#[test]
+pub fn variants_mut_last() {
+	let mut tag = NormalLangTagBuf::new("fr-azert-0foo-barbz-4242-e-ext".to_owned()).unwrap();
+	assert_eq!(tag.variants_mut().last().unwrap(), "4242");
+}
+
+#[test]
+pub fn variants_push() {
+	let mut tag = NormalLangTagBuf::new("fr-azert-0foo-barbz-e-ext".to_owned()).unwrap();
+	tag.variants_mut().push("4242".try_into().unwrap());
+	let mut it = tag.variants().iter();
+	assert_eq!(it.next().unwrap(), "azert");
+	assert_eq!(it.next().unwrap(), "0foo");
+	assert_eq!(it.next().unwrap(), "barbz");
+	assert_eq!(it.next().unwrap(), "4242");
+	assert_eq!(it.next(), None);
+}
+
+#[test]
+pub fn variants_pop() {
+	let mut tag = NormalLangTagBuf::new("fr-azert-0foo-barbz-4242-e-ext".to_owned()).unwrap();
+	assert_eq!(tag.variants_mut().last().unwrap(), "4242");
+	tag.variants_mut().pop();
+	assert_eq!(tag.variants_mut().last().unwrap(), "barbz");
+	let mut it = tag.variants().iter();
+	assert_eq!(it.next().unwrap(), "azert");
+	assert_eq!(it.next().unwrap(), "0foo");
+	assert_eq!(it.next().unwrap(), "barbz");
+	assert_eq!(it.next(), None);
+
+	tag.variants_mut().pop();
+	assert_eq!(tag.variants_mut().last().unwrap(), "0foo");
+	let mut it = tag.variants().iter();
+	assert_eq!(it.next().unwrap(), "azert");
+	assert_eq!(it.next().unwrap(), "0foo");
+	assert_eq!(it.next(), None);
+
+	tag.variants_mut().pop();
+	let mut it = tag.variants().iter();
+	assert_eq!(it.next().unwrap(), "azert");
+	assert_eq!(it.next(), None);
+
+	tag.variants_mut().pop();
+	assert_eq!(tag.variants_mut().last(), None);
+	let mut it = tag.variants().iter();
+	assert_eq!(it.next(), None);
+}
+
+#[test]
+pub fn variants_mut_pop_push() {
+	let mut tag = NormalLangTagBuf::new("de-CH-1996-u-co-phonebk-x-foo".to_owned()).unwrap();
+	assert_eq!(tag.variants_mut().pop().unwrap(), "1996");
+	assert_eq!(tag, "de-CH-u-co-phonebk-x-foo");
+	assert_eq!(tag.variants_mut().pop(), None);
+	tag.variants_mut().push("1901".try_into().unwrap());
+	assert_eq!(tag, "de-CH-1901-u-co-phonebk-x-foo");
+	assert_eq!(tag.extensions(), "u-co-phonebk");
+	assert_eq!(tag.private_use().unwrap(), "x-foo");
+
+	let mut tag = NormalLangTagBuf::new("sl".to_owned()).unwrap();
+	tag.variants_mut().push("rozaj".try_into().unwrap());
+	assert_eq!(tag, "sl-rozaj");
+}
+
+#[test]
+pub fn variants_len() {
+	let tag = LangTag::new("fr-azert-0foo-barbz-4242-e-ext").unwrap();
+	assert_eq!(tag.variants().len(), 4);
+	assert!(!tag.variants().is_empty());
+
+	let tag = LangTag::new("fr-FR-e-ext").unwrap();
+	assert_eq!(tag.variants().len(), 0);
+	assert!(tag.variants().is_empty());
+}
+
+#[test]
+pub fn variants_contains() {
+	let tag = LangTag::new("sl-rozaj-BISKE-1994").unwrap();
+	assert!(tag.variants().contains("biske".try_into().unwrap()));
+	assert!(tag.variants().contains("1994".try_into().unwrap()));
+	assert!(!tag.variants().contains("1996".try_into().unwrap()));
+}
+
+#[test]
+pub fn variants_iter_len() {
+	let tag = LangTag::new("fr-azert-0foo-barbz-4242-e-ext").unwrap();
+	let mut it = tag.variants().iter();
+	assert_eq!(it.len(), 4);
+	it.next();
+	it.next_back();
+	assert_eq!(it.len(), 2);
+	it.next();
+	it.next();
+	assert_eq!(it.len(), 0);
+	assert_eq!(LangTag::new("fr").unwrap().variants().iter().len(), 0);
+}